@@ -2,11 +2,22 @@ use std::sync::Arc;
 
 use blog_proto::{
     admin_service_server::AdminService, AdminExistsReply, AdminExistsRequest, CreateAdminReply,
-    EditAdminReply, EditAdminRequest, GetAdminReply, GetAdminRequest, ListAdminReply,
-    ListAdminRequest, ToggleAdminReply, ToggleAdminRequest,
+    EditAdminProfileReply, EditAdminProfileRequest, EditAdminReply, EditAdminRequest,
+    GetAdminReply, GetAdminRequest, ListAdminReply, ListAdminRequest, PurgeAdminsReply,
+    PurgeAdminsRequest, ToggleAdminReply, ToggleAdminRequest,
 };
 use blog_utils::password;
-use sqlx::{PgPool, Row};
+use chrono::{DateTime, Local};
+use sqlx::{postgres::PgRow, PgPool, Row};
+
+use crate::trash;
+
+/// 显示名称的最大长度（字符数）
+const DISPLAY_NAME_MAX_LEN: usize = 100;
+/// 头像地址的最大长度（字符数）
+const AVATAR_MAX_LEN: usize = 255;
+/// 个人简介的最大长度（字符数）
+const BIO_MAX_LEN: usize = 1000;
 
 pub struct Admin {
     pub pool: Arc<PgPool>,
@@ -57,7 +68,7 @@ impl AdminService for Admin {
         };
         let reply = match condition {
             blog_proto::get_admin_request::Condition::ByAuth(ba) => {
-                let row = sqlx::query("select id,email,is_del,password from admins where email=$1")
+                let row = sqlx::query("select id,email,is_del,deleted_at,deleted_by,display_name,avatar,bio,password from admins where email=$1")
                     .bind(ba.email)
                     .fetch_optional(&*self.pool)
                     .await
@@ -65,17 +76,12 @@ impl AdminService for Admin {
                 if let Some(row) = row {
                     let hashed_pwd: String = row.get("password");
                     let is_verify = password::verify(&ba.password, &hashed_pwd)
-                        .map_err(|err| tonic::Status::internal(err))?;
+                        .map_err(tonic::Status::internal)?;
                     if !is_verify {
                         return Err(tonic::Status::invalid_argument("用户名/密码错误"));
                     } else {
                         GetAdminReply {
-                            admin: Some(blog_proto::Admin {
-                                id: row.get("id"),
-                                email: row.get("email"),
-                                password: None,
-                                is_del: row.get("is_del"),
-                            }),
+                            admin: Some(admin_from_row(&row)),
                         }
                     }
                 } else {
@@ -85,12 +91,12 @@ impl AdminService for Admin {
             blog_proto::get_admin_request::Condition::ById(bi) => {
                 let row = match bi.is_del {
                     Some(is_del) => {
-                        sqlx::query("select id,email,is_del from admins where id=$1 and is_del=$2")
+                        sqlx::query("select id,email,is_del,deleted_at,deleted_by,display_name,avatar,bio from admins where id=$1 and is_del=$2")
                             .bind(bi.id)
                             .bind(is_del)
                     }
                     None => {
-                        sqlx::query("select id,email,is_del from admins where id=$1").bind(bi.id)
+                        sqlx::query("select id,email,is_del,deleted_at,deleted_by,display_name,avatar,bio from admins where id=$1").bind(bi.id)
                     }
                 }
                 .fetch_optional(&*self.pool)
@@ -98,12 +104,7 @@ impl AdminService for Admin {
                 .map_err(|err| tonic::Status::internal(err.to_string()))?;
                 if let Some(row) = row {
                     GetAdminReply {
-                        admin: Some(blog_proto::Admin {
-                            id: row.get("id"),
-                            email: row.get("email"),
-                            password: None,
-                            is_del: row.get("is_del"),
-                        }),
+                        admin: Some(admin_from_row(&row)),
                     }
                 } else {
                     return Err(tonic::Status::not_found("不存在的用户"));
@@ -157,6 +158,34 @@ impl AdminService for Admin {
         }))
     }
 
+    async fn edit_admin_profile(
+        &self,
+        request: tonic::Request<EditAdminProfileRequest>,
+    ) -> Result<tonic::Response<EditAdminProfileReply>, tonic::Status> {
+        let EditAdminProfileRequest {
+            id,
+            display_name,
+            avatar,
+            bio,
+        } = request.into_inner();
+        let (display_name, avatar, bio) = (display_name.trim(), avatar.trim(), bio.trim());
+        check_profile(display_name, avatar, bio).map_err(tonic::Status::invalid_argument)?;
+        let rows_affected =
+            sqlx::query("update admins set display_name=$1,avatar=$2,bio=$3 where id=$4")
+                .bind(display_name)
+                .bind(avatar)
+                .bind(bio)
+                .bind(id)
+                .execute(&*self.pool)
+                .await
+                .map_err(|err| tonic::Status::internal(err.to_string()))?
+                .rows_affected();
+        if rows_affected == 0 {
+            return Err(tonic::Status::not_found("不存在的用户"));
+        }
+        Ok(tonic::Response::new(EditAdminProfileReply { id, ok: true }))
+    }
+
     async fn list_admin(
         &self,
         request: tonic::Request<ListAdminRequest>,
@@ -165,7 +194,7 @@ impl AdminService for Admin {
         let rows = sqlx::query(
             r#"
             SELECT
-                id,email,is_del 
+                id,email,is_del,deleted_at,deleted_by,display_name,avatar,bio
             FROM
                 admins
             WHERE 1=1
@@ -180,13 +209,7 @@ impl AdminService for Admin {
         .map_err(|err| tonic::Status::internal(err.to_string()))?;
         let mut admins = Vec::with_capacity(rows.len());
         for row in rows {
-            let a = blog_proto::Admin {
-                id: row.get("id"),
-                email: row.get("email"),
-                password: None,
-                is_del: row.get("is_del"),
-            };
-            admins.push(a);
+            admins.push(admin_from_row(&row));
         }
         Ok(tonic::Response::new(ListAdminReply { admins }))
     }
@@ -221,15 +244,95 @@ impl AdminService for Admin {
         &self,
         request: tonic::Request<ToggleAdminRequest>,
     ) -> Result<tonic::Response<ToggleAdminReply>, tonic::Status> {
-        let ToggleAdminRequest { id } = request.into_inner();
-        let row = sqlx::query("update admins set is_del=(not is_del) where id=$1 returning is_del")
-            .bind(id)
-            .fetch_one(&*self.pool)
-            .await
-            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let ToggleAdminRequest { id, admin_id } = request.into_inner();
+        // 删除时记录删除时间和管理员，恢复时清空
+        let row = sqlx::query(
+            r#"
+            update admins set
+                is_del=(not is_del),
+                deleted_at=(case when is_del then null else current_timestamp end),
+                deleted_by=(case when is_del then null else $2 end)
+            where id=$1
+            returning is_del"#,
+        )
+        .bind(id)
+        .bind(admin_id)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
         Ok(tonic::Response::new(ToggleAdminReply {
             id,
             is_del: row.get(0),
         }))
     }
+
+    async fn purge_admins(
+        &self,
+        request: tonic::Request<PurgeAdminsRequest>,
+    ) -> Result<tonic::Response<PurgeAdminsReply>, tonic::Status> {
+        let PurgeAdminsRequest { ids } = request.into_inner();
+        let ids = trash::purge(&self.pool, &ids)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(PurgeAdminsReply { ids }))
+    }
+}
+
+fn admin_from_row(row: &PgRow) -> blog_proto::Admin {
+    let deleted_at: Option<DateTime<Local>> = row.get("deleted_at");
+    blog_proto::Admin {
+        id: row.get("id"),
+        email: row.get("email"),
+        password: None,
+        is_del: row.get("is_del"),
+        deleted_at: deleted_at.and_then(|dt| dt_conver(&dt)),
+        deleted_by: row.get("deleted_by"),
+        display_name: row.get("display_name"),
+        avatar: row.get("avatar"),
+        bio: row.get("bio"),
+    }
+}
+
+/// 检查个人资料。头像会显示在前台页面中，只允许站内地址和 http(s) 地址
+fn check_profile(display_name: &str, avatar: &str, bio: &str) -> Result<(), &'static str> {
+    if display_name.chars().count() > DISPLAY_NAME_MAX_LEN {
+        return Err("显示名称不能超过100个字符");
+    }
+    if avatar.chars().count() > AVATAR_MAX_LEN {
+        return Err("头像地址不能超过255个字符");
+    }
+    let is_url = avatar.is_empty()
+        || (avatar.starts_with('/') && !avatar.starts_with("//"))
+        || avatar.starts_with("http://")
+        || avatar.starts_with("https://");
+    if !is_url {
+        return Err("头像地址必须以 / 或 http(s):// 开头");
+    }
+    if bio.chars().count() > BIO_MAX_LEN {
+        return Err("个人简介不能超过1000个字符");
+    }
+    Ok(())
+}
+
+fn dt_conver(dt: &DateTime<Local>) -> Option<prost_types::Timestamp> {
+    Some(prost_types::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_profile() {
+        assert!(check_profile("张三", "/media/a.png", "简介").is_ok());
+        assert!(check_profile("", "", "").is_ok());
+        assert!(check_profile("", "https://example.com/a.png", "").is_ok());
+        assert!(check_profile("", "javascript:alert(1)", "").is_err());
+        assert!(check_profile("", "//example.com/a.png", "").is_err());
+        assert!(check_profile(&"名".repeat(101), "", "").is_err());
+        assert!(check_profile("", "", &"a".repeat(1001)).is_err());
+    }
 }
@@ -0,0 +1,41 @@
+// 回收站清理：周期性地彻底删除超过保留期的管理员
+use std::time::Duration;
+
+use sqlx::{PgPool, Row};
+
+pub async fn run(pool: PgPool, interval: Duration, retention_days: i32) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        match purge_expired(&pool, retention_days).await {
+            Ok(0) => {}
+            Ok(n) => println!("purged {} admin(s) from trash", n),
+            Err(err) => eprintln!("purge trash failed: {}", err),
+        }
+    }
+}
+
+async fn purge_expired(pool: &PgPool, retention_days: i32) -> Result<usize, sqlx::Error> {
+    let ids: Vec<i32> = sqlx::query(
+        "SELECT id FROM admins WHERE is_del AND deleted_at < CURRENT_TIMESTAMP - make_interval(days => $1)",
+    )
+    .bind(retention_days)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|row| row.get("id"))
+    .collect();
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    Ok(purge(pool, &ids).await?.len())
+}
+
+/// 彻底删除回收站中的管理员，返回已删除的管理员ID
+pub async fn purge(pool: &PgPool, ids: &[i32]) -> Result<Vec<i32>, sqlx::Error> {
+    let rows = sqlx::query("DELETE FROM admins WHERE id = ANY($1) AND is_del RETURNING id")
+        .bind(ids)
+        .fetch_all(pool)
+        .await?;
+    Ok(rows.iter().map(|row| row.get("id")).collect())
+}
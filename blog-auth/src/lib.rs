@@ -39,7 +39,7 @@ impl Jwt {
     }
 
     fn secret_bytes(&self) -> &[u8] {
-        (&self.secret).as_bytes()
+        self.secret.as_bytes()
     }
 
     pub fn token(&self, claims: &Claims) -> Result<String, crate::Error> {
@@ -53,7 +53,7 @@ impl Jwt {
     
     pub fn verify_and_get(&self, token: &str) -> Result<Claims, crate::Error> {
         let mut v = Validation::new(jsonwebtoken::Algorithm::HS256);
-        v.set_issuer(&[self.iss.clone()]);
+        v.set_issuer(std::slice::from_ref(&self.iss));
         let token_data = decode(token, &DecodingKey::from_secret(self.secret_bytes()), &v)
             .map_err(crate::Error::from)?;
         Ok(token_data.claims)
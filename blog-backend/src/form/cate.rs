@@ -3,4 +3,43 @@ use serde::Deserialize;
 #[derive(Deserialize)]
 pub struct AddCategory {
     pub name: String,
+    /// 上级分类，空表示顶级分类
+    #[serde(default)]
+    pub parent_id: String,
+}
+
+impl AddCategory {
+    pub fn parent_id(&self) -> Option<i32> {
+        self.parent_id.parse().ok()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct EditCategory {
+    pub name: String,
+    /// 上级分类，空表示顶级分类
+    #[serde(default)]
+    pub parent_id: String,
+    /// 修改前读取到的版本号
+    pub version: Option<i32>,
+}
+
+impl EditCategory {
+    pub fn parent_id(&self) -> Option<i32> {
+        self.parent_id.parse().ok()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct MoveCategory {
+    /// up / down
+    pub direction: String,
+}
+
+#[derive(Deserialize)]
+pub struct DeleteCategory {
+    /// 分类中还有文章时的处理方式：block / move / hide
+    pub mode: String,
+    /// 移动文章时的目标分类
+    pub move_to: Option<i32>,
 }
\ No newline at end of file
@@ -0,0 +1,48 @@
+use blog_proto::{CommentStatus, ListCommentRequest};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct CommentListFilter {
+    pub page: Option<i32>,
+    /// pending / approved / spam / deleted / all，默认为待审核
+    pub status: Option<String>,
+    pub topic_id: Option<String>,
+    pub msg: Option<String>,
+}
+
+impl CommentListFilter {
+    pub fn status(&self) -> &str {
+        self.status.as_deref().unwrap_or("pending")
+    }
+
+    pub fn topic_id(&self) -> Option<i64> {
+        self.topic_id.as_deref().and_then(|s| s.trim().parse().ok())
+    }
+}
+
+impl From<CommentListFilter> for ListCommentRequest {
+    fn from(f: CommentListFilter) -> Self {
+        Self {
+            page: f.page,
+            topic_id: f.topic_id(),
+            status: comment_status(f.status()).map(|s| s.into()),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ModerateComment {
+    pub status: String,
+    /// 审核后返回的列表
+    pub tab: String,
+}
+
+pub fn comment_status(s: &str) -> Option<CommentStatus> {
+    match s {
+        "pending" => Some(CommentStatus::Pending),
+        "approved" => Some(CommentStatus::Approved),
+        "spam" => Some(CommentStatus::Spam),
+        "deleted" => Some(CommentStatus::Deleted),
+        _ => None,
+    }
+}
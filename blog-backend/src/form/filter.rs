@@ -1,4 +1,4 @@
-use blog_proto::ListCategoryRequest;
+use blog_proto::{ListCategoryRequest, ListTopicRequest, TopicSort};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -8,11 +8,11 @@ pub struct CateListFilter {
     pub msg: Option<String>,
 }
 
-impl Into<ListCategoryRequest> for CateListFilter {
-    fn into(self) -> ListCategoryRequest {
-        ListCategoryRequest {
-            name: self.name,
-            is_del: match self.is_del {
+impl From<CateListFilter> for ListCategoryRequest {
+    fn from(f: CateListFilter) -> Self {
+        Self {
+            name: f.name,
+            is_del: match f.is_del {
                 Some(s) => {
                     if s.is_empty() {
                         None
@@ -22,6 +22,41 @@ impl Into<ListCategoryRequest> for CateListFilter {
                 }
                 None => None,
             },
+            with_stats: Some(true),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct TopicListFilter {
+    pub page: Option<i32>,
+    pub is_del: Option<String>,
+    pub keyword: Option<String>,
+    /// 排序方式，word_count 为按字数倒序，默认按发布时间
+    pub sort: Option<String>,
+    pub msg: Option<String>,
+}
+
+impl From<TopicListFilter> for ListTopicRequest {
+    fn from(f: TopicListFilter) -> Self {
+        Self {
+            page: f.page,
+            keyword: f.keyword.filter(|k| !k.is_empty()),
+            is_del: match f.is_del {
+                Some(s) => {
+                    if s.is_empty() {
+                        None
+                    } else {
+                        Some(&s == "true")
+                    }
+                }
+                None => None,
+            },
+            sort: match f.sort.as_deref() {
+                Some("word_count") => Some(TopicSort::WordCount.into()),
+                _ => None,
+            },
+            ..Default::default()
         }
     }
 }
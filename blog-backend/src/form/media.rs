@@ -0,0 +1,35 @@
+use blog_proto::ListMediaRequest;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct MediaFilter {
+    pub page: Option<i32>,
+    /// image / attachment，默认为全部
+    pub kind: Option<String>,
+    /// 是否作为文章编辑页的媒体选择器打开
+    pub picker: Option<bool>,
+    pub msg: Option<String>,
+}
+
+impl MediaFilter {
+    pub fn kind(&self) -> &str {
+        self.kind.as_deref().unwrap_or("")
+    }
+
+    pub fn picker(&self) -> bool {
+        self.picker.unwrap_or_default()
+    }
+}
+
+impl From<&MediaFilter> for ListMediaRequest {
+    fn from(f: &MediaFilter) -> Self {
+        Self {
+            page: f.page,
+            is_image: match f.kind() {
+                "image" => Some(true),
+                "attachment" => Some(false),
+                _ => None,
+            },
+        }
+    }
+}
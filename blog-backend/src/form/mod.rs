@@ -1,6 +1,22 @@
 mod auth;
 mod cate;
+mod comment;
 mod filter;
+mod media;
+mod pin;
+mod profile;
+mod series;
+mod tag;
+mod topic;
+mod trash;
 pub use auth::*;
 pub use cate::*;
-pub use filter::*;
\ No newline at end of file
+pub use comment::*;
+pub use filter::*;
+pub use media::*;
+pub use pin::*;
+pub use profile::*;
+pub use series::*;
+pub use tag::*;
+pub use topic::*;
+pub use trash::*;
\ No newline at end of file
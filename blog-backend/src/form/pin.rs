@@ -0,0 +1,41 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct PinnedArgs {
+    pub msg: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct PinForm {
+    /// global / category / none
+    pub scope: String,
+    /// 操作完成后返回的页面：pinned 为置顶文章页面，否则为文章列表
+    pub back: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct FeatureForm {
+    pub featured: bool,
+}
+
+/// 调整置顶顺序，表单中每篇文章的顺序为 order_{id}={顺序}
+pub struct ReorderPinnedForm {
+    /// 按新的顺序排列的文章ID，顺序相同时保持页面中的先后
+    pub ids: Vec<i64>,
+}
+
+impl From<Vec<(String, String)>> for ReorderPinnedForm {
+    fn from(fields: Vec<(String, String)>) -> Self {
+        let mut orders: Vec<(i64, i64)> = fields
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let id = key.strip_prefix("order_")?.parse().ok()?;
+                Some((value.trim().parse().unwrap_or(i64::MAX), id))
+            })
+            .collect();
+        orders.sort_by_key(|&(order, _)| order);
+        Self {
+            ids: orders.into_iter().map(|(_, id)| id).collect(),
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct ProfileArgs {
+    pub msg: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ProfileForm {
+    pub display_name: String,
+    pub avatar: String,
+    pub bio: String,
+}
@@ -0,0 +1,46 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct SeriesForm {
+    pub title: String,
+    pub description: String,
+}
+
+/// 设置系列中的文章。已有文章的顺序为 order_{id}={顺序}，顺序留空表示移出系列；
+/// add 中是要追加到最后的文章ID，用逗号、空格或换行分隔
+pub struct SeriesTopicsForm {
+    pub topic_ids: Vec<i64>,
+}
+
+impl TryFrom<Vec<(String, String)>> for SeriesTopicsForm {
+    type Error = String;
+
+    fn try_from(fields: Vec<(String, String)>) -> Result<Self, Self::Error> {
+        let mut orders: Vec<(i64, i64)> = vec![];
+        let mut added: Vec<i64> = vec![];
+        for (key, value) in fields {
+            let value = value.trim();
+            if key == "add" {
+                for id in value
+                    .split(|c: char| c == ',' || c == '，' || c.is_whitespace())
+                    .filter(|s| !s.is_empty())
+                {
+                    added.push(id.parse().map_err(|_| format!("错误的文章ID：{}", id))?);
+                }
+            } else if let Some(id) = key.strip_prefix("order_") {
+                if value.is_empty() {
+                    continue;
+                }
+                let id = id.parse().map_err(|_| format!("错误的文章ID：{}", id))?;
+                let order = value
+                    .parse()
+                    .map_err(|_| format!("错误的顺序：{}", value))?;
+                orders.push((order, id));
+            }
+        }
+        orders.sort_by_key(|&(order, _)| order);
+        let mut topic_ids: Vec<i64> = orders.into_iter().map(|(_, id)| id).collect();
+        topic_ids.extend(added);
+        Ok(Self { topic_ids })
+    }
+}
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct TagName {
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+pub struct MergeTag {
+    pub from_id: i32,
+    pub to_id: i32,
+}
@@ -0,0 +1,135 @@
+use blog_proto::TopicStatus;
+use chrono::{Local, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize)]
+pub struct DiffRevision {
+    pub from: i64,
+    pub to: i64,
+}
+
+#[derive(Deserialize)]
+pub struct RestoreRevision {
+    pub topic_id: i64,
+    /// 恢复前读取到的文章版本号
+    pub version: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct MsgArgs {
+    pub msg: Option<String>,
+}
+
+/// 批量操作，表单中的 ids 会出现多次，所以先解析为键值对
+pub struct BulkTopicForm {
+    pub ids: Vec<i64>,
+    /// delete / restore / move
+    pub action: String,
+    pub category_id: Option<i32>,
+}
+
+impl From<Vec<(String, String)>> for BulkTopicForm {
+    fn from(fields: Vec<(String, String)>) -> Self {
+        let mut form = Self {
+            ids: vec![],
+            action: String::new(),
+            category_id: None,
+        };
+        for (key, value) in fields {
+            match key.as_str() {
+                "ids" => form.ids.extend(value.parse::<i64>().ok()),
+                "action" => form.action = value,
+                "category_id" => form.category_id = value.parse().ok(),
+                _ => {}
+            }
+        }
+        form
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct TopicForm {
+    pub title: String,
+    pub category_id: i32,
+    pub summary: String,
+    pub content: String,
+    pub status: String,
+    pub publish_at: String,
+    pub dateline: String,
+    pub tags: String,
+    pub slug: String,
+    /// 编辑时读取到的版本号，添加文章时没有
+    #[serde(default)]
+    pub version: Option<i32>,
+}
+
+impl TopicForm {
+    pub fn summary(&self) -> Option<String> {
+        let summary = self.summary.trim();
+        if summary.is_empty() {
+            None
+        } else {
+            Some(summary.to_string())
+        }
+    }
+
+    pub fn status(&self) -> TopicStatus {
+        match self.status.as_str() {
+            "draft" => TopicStatus::Draft,
+            "scheduled" => TopicStatus::Scheduled,
+            _ => TopicStatus::Published,
+        }
+    }
+
+    pub fn publish_at(&self) -> Option<prost_types::Timestamp> {
+        parse_datetime_local(&self.publish_at)
+    }
+
+    pub fn dateline(&self) -> Option<prost_types::Timestamp> {
+        parse_datetime_local(&self.dateline)
+    }
+
+    pub fn slug(&self) -> Option<String> {
+        let slug = self.slug.trim();
+        if slug.is_empty() {
+            None
+        } else {
+            Some(slug.to_string())
+        }
+    }
+
+    /// 多个标签之间用逗号分隔
+    pub fn tags(&self) -> Vec<String> {
+        self.tags
+            .split([',', '，'])
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+}
+
+/// 解析 `<input type="datetime-local">` 提交的本地时间
+fn parse_datetime_local(s: &str) -> Option<prost_types::Timestamp> {
+    let dt = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M").ok()?;
+    let dt = Local.from_local_datetime(&dt).single()?;
+    Some(prost_types::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_datetime_local_round_trip() {
+        // 编辑页面按本地时间回填发布时间，不做修改直接提交后应保持不变
+        let dt = Local.with_ymd_and_hms(2023, 5, 20, 10, 0, 0).unwrap();
+        let dateline = blog_types::Dateline::from(dt);
+        let ts = parse_datetime_local(&dateline.format("%Y-%m-%dT%H:%M")).unwrap();
+        assert_eq!(ts.seconds, dt.timestamp());
+        assert_eq!(blog_types::Dateline::from(ts).timestamp, dateline.timestamp);
+        assert!(parse_datetime_local("").is_none());
+    }
+}
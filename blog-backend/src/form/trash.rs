@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct TrashFilter {
+    /// topic / cate / admin，默认为文章
+    pub kind: Option<String>,
+    pub page: Option<i32>,
+    pub msg: Option<String>,
+}
+
+impl TrashFilter {
+    pub fn kind(&self) -> &str {
+        self.kind.as_deref().unwrap_or("topic")
+    }
+}
+
+/// 回收站的批量操作，ids 可以重复出现
+pub struct TrashForm {
+    pub ids: Vec<i64>,
+    /// restore / purge
+    pub action: String,
+}
+
+impl From<Vec<(String, String)>> for TrashForm {
+    fn from(fields: Vec<(String, String)>) -> Self {
+        let mut form = Self {
+            ids: vec![],
+            action: String::new(),
+        };
+        for (key, value) in fields {
+            match key.as_str() {
+                "ids" => form.ids.extend(value.parse::<i64>().ok()),
+                "action" => form.action = value,
+                _ => {}
+            }
+        }
+        form
+    }
+}
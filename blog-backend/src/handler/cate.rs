@@ -1,17 +1,21 @@
 use std::sync::Arc;
 
 use axum::{
-    extract::Query,
+    extract::{Path, Query},
     http::{HeaderMap, StatusCode},
     response::Html,
     Extension, Form,
 };
-use blog_proto::ListCategoryReply;
+use blog_proto::{
+    CategoryDeleteMode, EditCategoryRequest, GetCategoryRequest, ListCategoryReply,
+    ListCategoryRequest, ListCategoryTreeRequest, ListTopicRequest, ReorderCategoriesRequest,
+    ToggleCategoryRequest,
+};
 use tera::Context;
 
-use crate::{form, model::AppState};
+use crate::{form, middleware::Auth, model::AppState};
 
-use super::redirect;
+use super::{redirect, redirect_with_msg, topic::cate_list};
 
 pub async fn list_cate(
     Extension(state): Extension<Arc<AppState>>,
@@ -23,24 +27,40 @@ pub async fn list_cate(
         context.insert("msg", &msg);
     }
     let mut cate = state.cate.clone();
-    let resp = cate.list_category(tonic::Request::new(params.into())).await;
-    let reply = match resp {
-        Ok(r) => r.into_inner(),
-        Err(e) => {
-            if e.code() == tonic::Code::NotFound {
-                ListCategoryReply { categories: vec![] }
-            } else {
-                return Err(e.to_string());
+    let searching = params.name.as_deref().is_some_and(|name| !name.is_empty());
+    let req: ListCategoryRequest = params.into();
+    // 没有按名称搜索时按层级显示
+    let cate_list = if searching {
+        let resp = cate.list_category(tonic::Request::new(req)).await;
+        let reply = match resp {
+            Ok(r) => r.into_inner(),
+            Err(e) => {
+                if e.code() == tonic::Code::NotFound {
+                    ListCategoryReply { categories: vec![] }
+                } else {
+                    return Err(e.to_string());
+                }
             }
+        };
+        let mut cate_list = Vec::with_capacity(reply.categories.len());
+        for c in reply.categories {
+            let tc: blog_types::Category = c.into();
+            cate_list.push(tc);
         }
+        cate_list
+    } else {
+        let reply = cate
+            .list_category_tree(tonic::Request::new(ListCategoryTreeRequest {
+                is_del: req.is_del,
+                with_stats: req.with_stats,
+            }))
+            .await
+            .map_err(|e| e.to_string())?
+            .into_inner();
+        blog_types::flatten_tree(reply.nodes)
     };
-
-    let mut cate_list = Vec::with_capacity(reply.categories.len());
-    for c in reply.categories {
-        let tc: blog_types::Category = c.into();
-        cate_list.push(tc);
-    }
     context.insert("cate_list", &cate_list);
+    context.insert("sortable", &!searching);
 
     let out = state
         .tera
@@ -53,7 +73,8 @@ pub async fn list_cate(
 pub async fn add_cate_ui(
     Extension(state): Extension<Arc<AppState>>,
 ) -> Result<Html<String>, String> {
-    let context = Context::new();
+    let mut context = Context::new();
+    context.insert("parent_list", &parent_list(&state, None).await?);
     let out = state
         .tera
         .render("cate/add.html", &context)
@@ -68,11 +89,230 @@ pub async fn add_cate(
     let mut cate = state.cate.clone();
     let resp = cate
         .create_category(tonic::Request::new(blog_proto::CreateCategoryRequest {
+            parent_id: form.parent_id(),
             name: form.name,
         }))
         .await
         .map_err(|e| e.to_string())?;
     let reply = resp.into_inner();
-    let url = format!("/m/cate?msg=分类(ID为{})添加成功", reply.id);
-    Ok(redirect(&url))
+    let msg = format!("分类(ID为{})添加成功", reply.id);
+    Ok(redirect_with_msg("/m/cate", &msg))
+}
+
+pub async fn edit_cate_ui(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    let mut cate = state.cate.clone();
+    let category: blog_types::Category = cate
+        .get_category(tonic::Request::new(GetCategoryRequest { id, is_del: None }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner()
+        .category
+        .ok_or("不存在的分类")?
+        .into();
+    context.insert("cate", &category);
+    context.insert("parent_list", &parent_list(&state, Some(id)).await?);
+    let out = state
+        .tera
+        .render("cate/edit.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn edit_cate(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Form(form): Form<form::EditCategory>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut cate = state.cate.clone();
+    let msg = match cate
+        .edit_category(tonic::Request::new(EditCategoryRequest {
+            id,
+            parent_id: form.parent_id(),
+            // 选择“无”时改为顶级分类
+            clear_parent: form.parent_id().is_none(),
+            name: form.name,
+            version: form.version,
+        }))
+        .await
+    {
+        Ok(reply) if reply.get_ref().ok => format!("分类(ID为{})修改成功", id),
+        Ok(_) => "不存在的分类".to_string(),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/cate", &msg))
+}
+
+/// 和同一上级分类下相邻的分类交换位置
+pub async fn move_cate(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Form(form): Form<form::MoveCategory>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut cate = state.cate.clone();
+    let reply = cate
+        .list_category_tree(tonic::Request::new(ListCategoryTreeRequest {
+            is_del: None,
+            with_stats: None,
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let mut list = blog_types::flatten_tree(reply.nodes);
+    let moved = match form.direction.as_str() {
+        "up" => move_up(&mut list, id),
+        "down" => match list.iter().position(|c| c.id == id) {
+            // 下移等于把下一个同级分类上移
+            Some(pos) => match list[subtree_end(&list, pos)..].first() {
+                Some(next) if next.depth == list[pos].depth => {
+                    let next_id = next.id;
+                    move_up(&mut list, next_id)
+                }
+                _ => false,
+            },
+            None => false,
+        },
+        _ => return Err("错误的移动方向".to_string()),
+    };
+    if !moved {
+        return Ok(redirect("/m/cate"));
+    }
+    // 按展开后的顺序重新设置所有分类的排序权重
+    let msg = match cate
+        .reorder_categories(tonic::Request::new(ReorderCategoriesRequest {
+            ids: list.iter().map(|c| c.id).collect(),
+        }))
+        .await
+    {
+        Ok(_) => "已调整分类顺序".to_string(),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/cate", &msg))
+}
+
+/// 先序展开的分类列表中，分类及其下级分类之后的位置
+fn subtree_end(list: &[blog_types::Category], pos: usize) -> usize {
+    let depth = list[pos].depth;
+    list[pos + 1..]
+        .iter()
+        .position(|c| c.depth <= depth)
+        .map_or(list.len(), |n| pos + 1 + n)
+}
+
+/// 把分类连同下级分类移到上一个同级分类之前，没有上一个同级分类时返回 false
+fn move_up(list: &mut [blog_types::Category], id: i32) -> bool {
+    let Some(pos) = list.iter().position(|c| c.id == id) else {
+        return false;
+    };
+    let depth = list[pos].depth;
+    let Some(prev) = list[..pos].iter().rposition(|c| c.depth <= depth) else {
+        return false;
+    };
+    if list[prev].depth != depth {
+        return false;
+    }
+    let end = subtree_end(list, pos);
+    list[prev..end].rotate_left(pos - prev);
+    true
+}
+
+/// 可以作为上级分类的分类，按层级展开。修改分类时排除分类自己和它的下级分类
+async fn parent_list(
+    state: &AppState,
+    exclude: Option<i32>,
+) -> Result<Vec<blog_types::Category>, String> {
+    let mut cate = state.cate.clone();
+    let reply = cate
+        .list_category_tree(tonic::Request::new(ListCategoryTreeRequest {
+            is_del: Some(false),
+            with_stats: None,
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let mut list = blog_types::flatten_tree(reply.nodes);
+    if let Some(pos) = list.iter().position(|c| Some(c.id) == exclude) {
+        let end = subtree_end(&list, pos);
+        list.drain(pos..end);
+    }
+    Ok(list)
+}
+
+/// 删除分类前确认，分类中还有文章时选择处理方式
+pub async fn del_cate_ui(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    let mut cate = state.cate.clone();
+    let category: blog_types::Category = cate
+        .get_category(tonic::Request::new(GetCategoryRequest {
+            id,
+            is_del: Some(false),
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner()
+        .category
+        .ok_or("分类不存在或已删除")?
+        .into();
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .list_topic(tonic::Request::new(ListTopicRequest {
+            category_id: Some(id),
+            is_del: Some(false),
+            page_size: Some(1),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    // 可以移动到的其它分类
+    let cate_list: Vec<blog_types::Category> = cate_list(&state)
+        .await?
+        .into_iter()
+        .filter(|c| !c.is_del && c.id != id)
+        .collect();
+    context.insert("cate", &category);
+    context.insert("topic_count", &reply.record_total);
+    context.insert("cate_list", &cate_list);
+    let out = state
+        .tera
+        .render("cate/del.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn del_cate(
+    Extension(state): Extension<Arc<AppState>>,
+    Auth(claims): Auth,
+    Path(id): Path<i32>,
+    Form(form): Form<form::DeleteCategory>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let delete_mode = match form.mode.as_str() {
+        "block" => CategoryDeleteMode::Block,
+        "move" => CategoryDeleteMode::Move,
+        "hide" => CategoryDeleteMode::Hide,
+        _ => return Err("错误的删除方式".to_string()),
+    };
+    let mut cate = state.cate.clone();
+    let msg = match cate
+        .toggle_category(tonic::Request::new(ToggleCategoryRequest {
+            id,
+            admin_id: Some(claims.id),
+            delete_mode: delete_mode.into(),
+            move_to: form.move_to,
+        }))
+        .await
+    {
+        Ok(reply) => match reply.into_inner().moved {
+            0 => format!("分类(ID为{})已删除", id),
+            moved => format!("分类(ID为{})已删除，{}篇文章已移动到其它分类", id, moved),
+        },
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/cate", &msg))
 }
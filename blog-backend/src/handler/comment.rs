@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::Html,
+    Extension, Form,
+};
+use blog_proto::ModerateCommentRequest;
+use tera::Context;
+
+use crate::{form, model::AppState};
+
+use super::redirect_with_msg;
+
+pub async fn list_comment(
+    Extension(state): Extension<Arc<AppState>>,
+    Query(params): Query<form::CommentListFilter>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = params.msg.clone() {
+        context.insert("msg", &msg);
+    }
+    context.insert("status", params.status());
+    context.insert("topic_id", &params.topic_id());
+
+    let mut comment = state.comment.clone();
+    let reply = comment
+        .list_comment(tonic::Request::new(params.into()))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let comment_list: Vec<blog_types::Comment> =
+        reply.comments.into_iter().map(|c| c.into()).collect();
+    let paginate = blog_types::Paginate {
+        page: reply.page,
+        page_size: reply.page_size,
+        page_totoal: reply.page_totoal,
+        record_total: reply.record_total,
+        data: comment_list,
+        next_cursor: None,
+        prev_cursor: None,
+    };
+    context.insert("paginate", &paginate);
+    let page_nums: Vec<i64> = (0..paginate.page_totoal).collect();
+    context.insert("page_nums", &page_nums);
+
+    let out = state
+        .tera
+        .render("comment/index.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn moderate_comment(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i64>,
+    Form(form): Form<form::ModerateComment>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let status = match form::comment_status(&form.status) {
+        Some(status) => status,
+        None => return Err("错误的评论状态".to_string()),
+    };
+    let mut comment = state.comment.clone();
+    comment
+        .moderate_comment(tonic::Request::new(ModerateCommentRequest {
+            ids: vec![id],
+            status: status.into(),
+        }))
+        .await
+        .map_err(|e| e.to_string())?;
+    let tab = match form.tab.as_str() {
+        "all" => "all",
+        tab => form::comment_status(tab)
+            .map(blog_types::comment_status_name)
+            .unwrap_or("pending"),
+    };
+    let url = format!("/m/comment?status={}", tab);
+    Ok(redirect_with_msg(&url, &format!("评论(ID为{})已更新", id)))
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Multipart, Query},
+    http::{HeaderMap, StatusCode},
+    response::Html,
+    Extension,
+};
+use blog_proto::UploadMediaRequest;
+use tera::Context;
+
+use crate::{form, middleware::Auth, model::AppState};
+
+use super::redirect_with_msg;
+
+/// 一次上传的最大字节数，单个文件的大小由 media-srv 限制
+pub const UPLOAD_BODY_LIMIT: usize = 50 * 1024 * 1024;
+
+pub async fn list_media(
+    Extension(state): Extension<Arc<AppState>>,
+    Query(params): Query<form::MediaFilter>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = params.msg.clone() {
+        context.insert("msg", &msg);
+    }
+    context.insert("kind", params.kind());
+    context.insert("picker", &params.picker());
+    context.insert("media_url", &state.media_url);
+
+    let mut media = state.media.clone();
+    let reply = media
+        .list_media(tonic::Request::new((&params).into()))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let media_list: Vec<blog_types::Media> = reply.media.into_iter().map(|m| m.into()).collect();
+    context.insert("media_list", &media_list);
+    context.insert("page", &reply.page);
+    let page_nums: Vec<i64> = (0..reply.page_totoal).collect();
+    context.insert("page_nums", &page_nums);
+
+    let out = state
+        .tera
+        .render("media/index.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn upload_media(
+    Extension(state): Extension<Arc<AppState>>,
+    Auth(claims): Auth,
+    Query(params): Query<form::MediaFilter>,
+    mut multipart: Multipart,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut media = state.media.clone();
+    let (mut uploaded, mut duplicated) = (0, 0);
+    let mut errors = vec![];
+    while let Some(field) = multipart.next_field().await.map_err(|e| e.to_string())? {
+        if field.name() != Some("file") {
+            continue;
+        }
+        let filename = field.file_name().unwrap_or_default().to_string();
+        let data = field.bytes().await.map_err(|e| e.to_string())?;
+        // 没有选择文件
+        if data.is_empty() && filename.is_empty() {
+            continue;
+        }
+        let resp = media
+            .upload_media(tonic::Request::new(UploadMediaRequest {
+                filename: filename.clone(),
+                data: data.into(),
+                uploaded_by: Some(claims.id),
+            }))
+            .await;
+        match resp {
+            Ok(reply) => {
+                uploaded += 1;
+                if reply.into_inner().duplicated {
+                    duplicated += 1;
+                }
+            }
+            Err(e) => errors.push(format!("{}：{}", filename, e.message())),
+        }
+    }
+
+    let mut msgs = vec![];
+    if duplicated > 0 {
+        msgs.push(format!(
+            "上传了{}个文件，其中{}个已存在",
+            uploaded, duplicated
+        ));
+    } else if uploaded > 0 {
+        msgs.push(format!("上传了{}个文件", uploaded));
+    }
+    msgs.extend(errors);
+    if msgs.is_empty() {
+        msgs.push("请选择文件".to_string());
+    }
+    let msg = msgs.join("；");
+    let url = if params.picker() {
+        "/m/media?picker=true"
+    } else {
+        "/m/media"
+    };
+    Ok(redirect_with_msg(url, &msg))
+}
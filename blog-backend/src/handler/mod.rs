@@ -2,10 +2,26 @@ use axum::http::{HeaderMap, StatusCode};
 
 pub use auth::*;
 pub use cate::*;
+pub use comment::*;
+pub use media::*;
+pub use pin::*;
+pub use profile::*;
+pub use series::*;
+pub use tag::*;
+pub use topic::*;
+pub use trash::*;
 
 mod auth;
 mod cate;
+mod comment;
 pub mod cookie;
+mod media;
+mod pin;
+mod profile;
+mod series;
+mod tag;
+mod topic;
+mod trash;
 
 pub async fn index() -> (StatusCode, HeaderMap) {
     redirect("/login")
@@ -15,6 +31,17 @@ pub fn redirect(url: &str) -> (StatusCode, HeaderMap) {
     redirect_with_cookie(url, None)
 }
 
+/// 跳转并通过查询参数 msg 显示提示信息，提示信息中可能有 `&`、`#` 等字符，需要编码
+pub fn redirect_with_msg(url: &str, msg: &str) -> (StatusCode, HeaderMap) {
+    let sep = if url.contains('?') { '&' } else { '?' };
+    redirect(&format!(
+        "{}{}msg={}",
+        url,
+        sep,
+        blog_utils::url::percent_encode(msg)
+    ))
+}
+
 pub fn redirect_with_cookie(url: &str, cookie: Option<&str>) -> (StatusCode, HeaderMap) {
     let mut headers = HeaderMap::new();
     headers.insert(axum::http::header::LOCATION, url.parse().unwrap());
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::Html,
+    Extension, Form,
+};
+use blog_proto::{
+    FeatureTopicRequest, ListPinnedTopicsRequest, PinScope, PinTopicRequest,
+    ReorderPinnedTopicsRequest,
+};
+use tera::Context;
+
+use crate::{form, model::AppState};
+
+use super::{redirect_with_msg, topic::cate_list};
+
+/// 置顶的文章，可以调整顺序和取消置顶
+pub async fn pinned_ui(
+    Extension(state): Extension<Arc<AppState>>,
+    Query(args): Query<form::PinnedArgs>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = args.msg {
+        context.insert("msg", &msg);
+    }
+    let cate_list = cate_list(&state).await?;
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .list_pinned_topics(tonic::Request::new(ListPinnedTopicsRequest {}))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let mut topic_list = Vec::with_capacity(reply.topics.len());
+    for t in reply.topics {
+        let mut t: blog_types::Topic = t.into();
+        if let Some(c) = cate_list.iter().find(|c| c.id == t.category_id) {
+            t.category_name = c.name.clone();
+        }
+        topic_list.push(t);
+    }
+    context.insert("topic_list", &topic_list);
+    let out = state
+        .tera
+        .render("topic/pinned.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn pin_topic(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i64>,
+    Form(form): Form<form::PinForm>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let scope = match form.scope.as_str() {
+        "global" => PinScope::Global,
+        "category" => PinScope::Category,
+        "none" => PinScope::None,
+        _ => return Err("错误的置顶范围".to_string()),
+    };
+    let back = match form.back.as_deref() {
+        Some("pinned") => "/m/topic/pinned",
+        _ => "/m/topic",
+    };
+    let mut topic = state.topic.clone();
+    let msg = match topic
+        .pin_topic(tonic::Request::new(PinTopicRequest {
+            id,
+            scope: scope.into(),
+        }))
+        .await
+    {
+        Ok(reply) if reply.get_ref().ok => match scope {
+            PinScope::None => "已取消置顶",
+            _ => "已置顶",
+        }
+        .to_string(),
+        Ok(_) => "不存在的文章".to_string(),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg(back, &msg))
+}
+
+pub async fn reorder_pinned(
+    Extension(state): Extension<Arc<AppState>>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let form: form::ReorderPinnedForm = fields.into();
+    let mut topic = state.topic.clone();
+    let msg = match topic
+        .reorder_pinned_topics(tonic::Request::new(ReorderPinnedTopicsRequest {
+            ids: form.ids,
+        }))
+        .await
+    {
+        Ok(reply) => format!("已调整{}篇文章的顺序", reply.into_inner().updated),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/topic/pinned", &msg))
+}
+
+pub async fn feature_topic(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i64>,
+    Form(form): Form<form::FeatureForm>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut topic = state.topic.clone();
+    let msg = match topic
+        .feature_topic(tonic::Request::new(FeatureTopicRequest {
+            id,
+            featured: form.featured,
+        }))
+        .await
+    {
+        Ok(reply) if reply.get_ref().ok => {
+            if form.featured {
+                "已推荐"
+            } else {
+                "已取消推荐"
+            }
+        }
+        .to_string(),
+        Ok(_) => "不存在的文章".to_string(),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/topic", &msg))
+}
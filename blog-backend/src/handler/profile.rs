@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::Query,
+    http::{HeaderMap, StatusCode},
+    response::Html,
+    Extension, Form,
+};
+use blog_proto::{
+    get_admin_request::{ById, Condition},
+    EditAdminProfileRequest, GetAdminRequest,
+};
+use tera::Context;
+
+use crate::{form, middleware::Auth, model::AppState};
+
+use super::redirect_with_msg;
+
+/// 当前登录的管理员的个人资料，显示在前台的作者页面中
+pub async fn profile_ui(
+    Extension(state): Extension<Arc<AppState>>,
+    Auth(claims): Auth,
+    Query(args): Query<form::ProfileArgs>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = args.msg {
+        context.insert("msg", &msg);
+    }
+    let mut admin = state.admin.clone();
+    let reply = admin
+        .get_admin(tonic::Request::new(GetAdminRequest {
+            condition: Some(Condition::ById(ById {
+                id: claims.id,
+                is_del: Some(false),
+            })),
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let admin = match reply.admin {
+        Some(admin) => admin,
+        None => return Err("管理员不存在".to_string()),
+    };
+    context.insert("id", &admin.id);
+    context.insert("email", &admin.email);
+    context.insert("display_name", &admin.display_name);
+    context.insert("avatar", &admin.avatar);
+    context.insert("bio", &admin.bio);
+    context.insert("media_url", &state.media_url);
+    let out = state
+        .tera
+        .render("admin/profile.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn profile(
+    Extension(state): Extension<Arc<AppState>>,
+    Auth(claims): Auth,
+    Form(form): Form<form::ProfileForm>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut admin = state.admin.clone();
+    let msg = match admin
+        .edit_admin_profile(tonic::Request::new(EditAdminProfileRequest {
+            id: claims.id,
+            display_name: form.display_name,
+            avatar: form.avatar,
+            bio: form.bio,
+        }))
+        .await
+    {
+        Ok(_) => "个人资料已保存".to_string(),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/profile", &msg))
+}
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::Html,
+    Extension, Form,
+};
+use blog_proto::{
+    CreateSeriesRequest, DeleteSeriesRequest, EditSeriesRequest, GetSeriesRequest,
+    ListSeriesRequest, SetSeriesTopicsRequest,
+};
+use tera::Context;
+
+use crate::{form, model::AppState};
+
+use super::{redirect_with_msg, topic::cate_list};
+
+pub async fn list_series(
+    Extension(state): Extension<Arc<AppState>>,
+    Query(params): Query<form::MsgArgs>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = params.msg {
+        context.insert("msg", &msg);
+    }
+    let mut series = state.series.clone();
+    let reply = series
+        .list_series(tonic::Request::new(ListSeriesRequest {}))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let series_list: Vec<blog_types::Series> = reply.series.into_iter().map(|s| s.into()).collect();
+    context.insert("series_list", &series_list);
+
+    let out = state
+        .tera
+        .render("series/index.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn add_series(
+    Extension(state): Extension<Arc<AppState>>,
+    Form(form): Form<form::SeriesForm>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut series = state.series.clone();
+    let msg = match series
+        .create_series(tonic::Request::new(CreateSeriesRequest {
+            title: form.title,
+            description: form.description,
+        }))
+        .await
+    {
+        Ok(reply) => format!("系列(ID为{})添加成功", reply.into_inner().id),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/series", &msg))
+}
+
+/// 修改系列，并管理系列中的文章和顺序
+pub async fn edit_series_ui(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Query(params): Query<form::MsgArgs>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = params.msg {
+        context.insert("msg", &msg);
+    }
+    let cate_list = cate_list(&state).await?;
+    let mut series = state.series.clone();
+    let reply = series
+        .get_series(tonic::Request::new(GetSeriesRequest {
+            id,
+            is_del: None,
+            status: None,
+            category_is_del: None,
+        }))
+        .await
+        .map_err(|e| e.message().to_string())?
+        .into_inner();
+    let series: blog_types::Series = reply.series.ok_or("不存在的系列")?.into();
+    let mut topic_list = Vec::with_capacity(reply.topics.len());
+    for t in reply.topics {
+        let mut t: blog_types::Topic = t.into();
+        if let Some(c) = cate_list.iter().find(|c| c.id == t.category_id) {
+            t.category_name = c.name.clone();
+        }
+        topic_list.push(t);
+    }
+    context.insert("series", &series);
+    context.insert("topic_list", &topic_list);
+
+    let out = state
+        .tera
+        .render("series/edit.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn edit_series(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Form(form): Form<form::SeriesForm>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut series = state.series.clone();
+    let msg = match series
+        .edit_series(tonic::Request::new(EditSeriesRequest {
+            id,
+            title: form.title,
+            description: form.description,
+        }))
+        .await
+    {
+        Ok(reply) if reply.get_ref().ok => "修改成功".to_string(),
+        Ok(_) => "不存在的系列".to_string(),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg(&format!("/m/series/edit/{}", id), &msg))
+}
+
+pub async fn set_series_topics(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let form: form::SeriesTopicsForm = match fields.try_into() {
+        Ok(form) => form,
+        Err(msg) => return Ok(redirect_with_msg(&format!("/m/series/edit/{}", id), &msg)),
+    };
+    let mut series = state.series.clone();
+    let msg = match series
+        .set_series_topics(tonic::Request::new(SetSeriesTopicsRequest {
+            series_id: id,
+            topic_ids: form.topic_ids,
+        }))
+        .await
+    {
+        Ok(reply) => format!("保存成功，系列共有{}篇文章", reply.into_inner().topic_count),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg(&format!("/m/series/edit/{}", id), &msg))
+}
+
+pub async fn delete_series(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut series = state.series.clone();
+    let msg = match series
+        .delete_series(tonic::Request::new(DeleteSeriesRequest { id }))
+        .await
+    {
+        Ok(reply) if reply.get_ref().ok => format!("系列(ID为{})已删除", id),
+        Ok(_) => "不存在的系列".to_string(),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/series", &msg))
+}
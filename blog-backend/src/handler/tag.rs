@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::Html,
+    Extension, Form,
+};
+use blog_proto::{CreateTagRequest, ListTagRequest, MergeTagRequest, RenameTagRequest};
+use tera::Context;
+
+use crate::{form, model::AppState};
+
+use super::redirect_with_msg;
+
+pub async fn list_tag(
+    Extension(state): Extension<Arc<AppState>>,
+    Query(params): Query<form::MsgArgs>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = params.msg {
+        context.insert("msg", &msg);
+    }
+    let mut tag = state.tag.clone();
+    let reply = tag
+        .list_tag(tonic::Request::new(ListTagRequest {
+            name: None,
+            is_del: Some(false),
+            status: None,
+            category_is_del: None,
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let tag_list: Vec<blog_types::Tag> = reply.tags.into_iter().map(|t| t.into()).collect();
+    context.insert("tag_list", &tag_list);
+
+    let out = state
+        .tera
+        .render("tag/index.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn add_tag(
+    Extension(state): Extension<Arc<AppState>>,
+    Form(form): Form<form::TagName>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut tag = state.tag.clone();
+    let msg = match tag
+        .create_tag(tonic::Request::new(CreateTagRequest { name: form.name }))
+        .await
+    {
+        Ok(reply) => format!("标签(ID为{})添加成功", reply.into_inner().id),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/tag", &msg))
+}
+
+pub async fn rename_tag(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Form(form): Form<form::TagName>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut tag = state.tag.clone();
+    let msg = match tag
+        .rename_tag(tonic::Request::new(RenameTagRequest {
+            id,
+            name: form.name,
+        }))
+        .await
+    {
+        Ok(_) => format!("标签(ID为{})重命名成功", id),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/tag", &msg))
+}
+
+pub async fn merge_tag(
+    Extension(state): Extension<Arc<AppState>>,
+    Form(form): Form<form::MergeTag>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    if form.from_id == form.to_id {
+        return Ok(redirect_with_msg("/m/tag", "不能合并到同一个标签"));
+    }
+    let mut tag = state.tag.clone();
+    let msg = match tag
+        .merge_tag(tonic::Request::new(MergeTagRequest {
+            from_ids: vec![form.from_id],
+            to_id: form.to_id,
+        }))
+        .await
+    {
+        Ok(reply) => format!(
+            "合并成功，目标标签共有{}篇文章",
+            reply.into_inner().topic_count
+        ),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/tag", &msg))
+}
@@ -0,0 +1,433 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::{Html, IntoResponse, Response},
+    Extension, Form,
+};
+use blog_proto::{
+    get_topic_request::Condition, BulkTopicReply, BulkTopicRequest, CreateTopicRequest,
+    DiffTopicRevisionRequest, EditTopicRequest, GetTopicRequest, GetTopicRevisionRequest,
+    ListCategoryRequest, ListTopicRevisionRequest, MoveTopicsRequest, RestoreTopicRevisionRequest,
+    TagNames,
+};
+use tera::Context;
+
+use crate::{form, middleware::Auth, model::AppState};
+
+use super::{redirect_with_msg, trash::admin_emails};
+
+pub async fn list_topic(
+    Extension(state): Extension<Arc<AppState>>,
+    Query(params): Query<form::TopicListFilter>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = params.msg.clone() {
+        context.insert("msg", &msg);
+    }
+    context.insert("keyword", &params.keyword.clone().unwrap_or_default());
+    context.insert("is_del", &params.is_del.clone().unwrap_or_default());
+    context.insert("sort", &params.sort.clone().unwrap_or_default());
+
+    let cate_list = cate_list(&state).await?;
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .list_topic(tonic::Request::new(params.into()))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let mut topic_list = Vec::with_capacity(reply.topics.len());
+    for t in reply.topics {
+        let mut t: blog_types::Topic = t.into();
+        if let Some(c) = cate_list.iter().find(|c| c.id == t.category_id) {
+            t.category_name = c.name.clone();
+        }
+        topic_list.push(t);
+    }
+    let paginate = blog_types::Paginate {
+        page: reply.page,
+        page_size: reply.page_size,
+        page_totoal: reply.page_totoal,
+        record_total: reply.record_total,
+        data: topic_list,
+        next_cursor: reply.next_cursor,
+        prev_cursor: reply.prev_cursor,
+    };
+    context.insert("paginate", &paginate);
+    let page_nums: Vec<i64> = (0..paginate.page_totoal).collect();
+    context.insert("page_nums", &page_nums);
+    context.insert("cate_list", &cate_list);
+
+    let out = state
+        .tera
+        .render("topic/index.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn add_topic_ui(
+    Extension(state): Extension<Arc<AppState>>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    context.insert("cate_list", &cate_list(&state).await?);
+    let out = state
+        .tera
+        .render("topic/add.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn add_topic(
+    Extension(state): Extension<Arc<AppState>>,
+    Auth(claims): Auth,
+    Form(form): Form<form::TopicForm>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .create_topic(tonic::Request::new(CreateTopicRequest {
+            summary: form.summary(),
+            status: Some(form.status().into()),
+            publish_at: form.publish_at(),
+            dateline: form.dateline(),
+            tags: form.tags(),
+            slug: form.slug(),
+            author_id: Some(claims.id),
+            title: form.title,
+            category_id: form.category_id,
+            content: form.content,
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let msg = format!("文章(ID为{})添加成功", reply.id);
+    Ok(redirect_with_msg("/m/topic", &msg))
+}
+
+pub async fn edit_topic_ui(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i64>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    context.insert("cate_list", &cate_list(&state).await?);
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .get_topic(tonic::Request::new(GetTopicRequest {
+            condition: Some(Condition::Id(id)),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let t: blog_types::Topic = match reply.topic {
+        Some(t) => t.into(),
+        None => return Err("文章不存在".to_string()),
+    };
+    if let Some(id) = t.last_edited_by {
+        let admins = admin_emails(&state).await?;
+        let email = admins
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| format!("#{}", id));
+        context.insert("last_edited_by", &email);
+    }
+    // datetime-local 输入框使用本地时间
+    context.insert("dateline", &t.dateline.format("%Y-%m-%dT%H:%M"));
+    if let Some(publish_at) = &t.publish_at {
+        context.insert("publish_at", &publish_at.format("%Y-%m-%dT%H:%M"));
+    }
+    context.insert("topic", &t);
+    let out = state
+        .tera
+        .render("topic/edit.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn edit_topic(
+    Extension(state): Extension<Arc<AppState>>,
+    Auth(claims): Auth,
+    Path(id): Path<i64>,
+    Form(form): Form<form::TopicForm>,
+) -> Result<Response, String> {
+    let mut topic = state.topic.clone();
+    let result = topic
+        .edit_topic(tonic::Request::new(EditTopicRequest {
+            id,
+            summary: form.summary(),
+            status: Some(form.status().into()),
+            publish_at: form.publish_at(),
+            dateline: form.dateline(),
+            tags: Some(TagNames { names: form.tags() }),
+            // 留空时根据标题重新生成
+            slug: Some(form.slug().unwrap_or_default()),
+            author_id: Some(claims.id),
+            title: form.title.clone(),
+            category_id: form.category_id,
+            content: form.content.clone(),
+            version: form.version,
+        }))
+        .await;
+    match result {
+        Ok(_) => {
+            let msg = format!("文章(ID为{})修改成功", id);
+            Ok(redirect_with_msg("/m/topic", &msg).into_response())
+        }
+        Err(e) => match blog_proto::current_version(&e) {
+            Some(current_version) => Ok(conflict_topic(&state, id, current_version, form)
+                .await?
+                .into_response()),
+            None => Err(e.to_string()),
+        },
+    }
+}
+
+/// 提交的修改基于旧的版本时，同时展示当前版本和提交的内容，由管理员决定是否覆盖
+async fn conflict_topic(
+    state: &AppState,
+    id: i64,
+    current_version: i32,
+    form: form::TopicForm,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    let cate_list = cate_list(state).await?;
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .get_topic(tonic::Request::new(GetTopicRequest {
+            condition: Some(Condition::Id(id)),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let mut t: blog_types::Topic = match reply.topic {
+        Some(t) => t.into(),
+        None => return Err("文章不存在".to_string()),
+    };
+    if let Some(c) = cate_list.iter().find(|c| c.id == t.category_id) {
+        t.category_name = c.name.clone();
+    }
+    if let Some(c) = cate_list.iter().find(|c| c.id == form.category_id) {
+        context.insert("mine_category_name", &c.name);
+    }
+    context.insert("topic", &t);
+    context.insert("current_version", &current_version);
+    context.insert("mine", &form);
+    let out = state
+        .tera
+        .render("topic/conflict.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn bulk_topic(
+    Extension(state): Extension<Arc<AppState>>,
+    Auth(claims): Auth,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let form: form::BulkTopicForm = fields.into();
+    if form.ids.is_empty() {
+        return Ok(redirect_with_msg("/m/topic", "请选择文章"));
+    }
+    let mut topic = state.topic.clone();
+    let resp = match (form.action.as_str(), form.category_id) {
+        ("delete", _) => {
+            topic
+                .delete_topics(tonic::Request::new(BulkTopicRequest {
+                    ids: form.ids,
+                    admin_id: Some(claims.id),
+                }))
+                .await
+        }
+        ("restore", _) => {
+            topic
+                .restore_topics(tonic::Request::new(BulkTopicRequest {
+                    ids: form.ids,
+                    admin_id: None,
+                }))
+                .await
+        }
+        ("move", Some(category_id)) => {
+            topic
+                .move_topics(tonic::Request::new(MoveTopicsRequest {
+                    ids: form.ids,
+                    category_id,
+                }))
+                .await
+        }
+        ("move", None) => return Ok(redirect_with_msg("/m/topic", "请选择分类")),
+        _ => return Err("错误的操作".to_string()),
+    };
+    let msg = match resp {
+        Ok(reply) => bulk_topic_msg(&reply.into_inner()),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg("/m/topic", &msg))
+}
+
+/// 批量操作的结果提示，列出失败的文章及原因
+pub(super) fn bulk_topic_msg(reply: &BulkTopicReply) -> String {
+    let failed: Vec<String> = reply
+        .results
+        .iter()
+        .filter(|r| !r.ok)
+        .map(|r| format!("ID为{}：{}", r.id, r.message))
+        .collect();
+    if failed.is_empty() {
+        format!("批量操作完成，修改了{}篇文章", reply.changed)
+    } else {
+        format!(
+            "批量操作完成，修改了{}篇文章，失败{}篇（{}）",
+            reply.changed,
+            failed.len(),
+            failed.join("；")
+        )
+    }
+}
+
+pub async fn list_revision(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(topic_id): Path<i64>,
+    Query(params): Query<form::MsgArgs>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = params.msg {
+        context.insert("msg", &msg);
+    }
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .list_topic_revision(tonic::Request::new(ListTopicRevisionRequest { topic_id }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let revision_list: Vec<blog_types::TopicRevision> =
+        reply.revisions.into_iter().map(|r| r.into()).collect();
+    context.insert("topic_id", &topic_id);
+    context.insert("topic_version", &topic_version(&state, topic_id).await?);
+    context.insert("revision_list", &revision_list);
+
+    let out = state
+        .tera
+        .render("topic/revision.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn view_revision(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i64>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .get_topic_revision(tonic::Request::new(GetTopicRevisionRequest { id }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let revision: blog_types::TopicRevision = match reply.revision {
+        Some(r) => r.into(),
+        None => return Err("修订不存在".to_string()),
+    };
+    context.insert(
+        "topic_version",
+        &topic_version(&state, revision.topic_id).await?,
+    );
+    context.insert("revision", &revision);
+
+    let out = state
+        .tera
+        .render("topic/revision_view.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn diff_revision(
+    Extension(state): Extension<Arc<AppState>>,
+    Query(params): Query<form::DiffRevision>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .diff_topic_revision(tonic::Request::new(DiffTopicRevisionRequest {
+            from_id: params.from.min(params.to),
+            to_id: params.from.max(params.to),
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    let from: blog_types::TopicRevision = reply.from.unwrap_or_default().into();
+    let to: blog_types::TopicRevision = reply.to.unwrap_or_default().into();
+    let title: Vec<blog_types::DiffLine> = reply.title.into_iter().map(|l| l.into()).collect();
+    let summary: Vec<blog_types::DiffLine> = reply.summary.into_iter().map(|l| l.into()).collect();
+    let content: Vec<blog_types::DiffLine> = reply.content.into_iter().map(|l| l.into()).collect();
+    context.insert("from", &from);
+    context.insert("to", &to);
+    context.insert("title_diff", &title);
+    context.insert("summary_diff", &summary);
+    context.insert("content_diff", &content);
+
+    let out = state
+        .tera
+        .render("topic/revision_diff.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn restore_revision(
+    Extension(state): Extension<Arc<AppState>>,
+    Auth(claims): Auth,
+    Path(id): Path<i64>,
+    Form(form): Form<form::RestoreRevision>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let mut topic = state.topic.clone();
+    let msg = match topic
+        .restore_topic_revision(tonic::Request::new(RestoreTopicRevisionRequest {
+            id,
+            version: form.version,
+            admin_id: Some(claims.id),
+        }))
+        .await
+    {
+        Ok(_) => format!("已恢复修订(ID为{})", id),
+        Err(e) => e.message().to_string(),
+    };
+    Ok(redirect_with_msg(
+        &format!("/m/topic/revision/{}", form.topic_id),
+        &msg,
+    ))
+}
+
+/// 文章当前的版本号，恢复修订时用于检测并发修改
+async fn topic_version(state: &AppState, id: i64) -> Result<i32, String> {
+    let mut topic = state.topic.clone();
+    let reply = topic
+        .get_topic(tonic::Request::new(GetTopicRequest {
+            condition: Some(Condition::Id(id)),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    Ok(reply.topic.map(|t| t.version).unwrap_or_default())
+}
+
+pub(super) async fn cate_list(state: &AppState) -> Result<Vec<blog_types::Category>, String> {
+    let mut cate = state.cate.clone();
+    let resp = cate
+        .list_category(tonic::Request::new(ListCategoryRequest {
+            name: None,
+            is_del: None,
+            with_stats: None,
+        }))
+        .await;
+    match resp {
+        Ok(r) => Ok(r
+            .into_inner()
+            .categories
+            .into_iter()
+            .map(|c| c.into())
+            .collect()),
+        Err(e) if e.code() == tonic::Code::NotFound => Ok(vec![]),
+        Err(e) => Err(e.to_string()),
+    }
+}
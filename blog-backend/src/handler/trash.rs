@@ -0,0 +1,281 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode},
+    response::Html,
+    Extension, Form,
+};
+use blog_proto::{
+    get_admin_request::{ById, Condition},
+    BulkTopicRequest, GetAdminRequest, GetCategoryRequest, ListAdminRequest, ListCategoryRequest,
+    ListTopicRequest, PurgeAdminsRequest, PurgeCategoriesRequest, ToggleAdminRequest,
+    ToggleCategoryRequest,
+};
+use serde::Serialize;
+use tera::Context;
+
+use crate::{form, model::AppState};
+
+use super::{redirect_with_msg, topic::bulk_topic_msg};
+
+/// 回收站中的一条记录
+#[derive(Serialize)]
+struct TrashItem {
+    id: i64,
+    /// 文章标题、分类名称或管理员邮箱
+    name: String,
+    deleted_at: Option<blog_types::Dateline>,
+    /// 执行删除的管理员邮箱
+    deleted_by: String,
+}
+
+pub async fn list_trash(
+    Extension(state): Extension<Arc<AppState>>,
+    Query(params): Query<form::TrashFilter>,
+) -> Result<Html<String>, String> {
+    let mut context = Context::new();
+    if let Some(msg) = params.msg.clone() {
+        context.insert("msg", &msg);
+    }
+    let kind = params.kind();
+    context.insert("kind", kind);
+
+    let admins = admin_emails(&state).await?;
+    let deleted_by = |id: Option<i32>| match id {
+        Some(id) => admins
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| format!("#{}", id)),
+        None => String::new(),
+    };
+    let mut page_totoal = 0;
+    let items: Vec<TrashItem> = match kind {
+        "topic" => {
+            let mut topic = state.topic.clone();
+            let reply = topic
+                .list_topic(tonic::Request::new(ListTopicRequest {
+                    page: params.page,
+                    is_del: Some(true),
+                    ..Default::default()
+                }))
+                .await
+                .map_err(|e| e.to_string())?
+                .into_inner();
+            page_totoal = reply.page_totoal;
+            reply
+                .topics
+                .into_iter()
+                .map(|t| TrashItem {
+                    id: t.id,
+                    name: t.title,
+                    deleted_at: t.deleted_at.map(|d| d.into()),
+                    deleted_by: deleted_by(t.deleted_by),
+                })
+                .collect()
+        }
+        "cate" => {
+            let mut cate = state.cate.clone();
+            let resp = cate
+                .list_category(tonic::Request::new(ListCategoryRequest {
+                    name: None,
+                    is_del: Some(true),
+                    with_stats: None,
+                }))
+                .await;
+            let categories = match resp {
+                Ok(r) => r.into_inner().categories,
+                Err(e) if e.code() == tonic::Code::NotFound => vec![],
+                Err(e) => return Err(e.to_string()),
+            };
+            categories
+                .into_iter()
+                .map(|c| TrashItem {
+                    id: c.id.into(),
+                    name: c.name,
+                    deleted_at: c.deleted_at.map(|d| d.into()),
+                    deleted_by: deleted_by(c.deleted_by),
+                })
+                .collect()
+        }
+        "admin" => {
+            let mut admin = state.admin.clone();
+            let reply = admin
+                .list_admin(tonic::Request::new(ListAdminRequest {
+                    email: None,
+                    is_del: Some(true),
+                }))
+                .await
+                .map_err(|e| e.to_string())?
+                .into_inner();
+            reply
+                .admins
+                .into_iter()
+                .map(|a| TrashItem {
+                    id: a.id.into(),
+                    name: a.email,
+                    deleted_at: a.deleted_at.map(|d| d.into()),
+                    deleted_by: deleted_by(a.deleted_by),
+                })
+                .collect()
+        }
+        _ => return Err("错误的类型".to_string()),
+    };
+    context.insert("items", &items);
+    context.insert("page", &params.page.unwrap_or(0));
+    let page_nums: Vec<i64> = (0..page_totoal).collect();
+    context.insert("page_nums", &page_nums);
+
+    let out = state
+        .tera
+        .render("trash/index.html", &context)
+        .map_err(|e| e.to_string())?;
+    Ok(Html(out))
+}
+
+pub async fn trash_action(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(kind): Path<String>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<(StatusCode, HeaderMap), String> {
+    let form: form::TrashForm = fields.into();
+    let kind = match kind.as_str() {
+        "topic" | "cate" | "admin" => kind,
+        _ => return Err("错误的类型".to_string()),
+    };
+    if form.ids.is_empty() {
+        return Ok(redirect_with_msg(
+            &format!("/m/trash?kind={}", kind),
+            "请选择记录",
+        ));
+    }
+    let ids = form.ids;
+    let int_ids = || -> Vec<i32> { ids.iter().filter_map(|&id| id.try_into().ok()).collect() };
+    let msg = match (kind.as_str(), form.action.as_str()) {
+        ("topic", action @ ("restore" | "purge")) => {
+            let mut topic = state.topic.clone();
+            let request = tonic::Request::new(BulkTopicRequest {
+                ids: ids.clone(),
+                admin_id: None,
+            });
+            let resp = if action == "restore" {
+                topic.restore_topics(request).await
+            } else {
+                topic.purge_topics(request).await
+            };
+            match resp {
+                Ok(reply) => bulk_topic_msg(&reply.into_inner()),
+                Err(e) => e.message().to_string(),
+            }
+        }
+        ("cate", "restore") => {
+            let n = restore_categories(&state, int_ids()).await?;
+            format!("恢复了{}个分类", n)
+        }
+        ("cate", "purge") => {
+            let ids = int_ids();
+            let mut cate = state.cate.clone();
+            let reply = cate
+                .purge_categories(tonic::Request::new(PurgeCategoriesRequest {
+                    ids: ids.clone(),
+                }))
+                .await
+                .map_err(|e| e.to_string())?
+                .into_inner();
+            if reply.ids.len() < ids.len() {
+                format!(
+                    "彻底删除了{}个分类，其余分类仍有文章或已不在回收站中",
+                    reply.ids.len()
+                )
+            } else {
+                format!("彻底删除了{}个分类", reply.ids.len())
+            }
+        }
+        ("admin", "restore") => {
+            let n = restore_admins(&state, int_ids()).await?;
+            format!("恢复了{}个管理员", n)
+        }
+        ("admin", "purge") => {
+            let mut admin = state.admin.clone();
+            let reply = admin
+                .purge_admins(tonic::Request::new(PurgeAdminsRequest { ids: int_ids() }))
+                .await
+                .map_err(|e| e.to_string())?
+                .into_inner();
+            format!("彻底删除了{}个管理员", reply.ids.len())
+        }
+        _ => return Err("错误的操作".to_string()),
+    };
+    Ok(redirect_with_msg(&format!("/m/trash?kind={}", kind), &msg))
+}
+
+/// 管理员ID与邮箱的对应关系，包括已删除的管理员
+pub(super) async fn admin_emails(state: &AppState) -> Result<HashMap<i32, String>, String> {
+    let mut admin = state.admin.clone();
+    let reply = admin
+        .list_admin(tonic::Request::new(ListAdminRequest {
+            email: None,
+            is_del: None,
+        }))
+        .await
+        .map_err(|e| e.to_string())?
+        .into_inner();
+    Ok(reply.admins.into_iter().map(|a| (a.id, a.email)).collect())
+}
+
+/// 只恢复仍在回收站中的分类，避免重复提交时又把分类删除
+async fn restore_categories(state: &AppState, ids: Vec<i32>) -> Result<usize, String> {
+    let mut cate = state.cate.clone();
+    let mut restored = 0;
+    for id in ids {
+        let reply = cate
+            .get_category(tonic::Request::new(GetCategoryRequest {
+                id,
+                is_del: Some(true),
+            }))
+            .await
+            .map_err(|e| e.to_string())?
+            .into_inner();
+        if reply.category.is_none() {
+            continue;
+        }
+        cate.toggle_category(tonic::Request::new(ToggleCategoryRequest {
+            id,
+            ..Default::default()
+        }))
+        .await
+        .map_err(|e| e.to_string())?;
+        restored += 1;
+    }
+    Ok(restored)
+}
+
+/// 只恢复仍在回收站中的管理员，避免重复提交时又把管理员删除
+async fn restore_admins(state: &AppState, ids: Vec<i32>) -> Result<usize, String> {
+    let mut admin = state.admin.clone();
+    let mut restored = 0;
+    for id in ids {
+        let resp = admin
+            .get_admin(tonic::Request::new(GetAdminRequest {
+                condition: Some(Condition::ById(ById {
+                    id,
+                    is_del: Some(true),
+                })),
+            }))
+            .await;
+        match resp {
+            Ok(_) => {}
+            Err(e) if e.code() == tonic::Code::NotFound => continue,
+            Err(e) => return Err(e.to_string()),
+        }
+        admin
+            .toggle_admin(tonic::Request::new(ToggleAdminRequest {
+                id,
+                admin_id: None,
+            }))
+            .await
+            .map_err(|e| e.to_string())?;
+        restored += 1;
+    }
+    Ok(restored)
+}
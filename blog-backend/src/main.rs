@@ -1,9 +1,15 @@
 use std::{env, sync::Arc};
 
-use axum::{routing::get, Extension, Router};
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+    Extension, Router,
+};
 use blog_auth::Jwt;
 use blog_proto::{
     admin_service_client::AdminServiceClient, category_service_client::CategoryServiceClient,
+    comment_service_client::CommentServiceClient, media_service_client::MediaServiceClient,
+    series_service_client::SeriesServiceClient, tag_service_client::TagServiceClient,
     topic_service_client::TopicServiceClient,
 };
 use tera::Tera;
@@ -23,6 +29,7 @@ async fn main() {
     let jwt_iss = env::var("JWT_ISS").unwrap_or("hello".to_string());
     let jwt_exp = env::var("JWT_EXP").unwrap_or("120".to_string());
     let jwt_exp = jwt_exp.parse().unwrap_or(120);
+    let media_url = env::var("MEDIA_URL").unwrap_or("http://[::1]:19529/media".to_string());
 
     let cate = CategoryServiceClient::connect("http://[::1]:19527")
         .await
@@ -32,11 +39,27 @@ async fn main() {
         .await
         .unwrap();
 
+    let tag = TagServiceClient::connect("http://[::1]:19528")
+        .await
+        .unwrap();
+
+    let series = SeriesServiceClient::connect("http://[::1]:19528")
+        .await
+        .unwrap();
+
+    let comment = CommentServiceClient::connect("http://[::1]:19532")
+        .await
+        .unwrap();
+
+    let media = MediaServiceClient::connect("http://[::1]:19533")
+        .await
+        .unwrap();
+
     let admin = AdminServiceClient::connect("http://[::1]:19530")
         .await
         .unwrap();
 
-    let tera = Tera::new("blog-backend/templates/*.html").unwrap();
+    let tera = Tera::new("blog-backend/templates/**/*.html").unwrap();
     let jwt = Jwt::new(jwt_secret, jwt_exp, jwt_iss);
 
     let m_router = Router::new()
@@ -45,6 +68,60 @@ async fn main() {
             "/cate/add",
             get(handler::add_cate_ui).post(handler::add_cate),
         )
+        .route(
+            "/cate/edit/:id",
+            get(handler::edit_cate_ui).post(handler::edit_cate),
+        )
+        .route("/cate/move/:id", post(handler::move_cate))
+        .route(
+            "/cate/del/:id",
+            get(handler::del_cate_ui).post(handler::del_cate),
+        )
+        .route("/topic", get(handler::list_topic))
+        .route("/topic/bulk", post(handler::bulk_topic))
+        .route(
+            "/topic/pinned",
+            get(handler::pinned_ui).post(handler::reorder_pinned),
+        )
+        .route("/topic/pin/:id", post(handler::pin_topic))
+        .route("/topic/feature/:id", post(handler::feature_topic))
+        .route(
+            "/topic/add",
+            get(handler::add_topic_ui).post(handler::add_topic),
+        )
+        .route(
+            "/topic/edit/:id",
+            get(handler::edit_topic_ui).post(handler::edit_topic),
+        )
+        .route("/topic/revision/:topic_id", get(handler::list_revision))
+        .route("/topic/revision/view/:id", get(handler::view_revision))
+        .route("/topic/revision/diff", get(handler::diff_revision))
+        .route(
+            "/topic/revision/restore/:id",
+            post(handler::restore_revision),
+        )
+        .route("/tag", get(handler::list_tag))
+        .route("/tag/add", post(handler::add_tag))
+        .route("/tag/rename/:id", post(handler::rename_tag))
+        .route("/tag/merge", post(handler::merge_tag))
+        .route("/series", get(handler::list_series))
+        .route("/series/add", post(handler::add_series))
+        .route(
+            "/series/edit/:id",
+            get(handler::edit_series_ui).post(handler::edit_series),
+        )
+        .route("/series/topics/:id", post(handler::set_series_topics))
+        .route("/series/delete/:id", post(handler::delete_series))
+        .route("/comment", get(handler::list_comment))
+        .route("/comment/moderate/:id", post(handler::moderate_comment))
+        .route("/trash", get(handler::list_trash))
+        .route("/trash/:kind", post(handler::trash_action))
+        .route("/profile", get(handler::profile_ui).post(handler::profile))
+        .route("/media", get(handler::list_media))
+        .route(
+            "/media/upload",
+            post(handler::upload_media).layer(DefaultBodyLimit::max(handler::UPLOAD_BODY_LIMIT)),
+        )
         .layer(axum::middleware::from_extractor::<middleware::Auth>());
 
     let app = Router::new()
@@ -53,7 +130,7 @@ async fn main() {
         .route("/login", get(handler::login_ui).post(handler::login))
         .route("/logout", get(handler::logout))
         .layer(Extension(Arc::new(model::AppState::new(
-            cate, topic, admin, tera, jwt,
+            cate, topic, admin, tag, series, comment, media, media_url, tera, jwt,
         ))));
 
     let listener = TcpListener::bind(addr).await.unwrap();
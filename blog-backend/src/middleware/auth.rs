@@ -9,7 +9,8 @@ use blog_auth::Claims;
 
 use crate::{handler::cookie, model::AppState};
 
-pub struct Auth(Claims);
+/// 已登录的管理员
+pub struct Auth(pub Claims);
 
 #[async_trait]
 impl<S> FromRequestParts<S> for Auth
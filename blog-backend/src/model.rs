@@ -1,6 +1,9 @@
 use blog_auth::Jwt;
 use blog_proto::{
-    admin_service_client::AdminServiceClient, category_service_client::CategoryServiceClient, topic_service_client::TopicServiceClient
+    admin_service_client::AdminServiceClient, category_service_client::CategoryServiceClient,
+    comment_service_client::CommentServiceClient, media_service_client::MediaServiceClient,
+    series_service_client::SeriesServiceClient, tag_service_client::TagServiceClient,
+    topic_service_client::TopicServiceClient,
 };
 use tera::Tera;
 
@@ -8,18 +11,41 @@ pub struct AppState {
     pub cate: CategoryServiceClient<tonic::transport::Channel>,
     pub topic: TopicServiceClient<tonic::transport::Channel>,
     pub admin: AdminServiceClient<tonic::transport::Channel>,
+    pub tag: TagServiceClient<tonic::transport::Channel>,
+    pub series: SeriesServiceClient<tonic::transport::Channel>,
+    pub comment: CommentServiceClient<tonic::transport::Channel>,
+    pub media: MediaServiceClient<tonic::transport::Channel>,
+    /// 前台媒体文件的地址前缀，用于在后台预览
+    pub media_url: String,
     pub tera: Tera,
     pub jwt: Jwt,
 }
 
 impl AppState {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         cate: CategoryServiceClient<tonic::transport::Channel>,
         topic: TopicServiceClient<tonic::transport::Channel>,
         admin: AdminServiceClient<tonic::transport::Channel>,
+        tag: TagServiceClient<tonic::transport::Channel>,
+        series: SeriesServiceClient<tonic::transport::Channel>,
+        comment: CommentServiceClient<tonic::transport::Channel>,
+        media: MediaServiceClient<tonic::transport::Channel>,
+        media_url: String,
         tera: Tera,
         jwt: Jwt,
     ) -> Self {
-        Self { cate, topic, admin, tera, jwt }
+        Self {
+            cate,
+            topic,
+            admin,
+            tag,
+            series,
+            comment,
+            media,
+            media_url,
+            tera,
+            jwt,
+        }
     }
 }
\ No newline at end of file
@@ -1,28 +1,62 @@
-use std::sync::Arc;
+use std::{
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
 
 use axum::{
-    extract::{Path, Query},
-    response::Html,
-    Extension,
+    extract::{ConnectInfo, Path, Query},
+    http::{header, HeaderMap, StatusCode},
+    response::{Html, IntoResponse, Redirect, Response},
+    Extension, Form,
+};
+use blog_proto::{
+    get_admin_request::{self, ById},
+    get_topic_request::Condition,
+    CountCommentRequest, CreateCommentRequest, DatelineRange, GetAdminRequest,
+    GetCommentThreadRequest, GetMediaFileRequest, GetRelatedTopicsRequest, GetSeriesRequest,
+    GetTopicRequest, GetTopicSeriesRequest, ListAdminRequest, ListArchiveRequest,
+    ListCategoryTreeRequest, ListFeaturedTopicsRequest, ListTagRequest, ListTopicRequest,
+    TopicSort, TopicStatus,
 };
-use blog_proto::{GetTopicRequest, ListCategoryRequest, ListTopicRequest};
+use blog_utils::url::percent_encode;
+use chrono::{Local, TimeZone};
 use serde::{Deserialize, Serialize};
 use tera::Context;
 
 use crate::model::AppState;
 
+/// 文章页显示的相关文章条数
+const RELATED_LIMIT: i32 = 5;
+/// 媒体文件的缓存时间为一年
+const MEDIA_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
 #[derive(Serialize, Deserialize)]
 pub struct QueryParams {
-    pub page: Option<i32>,
+    pub cursor: Option<String>,
     pub category_id: Option<i32>,
     pub keyword: Option<String>,
+    /// dateline / hit / title
+    pub sort: Option<String>,
+}
+
+impl QueryParams {
+    fn sort(&self) -> Option<TopicSort> {
+        match self.sort.as_deref() {
+            Some("dateline") => Some(TopicSort::Dateline),
+            Some("hit") => Some(TopicSort::Hit),
+            Some("title") => Some(TopicSort::Title),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Deserialize, Serialize)]
 pub struct QueryParamsForUrl {
     pub category_id: i32,
     pub keyword: String,
-    pub page: i32,
+    pub sort: String,
 }
 
 impl From<QueryParams> for QueryParamsForUrl {
@@ -30,55 +64,233 @@ impl From<QueryParams> for QueryParamsForUrl {
         Self {
             category_id: p.category_id.unwrap_or_default(),
             keyword: p.keyword.unwrap_or_default(),
-            page: p.page.unwrap_or_default(),
+            sort: p.sort.unwrap_or_default(),
         }
     }
 }
 
+#[derive(Deserialize)]
+pub struct PostArgs {
+    pub msg: Option<String>,
+    /// 回复的评论
+    pub reply: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct CommentForm {
+    pub parent_id: Option<String>,
+    pub author: String,
+    pub email: String,
+    pub content: String,
+}
+
+/// 文章列表页的附加条件
+#[derive(Default)]
+struct ListFilter {
+    tag: Option<String>,
+    /// 归档的年份和月份
+    archive: Option<(i32, u32)>,
+    author_id: Option<i32>,
+}
+
+/// 同一年的归档
+#[derive(Serialize)]
+struct ArchiveYear {
+    year: i32,
+    topic_count: i64,
+    months: Vec<blog_types::Archive>,
+}
+
 pub async fn index(
     Extension(state): Extension<Arc<AppState>>,
     Query(params): Query<QueryParams>,
 ) -> Result<Html<String>, String> {
+    topic_list(&state, params, ListFilter::default()).await
+}
+
+pub async fn tag(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(name): Path<String>,
+    Query(params): Query<QueryParams>,
+) -> Result<Html<String>, String> {
+    let filter = ListFilter {
+        tag: Some(name),
+        ..Default::default()
+    };
+    topic_list(&state, params, filter).await
+}
+
+pub async fn archive_month(
+    Extension(state): Extension<Arc<AppState>>,
+    Path((year, month)): Path<(i32, u32)>,
+    Query(params): Query<QueryParams>,
+) -> Result<Html<String>, String> {
+    let filter = ListFilter {
+        archive: Some((year, month)),
+        ..Default::default()
+    };
+    topic_list(&state, params, filter).await
+}
+
+pub async fn author(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Query(params): Query<QueryParams>,
+) -> Result<Html<String>, String> {
+    let filter = ListFilter {
+        author_id: Some(id),
+        ..Default::default()
+    };
+    topic_list(&state, params, filter).await
+}
+
+pub async fn archive(Extension(state): Extension<Arc<AppState>>) -> Result<Html<String>, String> {
     let mut ctx = Context::new();
-    // 获取分类列表
-    let mut cate = state.cate.clone();
-    let resp = cate
-        .list_category(tonic::Request::new(ListCategoryRequest {
-            name: None,
+    ctx.insert("cate_list", &cate_list(&state).await?);
+    ctx.insert("tag_list", &tag_list(&state).await?);
+
+    let mut tpc = state.topic.clone();
+    let resp = tpc
+        .list_archive(tonic::Request::new(ListArchiveRequest {
             is_del: Some(false),
+            status: Some(TopicStatus::Published.into()),
+            category_is_del: Some(false),
         }))
         .await
         .map_err(|err| err.to_string())?;
-    let reply = resp.into_inner();
-    let mut cate_list: Vec<blog_types::Category> = Vec::with_capacity(reply.categories.len());
-    for reply_cate in reply.categories {
-        cate_list.push(reply_cate.into());
+    // 按年份分组，服务端已按年月倒序
+    let mut years: Vec<ArchiveYear> = vec![];
+    for a in resp.into_inner().archives {
+        let a: blog_types::Archive = a.into();
+        match years.last_mut() {
+            Some(y) if y.year == a.year => {
+                y.topic_count += a.topic_count;
+                y.months.push(a);
+            }
+            _ => years.push(ArchiveYear {
+                year: a.year,
+                topic_count: a.topic_count,
+                months: vec![a],
+            }),
+        }
+    }
+    ctx.insert("years", &years);
+
+    let out = state
+        .tera
+        .render("archive.html", &ctx)
+        .map_err(|err| err.to_string())?;
+
+    Ok(Html(out))
+}
+
+/// 系列的目录，只列出已发布的文章
+pub async fn series(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<Html<String>, String> {
+    let mut ctx = Context::new();
+    ctx.insert("cate_list", &cate_list(&state).await?);
+    ctx.insert("tag_list", &tag_list(&state).await?);
+
+    let mut srs = state.series.clone();
+    let reply = srs
+        .get_series(tonic::Request::new(GetSeriesRequest {
+            id,
+            is_del: Some(false),
+            status: Some(TopicStatus::Published.into()),
+            category_is_del: Some(false),
+        }))
+        .await
+        .map_err(|err| err.to_string())?
+        .into_inner();
+    let series: blog_types::Series = reply.series.unwrap_or_default().into();
+    let topics: Vec<blog_types::Topic> = reply.topics.into_iter().map(|t| t.into()).collect();
+    ctx.insert("series", &series);
+    ctx.insert("topics", &topics);
+
+    let out = state
+        .tera
+        .render("series.html", &ctx)
+        .map_err(|err| err.to_string())?;
+
+    Ok(Html(out))
+}
+
+async fn topic_list(
+    state: &AppState,
+    params: QueryParams,
+    filter: ListFilter,
+) -> Result<Html<String>, String> {
+    let mut ctx = Context::new();
+    let mut dateline_range = None;
+    if let Some((year, month)) = filter.archive {
+        dateline_range = Some(month_range(year, month).ok_or("错误的日期".to_string())?);
+        ctx.insert("archive_year", &year);
+        ctx.insert("archive_month", &month);
     }
+    // 获取分类列表
+    let cate_list = cate_list(state).await?;
     ctx.insert("cate_list", &cate_list);
+    ctx.insert("tag_list", &tag_list(state).await?);
+    let tag = filter.tag;
+    if let Some(tag) = &tag {
+        ctx.insert("tag", tag);
+    }
+    let authors = author_map(state).await;
+    if let Some(author_id) = filter.author_id {
+        match authors.get(&author_id) {
+            Some(author) => ctx.insert("author", author),
+            None => return Err("作者不存在".to_string()),
+        }
+    }
 
     // 文章列表
-    let query_category_id = match params.category_id.clone() {
-        Some(cid) => {
-            if cid > 0 {
-                Some(cid)
-            } else {
-                None
-            }
-        }
-        None => None,
-    };
+    let query_category_id = params.category_id.filter(|&cid| cid > 0);
+    let no_keyword = params
+        .keyword
+        .as_deref()
+        .is_none_or(|k| k.trim().is_empty());
+    // 首页和分类页按默认排序时，先列出置顶的文章
+    let pinned_first = no_keyword
+        && tag.is_none()
+        && dateline_range.is_none()
+        && filter.author_id.is_none()
+        && matches!(params.sort(), None | Some(TopicSort::Dateline));
+    ctx.insert("pinned_first", &pinned_first);
+    if pinned_first && query_category_id.is_none() && params.cursor.is_none() {
+        ctx.insert("featured", &featured_list(state, &cate_list).await);
+    }
     let mut tpc = state.topic.clone();
     let resp = tpc
         .list_topic(tonic::Request::new(ListTopicRequest {
-            page: params.page.clone(),
             category_id: query_category_id,
+            include_descendants: Some(true),
             keyword: params.keyword.clone(),
             is_del: Some(false),
-            dateline_range: None,
+            dateline_range,
+            status: Some(TopicStatus::Published.into()),
+            category_is_del: Some(false),
+            tag,
+            sort: params.sort().map(|s| s.into()),
+            cursor: params.cursor.clone(),
+            skip_total: Some(true),
+            author_id: filter.author_id,
+            pinned_first: Some(pinned_first),
+            ..Default::default()
         }))
         .await
         .map_err(|err| err.to_string())?;
-    let reply = resp.into_inner();
+    let mut reply = resp.into_inner();
+    // 评论数，获取失败时不显示
+    let mut cmt = state.comment.clone();
+    let comment_counts = cmt
+        .count_comment(tonic::Request::new(CountCommentRequest {
+            topic_ids: reply.topics.iter().map(|t| t.id).collect(),
+        }))
+        .await
+        .map(|resp| resp.into_inner().counts)
+        .unwrap_or_default();
     let mut topic_list: Vec<blog_types::Topic> = Vec::with_capacity(reply.topics.len());
     for reply_topic in reply.topics {
         let mut t: blog_types::Topic = reply_topic.into();
@@ -89,6 +301,12 @@ pub async fn index(
                 break;
             }
         }
+        // 搜索高亮
+        if let Some(idx) = reply.highlights.iter().position(|h| h.id == t.id) {
+            t.highlight = Some(reply.highlights.swap_remove(idx).into());
+        }
+        t.comment_count = comment_counts.get(&t.id).copied().unwrap_or_default();
+        t.author = t.author_id.and_then(|id| authors.get(&id).cloned());
         topic_list.push(t);
     }
     let paginate = blog_types::Paginate {
@@ -97,15 +315,11 @@ pub async fn index(
         page_totoal: reply.page_totoal,
         record_total: reply.record_total,
         data: topic_list,
+        next_cursor: reply.next_cursor,
+        prev_cursor: reply.prev_cursor,
     };
     ctx.insert("paginate", &paginate);
 
-    let mut page_nums = vec![];
-    for i in 0..paginate.page_totoal {
-        page_nums.push(i as i32);
-    }
-    ctx.insert("page_nums", &page_nums);
-
     let params: QueryParamsForUrl = params.into();
     ctx.insert("params", &params);
     let out = state
@@ -116,34 +330,104 @@ pub async fn index(
     Ok(Html(out))
 }
 
+pub async fn add_comment(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(slug): Path<String>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    Form(form): Form<CommentForm>,
+) -> Result<Response, String> {
+    // 以地址中的 slug 确定文章，只能评论前台可见的文章
+    let mut tpc = state.topic.clone();
+    let topic = tpc
+        .get_topic(tonic::Request::new(GetTopicRequest {
+            condition: Some(Condition::Slug(slug.clone())),
+            is_del: Some(false),
+            status: Some(TopicStatus::Published.into()),
+            category_is_del: Some(false),
+            ..Default::default()
+        }))
+        .await
+        .map_err(|err| err.to_string())?
+        .into_inner()
+        .topic;
+    let topic = match topic {
+        Some(topic) => topic,
+        None => return Err("文章不存在".to_string()),
+    };
+    let mut cmt = state.comment.clone();
+    let msg = match cmt
+        .create_comment(tonic::Request::new(CreateCommentRequest {
+            topic_id: topic.id,
+            parent_id: form.parent_id.as_deref().and_then(|s| s.parse().ok()),
+            author: form.author,
+            email: form.email,
+            content: form.content,
+            ip: client_ip(&headers, addr, &state.trusted_proxies),
+            user_agent: user_agent(&headers),
+        }))
+        .await
+    {
+        Ok(_) => "评论已提交，审核通过后显示".to_string(),
+        Err(err) => err.message().to_string(),
+    };
+    let url = format!(
+        "/post/{}?msg={}#comments",
+        percent_encode(&topic.slug),
+        percent_encode(&msg)
+    );
+    Ok(Redirect::to(&url).into_response())
+}
+
+/// 旧的文章地址，永久跳转到文章的永久链接
 pub async fn detail(
     Extension(state): Extension<Arc<AppState>>,
     Path(id): Path<i64>,
-) -> Result<Html<String>, String> {
-    let mut ctx = Context::new();
-    // 获取分类列表
-    let mut cate = state.cate.clone();
-    let resp = cate
-        .list_category(tonic::Request::new(ListCategoryRequest {
-            name: None,
+) -> Result<Response, String> {
+    let mut tpc = state.topic.clone();
+    let resp = tpc
+        .get_topic(tonic::Request::new(GetTopicRequest {
+            condition: Some(Condition::Id(id)),
             is_del: Some(false),
+            status: Some(TopicStatus::Published.into()),
+            category_is_del: Some(false),
+            ..Default::default()
         }))
         .await
         .map_err(|err| err.to_string())?;
-    let reply = resp.into_inner();
-    let mut cate_list: Vec<blog_types::Category> = Vec::with_capacity(reply.categories.len());
-    for reply_cate in reply.categories {
-        cate_list.push(reply_cate.into());
+    match resp.into_inner().topic {
+        Some(topic) => Ok(moved_permanently(&topic.slug)),
+        None => Err("文章不存在".to_string()),
+    }
+}
+
+pub async fn post(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(slug): Path<String>,
+    Query(args): Query<PostArgs>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Result<Response, String> {
+    let mut ctx = Context::new();
+    if let Some(msg) = &args.msg {
+        ctx.insert("msg", msg);
     }
+    // 获取分类列表
+    let cate_list = cate_list(&state).await?;
     ctx.insert("cate_list", &cate_list);
+    ctx.insert("tag_list", &tag_list(&state).await?);
 
     // 获取文章详情
     let mut tpc = state.topic.clone();
     let resp = tpc
         .get_topic(tonic::Request::new(GetTopicRequest {
-            id,
+            condition: Some(Condition::Slug(slug.clone())),
             inc_hit: Some(true),
             is_del: Some(false),
+            status: Some(TopicStatus::Published.into()),
+            category_is_del: Some(false),
+            visitor_id: Some(visitor_id(&headers, addr, &state.trusted_proxies)),
+            user_agent: Some(user_agent(&headers)),
         }))
         .await
         .map_err(|err| err.to_string())?;
@@ -153,6 +437,10 @@ pub async fn detail(
         Some(topic) => topic.into(),
         None => return Err("文章不存在".to_string()),
     };
+    // 使用旧链接访问时跳转到新链接
+    if t.slug != slug {
+        return Ok(moved_permanently(&t.slug));
+    }
     // 查找分类
     for cate in &cate_list {
         if cate.id == t.category_id {
@@ -160,11 +448,345 @@ pub async fn detail(
             break;
         }
     }
+    t.breadcrumbs = blog_types::breadcrumbs(&cate_list, t.category_id);
+    // 作者，获取失败时不显示
+    if let Some(author_id) = t.author_id {
+        let mut adm = state.admin.clone();
+        t.author = adm
+            .get_admin(tonic::Request::new(GetAdminRequest {
+                condition: Some(get_admin_request::Condition::ById(ById {
+                    id: author_id,
+                    is_del: Some(false),
+                })),
+            }))
+            .await
+            .ok()
+            .and_then(|resp| resp.into_inner().admin)
+            .map(|a| a.into());
+    }
     ctx.insert("topic", &t);
+
+    // 相关文章，获取失败时不影响文章的显示
+    let related: Vec<blog_types::Topic> = tpc
+        .get_related_topics(tonic::Request::new(GetRelatedTopicsRequest {
+            id: t.id,
+            limit: Some(RELATED_LIMIT),
+        }))
+        .await
+        .map(|resp| {
+            resp.into_inner()
+                .topics
+                .into_iter()
+                .map(|t| t.into())
+                .collect()
+        })
+        .unwrap_or_default();
+    ctx.insert("related", &related);
+
+    // 所属的系列，获取失败时不显示
+    let mut srs = state.series.clone();
+    let series = srs
+        .get_topic_series(tonic::Request::new(GetTopicSeriesRequest {
+            topic_id: t.id,
+            is_del: Some(false),
+            status: Some(TopicStatus::Published.into()),
+            category_is_del: Some(false),
+        }))
+        .await
+        .ok()
+        .map(|resp| resp.into_inner())
+        .filter(|reply| reply.part > 0)
+        .and_then(|reply| {
+            Some(blog_types::SeriesNav {
+                series: reply.series?.into(),
+                part: reply.part,
+                total: reply.total,
+                prev: reply.prev.map(|t| t.into()),
+                next: reply.next.map(|t| t.into()),
+            })
+        });
+    if let Some(series) = &series {
+        ctx.insert("series", series);
+    }
+
+    // 评论串，获取失败时不影响文章的显示
+    let mut cmt = state.comment.clone();
+    let (comments, comment_total) = cmt
+        .get_comment_thread(tonic::Request::new(GetCommentThreadRequest {
+            topic_id: t.id,
+        }))
+        .await
+        .map(|resp| {
+            let reply = resp.into_inner();
+            let comments: Vec<blog_types::Comment> =
+                reply.comments.into_iter().map(|c| c.into()).collect();
+            (comments, reply.total)
+        })
+        .unwrap_or_default();
+    if let Some(reply_to) = args.reply.and_then(|id| find_comment(&comments, id)) {
+        ctx.insert("reply_to", reply_to);
+    }
+    ctx.insert("comments", &comments);
+    ctx.insert("comment_total", &comment_total);
+
     let out = state
         .tera
         .render("detail.html", &ctx)
         .map_err(|err| err.to_string())?;
 
-    Ok(Html(out))
+    Ok(Html(out).into_response())
+}
+
+/// 访客标识：客户端IP和 User-Agent 的摘要
+fn visitor_id(headers: &HeaderMap, addr: SocketAddr, trusted_proxies: &[IpAddr]) -> String {
+    let mut hasher = DefaultHasher::new();
+    client_ip(headers, addr, trusted_proxies).hash(&mut hasher);
+    headers.get(header::USER_AGENT).hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
+/// 客户端IP，部署在反向代理之后时使用代理传递的真实IP
+/// 只有请求来自受信任的代理时才使用代理传递的头，否则客户端可以随意伪造。
+/// X-Forwarded-For 从右向左跳过受信任的代理，第一个不受信任的地址是客户端
+fn client_ip(headers: &HeaderMap, addr: SocketAddr, trusted_proxies: &[IpAddr]) -> String {
+    let peer = addr.ip();
+    if !trusted_proxies.contains(&peer) {
+        return peer.to_string();
+    }
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    if let Some(forwarded) = header("x-forwarded-for") {
+        let mut client = None;
+        for ip in forwarded.rsplit(',').map(|s| s.trim().parse::<IpAddr>()) {
+            match ip {
+                Ok(ip) => {
+                    client = Some(ip);
+                    if !trusted_proxies.contains(&ip) {
+                        break;
+                    }
+                }
+                // 无法解析的地址之前的内容都不可信
+                Err(_) => break,
+            }
+        }
+        if let Some(ip) = client {
+            return ip.to_string();
+        }
+    }
+    header("x-real-ip")
+        .and_then(|v| v.trim().parse::<IpAddr>().ok())
+        .unwrap_or(peer)
+        .to_string()
+}
+
+fn user_agent(headers: &HeaderMap) -> String {
+    headers
+        .get(header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// 媒体文件。文件名中包含内容的哈希，内容不会改变，可以长期缓存
+pub async fn media(
+    Extension(state): Extension<Arc<AppState>>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, String> {
+    let etag = format!("\"{}\"", name);
+    let cache_headers = [
+        (header::CACHE_CONTROL, MEDIA_CACHE_CONTROL.to_string()),
+        (header::ETAG, etag.clone()),
+    ];
+    let if_none_match = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok());
+    if if_none_match == Some(etag.as_str()) {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+    let mut media = state.media.clone();
+    let resp = media
+        .get_media_file(tonic::Request::new(GetMediaFileRequest { name }))
+        .await;
+    let reply = match resp {
+        Ok(r) => r.into_inner(),
+        Err(e) if e.code() == tonic::Code::NotFound => {
+            return Ok((StatusCode::NOT_FOUND, e.message().to_string()).into_response())
+        }
+        Err(e) => return Err(e.to_string()),
+    };
+    // 附件以上传时的文件名下载，不在浏览器中打开
+    let disposition = if reply.mime.starts_with("image/") {
+        "inline".to_string()
+    } else {
+        format!(
+            "attachment; filename*=UTF-8''{}",
+            percent_encode(&reply.filename)
+        )
+    };
+    Ok((
+        cache_headers,
+        [
+            (header::CONTENT_TYPE, reply.mime),
+            (header::CONTENT_DISPOSITION, disposition),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_string()),
+        ],
+        reply.data,
+    )
+        .into_response())
+}
+
+/// 未删除的管理员，以ID为键。获取失败时不显示作者
+async fn author_map(state: &AppState) -> HashMap<i32, blog_types::Author> {
+    let mut adm = state.admin.clone();
+    adm.list_admin(tonic::Request::new(ListAdminRequest {
+        email: None,
+        is_del: Some(false),
+    }))
+    .await
+    .map(|resp| {
+        resp.into_inner()
+            .admins
+            .into_iter()
+            .map(|a| (a.id, a.into()))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// 首页轮播的推荐文章，获取失败时不显示
+async fn featured_list(
+    state: &AppState,
+    cate_list: &[blog_types::Category],
+) -> Vec<blog_types::Topic> {
+    let mut tpc = state.topic.clone();
+    let topics = tpc
+        .list_featured_topics(tonic::Request::new(ListFeaturedTopicsRequest {
+            limit: None,
+        }))
+        .await
+        .map(|resp| resp.into_inner().topics)
+        .unwrap_or_default();
+    topics
+        .into_iter()
+        .map(|t| {
+            let mut t: blog_types::Topic = t.into();
+            if let Some(c) = cate_list.iter().find(|c| c.id == t.category_id) {
+                t.category_name = c.name.clone();
+            }
+            t
+        })
+        .collect()
+}
+
+/// 在评论串中查找评论
+fn find_comment(comments: &[blog_types::Comment], id: i64) -> Option<&blog_types::Comment> {
+    comments.iter().find_map(|c| {
+        if c.id == id {
+            Some(c)
+        } else {
+            find_comment(&c.replies, id)
+        }
+    })
+}
+
+/// 301 跳转到文章的永久链接
+fn moved_permanently(slug: &str) -> Response {
+    (
+        StatusCode::MOVED_PERMANENTLY,
+        [(header::LOCATION, format!("/post/{}", percent_encode(slug)))],
+    )
+        .into_response()
+}
+
+/// 某年某月的时间区间，不包含下个月的第一天
+fn month_range(year: i32, month: u32) -> Option<DatelineRange> {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    let start = Local.with_ymd_and_hms(year, month, 1, 0, 0, 0).earliest()?;
+    let end = Local
+        .with_ymd_and_hms(next_year, next_month, 1, 0, 0, 0)
+        .earliest()?;
+    Some(DatelineRange {
+        start: Some(prost_types::Timestamp {
+            seconds: start.timestamp(),
+            nanos: 0,
+        }),
+        end: Some(prost_types::Timestamp {
+            seconds: end.timestamp(),
+            nanos: 0,
+        }),
+    })
+}
+
+/// 分类列表，按层级展开
+async fn cate_list(state: &AppState) -> Result<Vec<blog_types::Category>, String> {
+    let mut cate = state.cate.clone();
+    let resp = cate
+        .list_category_tree(tonic::Request::new(ListCategoryTreeRequest {
+            is_del: Some(false),
+            with_stats: Some(true),
+        }))
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(blog_types::flatten_tree(resp.into_inner().nodes))
+}
+
+/// 标签列表，只包含有已发布文章的标签
+async fn tag_list(state: &AppState) -> Result<Vec<blog_types::Tag>, String> {
+    let mut tag = state.tag.clone();
+    let resp = tag
+        .list_tag(tonic::Request::new(ListTagRequest {
+            name: None,
+            is_del: Some(false),
+            status: Some(TopicStatus::Published.into()),
+            category_is_del: Some(false),
+        }))
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(resp
+        .into_inner()
+        .tags
+        .into_iter()
+        .filter(|t| t.topic_count > 0)
+        .map(|t| t.into())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_month_range() {
+        let range = month_range(2023, 12).unwrap();
+        let start = Local.with_ymd_and_hms(2023, 12, 1, 0, 0, 0).unwrap();
+        let end = Local.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(range.start.unwrap().seconds, start.timestamp());
+        assert_eq!(range.end.unwrap().seconds, end.timestamp());
+        assert!(month_range(2023, 13).is_none());
+        assert!(month_range(2023, 0).is_none());
+    }
+
+    #[test]
+    fn test_client_ip() {
+        let proxy: IpAddr = "10.0.0.1".parse().unwrap();
+        let from_proxy: SocketAddr = "10.0.0.1:1234".parse().unwrap();
+        let direct: SocketAddr = "203.0.113.9:1234".parse().unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            "1.1.1.1, 198.51.100.7, 10.0.0.1".parse().unwrap(),
+        );
+        // 不是受信任的代理时忽略转发头
+        assert_eq!(client_ip(&headers, direct, &[proxy]), "203.0.113.9");
+        assert_eq!(client_ip(&headers, from_proxy, &[]), "10.0.0.1");
+        // 客户端自己添加的地址不可信，取最右侧的非代理地址
+        assert_eq!(client_ip(&headers, from_proxy, &[proxy]), "198.51.100.7");
+        let mut headers = HeaderMap::new();
+        headers.insert("x-real-ip", "198.51.100.8".parse().unwrap());
+        assert_eq!(client_ip(&headers, from_proxy, &[proxy]), "198.51.100.8");
+    }
 }
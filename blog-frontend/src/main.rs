@@ -1,8 +1,14 @@
-use std::sync::Arc;
+use std::{env, net::SocketAddr, sync::Arc};
 
-use axum::{routing::get, Extension, Router};
+use axum::{
+    routing::{get, post},
+    Extension, Router,
+};
 use blog_proto::{
-    category_service_client::CategoryServiceClient, topic_service_client::TopicServiceClient,
+    admin_service_client::AdminServiceClient, category_service_client::CategoryServiceClient,
+    comment_service_client::CommentServiceClient, media_service_client::MediaServiceClient,
+    series_service_client::SeriesServiceClient, tag_service_client::TagServiceClient,
+    topic_service_client::TopicServiceClient,
 };
 use tera::Tera;
 use tokio::net::TcpListener;
@@ -22,15 +28,65 @@ async fn main() {
         .await
         .unwrap();
 
+    let tag = TagServiceClient::connect("http://[::1]:19528")
+        .await
+        .unwrap();
+
+    let series = SeriesServiceClient::connect("http://[::1]:19528")
+        .await
+        .unwrap();
+
+    let comment = CommentServiceClient::connect("http://[::1]:19532")
+        .await
+        .unwrap();
+
+    // 媒体文件最大 10MB，留出其它字段的空间
+    let media = MediaServiceClient::connect("http://[::1]:19533")
+        .await
+        .unwrap()
+        .max_decoding_message_size(16 * 1024 * 1024);
+
+    let admin = AdminServiceClient::connect("http://[::1]:19530")
+        .await
+        .unwrap();
+
     let tera = Tera::new("blog-frontend/templates/*.html").unwrap();
 
+    // 受信任的反向代理地址，用逗号分隔。为空时不使用代理传递的客户端IP
+    let trusted_proxies = env::var("TRUSTED_PROXIES")
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|s| s.trim().parse().ok())
+        .collect();
+
     let app = Router::new()
         .route("/", get(handler::index))
         .route("/detail/:id", get(handler::detail))
-        .layer(Extension(Arc::new(model::AppState::new(cate, topic, tera))));
+        .route("/post/:slug", get(handler::post))
+        .route("/post/:slug/comment", post(handler::add_comment))
+        .route("/tag/:name", get(handler::tag))
+        .route("/author/:id", get(handler::author))
+        .route("/series/:id", get(handler::series))
+        .route("/archive", get(handler::archive))
+        .route("/archive/:year/:month", get(handler::archive_month))
+        .route("/media/:name", get(handler::media))
+        .layer(Extension(Arc::new(model::AppState::new(
+            cate,
+            topic,
+            tag,
+            series,
+            comment,
+            media,
+            admin,
+            tera,
+            trusted_proxies,
+        ))));
 
     let listener = TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app.into_make_service())
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
         .await
         .unwrap();
 }
@@ -1,20 +1,49 @@
+use std::net::IpAddr;
+
 use blog_proto::{
-    category_service_client::CategoryServiceClient, topic_service_client::TopicServiceClient,
+    admin_service_client::AdminServiceClient, category_service_client::CategoryServiceClient,
+    comment_service_client::CommentServiceClient, media_service_client::MediaServiceClient,
+    series_service_client::SeriesServiceClient, tag_service_client::TagServiceClient,
+    topic_service_client::TopicServiceClient,
 };
 use tera::Tera;
 
 pub struct AppState {
     pub cate: CategoryServiceClient<tonic::transport::Channel>,
     pub topic: TopicServiceClient<tonic::transport::Channel>,
+    pub tag: TagServiceClient<tonic::transport::Channel>,
+    pub series: SeriesServiceClient<tonic::transport::Channel>,
+    pub comment: CommentServiceClient<tonic::transport::Channel>,
+    pub media: MediaServiceClient<tonic::transport::Channel>,
+    pub admin: AdminServiceClient<tonic::transport::Channel>,
     pub tera: Tera,
+    /// 受信任的反向代理，只有来自这些地址的请求才使用 X-Forwarded-For / X-Real-IP
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 impl AppState {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         cate: CategoryServiceClient<tonic::transport::Channel>,
         topic: TopicServiceClient<tonic::transport::Channel>,
+        tag: TagServiceClient<tonic::transport::Channel>,
+        series: SeriesServiceClient<tonic::transport::Channel>,
+        comment: CommentServiceClient<tonic::transport::Channel>,
+        media: MediaServiceClient<tonic::transport::Channel>,
+        admin: AdminServiceClient<tonic::transport::Channel>,
         tera: Tera,
+        trusted_proxies: Vec<IpAddr>,
     ) -> Self {
-        Self { cate, topic, tera }
+        Self {
+            cate,
+            topic,
+            tag,
+            series,
+            comment,
+            media,
+            admin,
+            tera,
+            trusted_proxies,
+        }
     }
 }
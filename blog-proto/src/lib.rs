@@ -1,3 +1,43 @@
 mod pb;
 
-pub use pb::*;
\ No newline at end of file
+pub use pb::*;
+
+/// 版本冲突时，当前的版本号放在 metadata 的这个键中
+const CURRENT_VERSION_KEY: &str = "x-current-version";
+
+/// 乐观锁：记录已被其他人修改过，返回 Aborted 并附带当前的版本号
+pub fn version_conflict(message: &str, current_version: i32) -> tonic::Status {
+    let mut status = tonic::Status::aborted(message);
+    status
+        .metadata_mut()
+        .insert(CURRENT_VERSION_KEY, current_version.into());
+    status
+}
+
+/// 取出版本冲突错误中的当前版本号，其它错误返回 None
+pub fn current_version(status: &tonic::Status) -> Option<i32> {
+    if status.code() != tonic::Code::Aborted {
+        return None;
+    }
+    status
+        .metadata()
+        .get(CURRENT_VERSION_KEY)?
+        .to_str()
+        .ok()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_conflict() {
+        let status = version_conflict("文章已被修改", 7);
+        assert_eq!(status.code(), tonic::Code::Aborted);
+        assert_eq!(current_version(&status), Some(7));
+        assert_eq!(current_version(&tonic::Status::aborted("其它错误")), None);
+        assert_eq!(current_version(&tonic::Status::not_found("不存在")), None);
+    }
+}
@@ -1,125 +1,216 @@
 // This file is @generated by prost-build.
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Category {
+pub struct Admin {
     #[prost(int32, tag = "1")]
     pub id: i32,
     #[prost(string, tag = "2")]
-    pub name: ::prost::alloc::string::String,
-    #[prost(bool, tag = "3")]
+    pub email: ::prost::alloc::string::String,
+    #[prost(string, optional, tag = "3")]
+    pub password: ::core::option::Option<::prost::alloc::string::String>,
+    #[prost(bool, tag = "4")]
     pub is_del: bool,
+    /// 删除时间
+    #[prost(message, optional, tag = "5")]
+    pub deleted_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// 执行删除的管理员
+    #[prost(int32, optional, tag = "6")]
+    pub deleted_by: ::core::option::Option<i32>,
+    /// 作者页面显示的名称
+    #[prost(string, tag = "7")]
+    pub display_name: ::prost::alloc::string::String,
+    /// 头像地址
+    #[prost(string, tag = "8")]
+    pub avatar: ::prost::alloc::string::String,
+    /// 个人简介
+    #[prost(string, tag = "9")]
+    pub bio: ::prost::alloc::string::String,
 }
-/// 创建分类
+/// -- 添加管理员
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CreateCategoryRequest {
+pub struct CreateAdminRequest {
     #[prost(string, tag = "1")]
-    pub name: ::prost::alloc::string::String,
+    pub email: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub password: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct CreateCategoryReply {
+pub struct CreateAdminReply {
     #[prost(int32, tag = "1")]
     pub id: i32,
 }
-/// 修改分类
+/// -- 管理员列表
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EditCategoryRequest {
+pub struct ListAdminRequest {
+    /// 根据EMAIL进行查找
+    #[prost(string, optional, tag = "1")]
+    pub email: ::core::option::Option<::prost::alloc::string::String>,
+    /// 是否删除
+    #[prost(bool, optional, tag = "2")]
+    pub is_del: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListAdminReply {
+    #[prost(message, repeated, tag = "1")]
+    pub admins: ::prost::alloc::vec::Vec<Admin>,
+}
+/// -- 修改管理员
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditAdminRequest {
     #[prost(int32, tag = "1")]
     pub id: i32,
     #[prost(string, tag = "2")]
-    pub name: ::prost::alloc::string::String,
+    pub email: ::prost::alloc::string::String,
+    /// 现用密码
+    #[prost(string, tag = "3")]
+    pub password: ::prost::alloc::string::String,
+    /// 如果有密码，则修改密码
+    #[prost(string, optional, tag = "4")]
+    pub new_password: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct EditCategoryReply {
+pub struct EditAdminReply {
     #[prost(int32, tag = "1")]
     pub id: i32,
     #[prost(bool, tag = "2")]
     pub ok: bool,
 }
-/// 列出分类
+/// -- 修改个人资料
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListCategoryRequest {
-    /// 根据分类名称查找
-    #[prost(string, optional, tag = "1")]
-    pub name: ::core::option::Option<::prost::alloc::string::String>,
-    /// 是否删除
-    #[prost(bool, optional, tag = "2")]
-    pub is_del: ::core::option::Option<bool>,
+pub struct EditAdminProfileRequest {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(string, tag = "2")]
+    pub display_name: ::prost::alloc::string::String,
+    /// 以 / 开头的站内地址，或 http(s) 地址，可以为空
+    #[prost(string, tag = "3")]
+    pub avatar: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub bio: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListCategoryReply {
-    #[prost(message, repeated, tag = "1")]
-    pub categories: ::prost::alloc::vec::Vec<Category>,
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EditAdminProfileReply {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(bool, tag = "2")]
+    pub ok: bool,
 }
-/// 删除/恢复分类
+/// -- 删除/恢复管理员
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct ToggleCategoryRequest {
+pub struct ToggleAdminRequest {
     #[prost(int32, tag = "1")]
     pub id: i32,
+    /// 执行删除的管理员
+    #[prost(int32, optional, tag = "2")]
+    pub admin_id: ::core::option::Option<i32>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct ToggleCategoryReply {
+pub struct ToggleAdminReply {
     #[prost(int32, tag = "1")]
     pub id: i32,
     #[prost(bool, tag = "2")]
     pub is_del: bool,
 }
-/// 分类是否存在
+/// -- 管理员是否存在
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CategoryExistsRequest {
-    #[prost(oneof = "category_exists_request::Condition", tags = "1, 2")]
-    pub condition: ::core::option::Option<category_exists_request::Condition>,
+pub struct AdminExistsRequest {
+    #[prost(oneof = "admin_exists_request::Condition", tags = "1, 2")]
+    pub condition: ::core::option::Option<admin_exists_request::Condition>,
 }
-/// Nested message and enum types in `CategoryExistsRequest`.
-pub mod category_exists_request {
+/// Nested message and enum types in `AdminExistsRequest`.
+pub mod admin_exists_request {
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Condition {
         #[prost(string, tag = "1")]
-        Name(::prost::alloc::string::String),
+        Email(::prost::alloc::string::String),
         #[prost(int32, tag = "2")]
         Id(i32),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct CategoryExistsReply {
+pub struct AdminExistsReply {
     #[prost(bool, tag = "1")]
     pub exists: bool,
 }
-/// 分类详情
+/// -- 获取管理员
 #[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct GetCategoryRequest {
-    #[prost(int32, tag = "1")]
-    pub id: i32,
-    #[prost(bool, optional, tag = "2")]
-    pub is_del: ::core::option::Option<bool>,
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAdminRequest {
+    #[prost(oneof = "get_admin_request::Condition", tags = "1, 2")]
+    pub condition: ::core::option::Option<get_admin_request::Condition>,
+}
+/// Nested message and enum types in `GetAdminRequest`.
+pub mod get_admin_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ByAuth {
+        #[prost(string, tag = "1")]
+        pub email: ::prost::alloc::string::String,
+        #[prost(string, tag = "2")]
+        pub password: ::prost::alloc::string::String,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
+    pub struct ById {
+        #[prost(int32, tag = "1")]
+        pub id: i32,
+        #[prost(bool, optional, tag = "2")]
+        pub is_del: ::core::option::Option<bool>,
+    }
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Condition {
+        /// 通过ID直接获取
+        #[prost(message, tag = "1")]
+        ById(ById),
+        /// 通过登录信息获取
+        #[prost(message, tag = "2")]
+        ByAuth(ByAuth),
+    }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetCategoryReply {
+pub struct GetAdminReply {
     #[prost(message, optional, tag = "1")]
-    pub category: ::core::option::Option<Category>,
+    pub admin: ::core::option::Option<Admin>,
+}
+/// -- 彻底删除回收站中的管理员
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PurgeAdminsRequest {
+    #[prost(int32, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PurgeAdminsReply {
+    /// 已彻底删除的管理员
+    #[prost(int32, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i32>,
 }
 /// Generated client implementations.
-pub mod category_service_client {
+pub mod admin_service_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
     #[derive(Debug, Clone)]
-    pub struct CategoryServiceClient<T> {
+    pub struct AdminServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
-    impl CategoryServiceClient<tonic::transport::Channel> {
+    impl AdminServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
         where
@@ -130,7 +221,7 @@ pub mod category_service_client {
             Ok(Self::new(conn))
         }
     }
-    impl<T> CategoryServiceClient<T>
+    impl<T> AdminServiceClient<T>
     where
         T: tonic::client::GrpcService<tonic::body::BoxBody>,
         T::Error: Into<StdError>,
@@ -148,7 +239,7 @@ pub mod category_service_client {
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> CategoryServiceClient<InterceptedService<T, F>>
+        ) -> AdminServiceClient<InterceptedService<T, F>>
         where
             F: tonic::service::Interceptor,
             T::ResponseBody: Default,
@@ -162,7 +253,7 @@ pub mod category_service_client {
                 http::Request<tonic::body::BoxBody>,
             >>::Error: Into<StdError> + Send + Sync,
         {
-            CategoryServiceClient::new(InterceptedService::new(inner, interceptor))
+            AdminServiceClient::new(InterceptedService::new(inner, interceptor))
         }
         /// Compress requests with the given encoding.
         ///
@@ -195,12 +286,12 @@ pub mod category_service_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
-        /// 创建分类
-        pub async fn create_category(
+        /// 添加管理员
+        pub async fn create_admin(
             &mut self,
-            request: impl tonic::IntoRequest<super::CreateCategoryRequest>,
+            request: impl tonic::IntoRequest<super::CreateAdminRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CreateCategoryReply>,
+            tonic::Response<super::CreateAdminReply>,
             tonic::Status,
         > {
             self.inner
@@ -214,21 +305,18 @@ pub mod category_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.CategoryService/CreateCategory",
+                "/pb.AdminService/CreateAdmin",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("pb.CategoryService", "CreateCategory"));
+                .insert(GrpcMethod::new("pb.AdminService", "CreateAdmin"));
             self.inner.unary(req, path, codec).await
         }
-        /// 修改分类
-        pub async fn edit_category(
+        /// 管理员列表
+        pub async fn list_admin(
             &mut self,
-            request: impl tonic::IntoRequest<super::EditCategoryRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::EditCategoryReply>,
-            tonic::Status,
-        > {
+            request: impl tonic::IntoRequest<super::ListAdminRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListAdminReply>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -240,21 +328,17 @@ pub mod category_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.CategoryService/EditCategory",
+                "/pb.AdminService/ListAdmin",
             );
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("pb.CategoryService", "EditCategory"));
+            req.extensions_mut().insert(GrpcMethod::new("pb.AdminService", "ListAdmin"));
             self.inner.unary(req, path, codec).await
         }
-        /// 分类列表
-        pub async fn list_category(
+        /// 修改管理员
+        pub async fn edit_admin(
             &mut self,
-            request: impl tonic::IntoRequest<super::ListCategoryRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ListCategoryReply>,
-            tonic::Status,
-        > {
+            request: impl tonic::IntoRequest<super::EditAdminRequest>,
+        ) -> std::result::Result<tonic::Response<super::EditAdminReply>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -266,19 +350,18 @@ pub mod category_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.CategoryService/ListCategory",
+                "/pb.AdminService/EditAdmin",
             );
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("pb.CategoryService", "ListCategory"));
+            req.extensions_mut().insert(GrpcMethod::new("pb.AdminService", "EditAdmin"));
             self.inner.unary(req, path, codec).await
         }
-        /// 删除/恢复分类
-        pub async fn toggle_category(
+        /// 修改个人资料
+        pub async fn edit_admin_profile(
             &mut self,
-            request: impl tonic::IntoRequest<super::ToggleCategoryRequest>,
+            request: impl tonic::IntoRequest<super::EditAdminProfileRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ToggleCategoryReply>,
+            tonic::Response<super::EditAdminProfileReply>,
             tonic::Status,
         > {
             self.inner
@@ -292,19 +375,19 @@ pub mod category_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.CategoryService/ToggleCategory",
+                "/pb.AdminService/EditAdminProfile",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("pb.CategoryService", "ToggleCategory"));
+                .insert(GrpcMethod::new("pb.AdminService", "EditAdminProfile"));
             self.inner.unary(req, path, codec).await
         }
-        /// 分类是否存在
-        pub async fn category_exists(
+        /// 删除/恢复管理员
+        pub async fn toggle_admin(
             &mut self,
-            request: impl tonic::IntoRequest<super::CategoryExistsRequest>,
+            request: impl tonic::IntoRequest<super::ToggleAdminRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CategoryExistsReply>,
+            tonic::Response<super::ToggleAdminReply>,
             tonic::Status,
         > {
             self.inner
@@ -318,19 +401,19 @@ pub mod category_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.CategoryService/CategoryExists",
+                "/pb.AdminService/ToggleAdmin",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("pb.CategoryService", "CategoryExists"));
+                .insert(GrpcMethod::new("pb.AdminService", "ToggleAdmin"));
             self.inner.unary(req, path, codec).await
         }
-        /// 获取分类详情
-        pub async fn get_category(
+        /// 管理员是否存在
+        pub async fn admin_exists(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetCategoryRequest>,
+            request: impl tonic::IntoRequest<super::AdminExistsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetCategoryReply>,
+            tonic::Response<super::AdminExistsReply>,
             tonic::Status,
         > {
             self.inner
@@ -344,80 +427,133 @@ pub mod category_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.CategoryService/GetCategory",
+                "/pb.AdminService/AdminExists",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("pb.CategoryService", "GetCategory"));
+                .insert(GrpcMethod::new("pb.AdminService", "AdminExists"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod category_service_server {
-    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with CategoryServiceServer.
-    #[async_trait]
-    pub trait CategoryService: Send + Sync + 'static {
-        /// 创建分类
-        async fn create_category(
-            &self,
-            request: tonic::Request<super::CreateCategoryRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateCategoryReply>,
-            tonic::Status,
-        >;
-        /// 修改分类
-        async fn edit_category(
-            &self,
-            request: tonic::Request<super::EditCategoryRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::EditCategoryReply>,
-            tonic::Status,
+        /// 获取管理员
+        pub async fn get_admin(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetAdminRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetAdminReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pb.AdminService/GetAdmin");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("pb.AdminService", "GetAdmin"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 彻底删除管理员
+        pub async fn purge_admins(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PurgeAdminsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PurgeAdminsReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.AdminService/PurgeAdmins",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.AdminService", "PurgeAdmins"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod admin_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with AdminServiceServer.
+    #[async_trait]
+    pub trait AdminService: Send + Sync + 'static {
+        /// 添加管理员
+        async fn create_admin(
+            &self,
+            request: tonic::Request<super::CreateAdminRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateAdminReply>,
+            tonic::Status,
         >;
-        /// 分类列表
-        async fn list_category(
+        /// 管理员列表
+        async fn list_admin(
             &self,
-            request: tonic::Request<super::ListCategoryRequest>,
+            request: tonic::Request<super::ListAdminRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListAdminReply>, tonic::Status>;
+        /// 修改管理员
+        async fn edit_admin(
+            &self,
+            request: tonic::Request<super::EditAdminRequest>,
+        ) -> std::result::Result<tonic::Response<super::EditAdminReply>, tonic::Status>;
+        /// 修改个人资料
+        async fn edit_admin_profile(
+            &self,
+            request: tonic::Request<super::EditAdminProfileRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ListCategoryReply>,
+            tonic::Response<super::EditAdminProfileReply>,
             tonic::Status,
         >;
-        /// 删除/恢复分类
-        async fn toggle_category(
+        /// 删除/恢复管理员
+        async fn toggle_admin(
             &self,
-            request: tonic::Request<super::ToggleCategoryRequest>,
+            request: tonic::Request<super::ToggleAdminRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ToggleCategoryReply>,
+            tonic::Response<super::ToggleAdminReply>,
             tonic::Status,
         >;
-        /// 分类是否存在
-        async fn category_exists(
+        /// 管理员是否存在
+        async fn admin_exists(
             &self,
-            request: tonic::Request<super::CategoryExistsRequest>,
+            request: tonic::Request<super::AdminExistsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CategoryExistsReply>,
+            tonic::Response<super::AdminExistsReply>,
             tonic::Status,
         >;
-        /// 获取分类详情
-        async fn get_category(
+        /// 获取管理员
+        async fn get_admin(
             &self,
-            request: tonic::Request<super::GetCategoryRequest>,
+            request: tonic::Request<super::GetAdminRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetAdminReply>, tonic::Status>;
+        /// 彻底删除管理员
+        async fn purge_admins(
+            &self,
+            request: tonic::Request<super::PurgeAdminsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetCategoryReply>,
+            tonic::Response<super::PurgeAdminsReply>,
             tonic::Status,
         >;
     }
     #[derive(Debug)]
-    pub struct CategoryServiceServer<T: CategoryService> {
+    pub struct AdminServiceServer<T: AdminService> {
         inner: Arc<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
         max_decoding_message_size: Option<usize>,
         max_encoding_message_size: Option<usize>,
     }
-    impl<T: CategoryService> CategoryServiceServer<T> {
+    impl<T: AdminService> AdminServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -468,9 +604,9 @@ pub mod category_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for CategoryServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for AdminServiceServer<T>
     where
-        T: CategoryService,
+        T: AdminService,
         B: Body + Send + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -485,26 +621,5181 @@ pub mod category_service_server {
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             match req.uri().path() {
-                "/pb.CategoryService/CreateCategory" => {
+                "/pb.AdminService/CreateAdmin" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateAdminSvc<T: AdminService>(pub Arc<T>);
+                    impl<
+                        T: AdminService,
+                    > tonic::server::UnaryService<super::CreateAdminRequest>
+                    for CreateAdminSvc<T> {
+                        type Response = super::CreateAdminReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateAdminRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminService>::create_admin(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateAdminSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.AdminService/ListAdmin" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAdminSvc<T: AdminService>(pub Arc<T>);
+                    impl<
+                        T: AdminService,
+                    > tonic::server::UnaryService<super::ListAdminRequest>
+                    for ListAdminSvc<T> {
+                        type Response = super::ListAdminReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListAdminRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminService>::list_admin(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListAdminSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.AdminService/EditAdmin" => {
+                    #[allow(non_camel_case_types)]
+                    struct EditAdminSvc<T: AdminService>(pub Arc<T>);
+                    impl<
+                        T: AdminService,
+                    > tonic::server::UnaryService<super::EditAdminRequest>
+                    for EditAdminSvc<T> {
+                        type Response = super::EditAdminReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EditAdminRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminService>::edit_admin(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EditAdminSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.AdminService/EditAdminProfile" => {
+                    #[allow(non_camel_case_types)]
+                    struct EditAdminProfileSvc<T: AdminService>(pub Arc<T>);
+                    impl<
+                        T: AdminService,
+                    > tonic::server::UnaryService<super::EditAdminProfileRequest>
+                    for EditAdminProfileSvc<T> {
+                        type Response = super::EditAdminProfileReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EditAdminProfileRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminService>::edit_admin_profile(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EditAdminProfileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.AdminService/ToggleAdmin" => {
+                    #[allow(non_camel_case_types)]
+                    struct ToggleAdminSvc<T: AdminService>(pub Arc<T>);
+                    impl<
+                        T: AdminService,
+                    > tonic::server::UnaryService<super::ToggleAdminRequest>
+                    for ToggleAdminSvc<T> {
+                        type Response = super::ToggleAdminReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ToggleAdminRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminService>::toggle_admin(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ToggleAdminSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.AdminService/AdminExists" => {
+                    #[allow(non_camel_case_types)]
+                    struct AdminExistsSvc<T: AdminService>(pub Arc<T>);
+                    impl<
+                        T: AdminService,
+                    > tonic::server::UnaryService<super::AdminExistsRequest>
+                    for AdminExistsSvc<T> {
+                        type Response = super::AdminExistsReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AdminExistsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminService>::admin_exists(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AdminExistsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.AdminService/GetAdmin" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAdminSvc<T: AdminService>(pub Arc<T>);
+                    impl<
+                        T: AdminService,
+                    > tonic::server::UnaryService<super::GetAdminRequest>
+                    for GetAdminSvc<T> {
+                        type Response = super::GetAdminReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetAdminRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminService>::get_admin(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetAdminSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.AdminService/PurgeAdmins" => {
+                    #[allow(non_camel_case_types)]
+                    struct PurgeAdminsSvc<T: AdminService>(pub Arc<T>);
+                    impl<
+                        T: AdminService,
+                    > tonic::server::UnaryService<super::PurgeAdminsRequest>
+                    for PurgeAdminsSvc<T> {
+                        type Response = super::PurgeAdminsReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PurgeAdminsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as AdminService>::purge_admins(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PurgeAdminsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", tonic::Code::Unimplemented as i32)
+                                .header(
+                                    http::header::CONTENT_TYPE,
+                                    tonic::metadata::GRPC_CONTENT_TYPE,
+                                )
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: AdminService> Clone for AdminServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: AdminService> tonic::server::NamedService for AdminServiceServer<T> {
+        const NAME: &'static str = "pb.AdminService";
+    }
+}
+/// 媒体文件。文件按内容的 SHA-256 去重保存，以下的文件名都是存储中的名称，例如 {hash}.png
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Media {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// 文件内容的 SHA-256
+    #[prost(string, tag = "2")]
+    pub hash: ::prost::alloc::string::String,
+    /// 上传时的文件名
+    #[prost(string, tag = "3")]
+    pub filename: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub mime: ::prost::alloc::string::String,
+    /// 字节数
+    #[prost(int64, tag = "5")]
+    pub size: i64,
+    /// 图片的宽度
+    #[prost(int32, optional, tag = "6")]
+    pub width: ::core::option::Option<i32>,
+    /// 图片的高度
+    #[prost(int32, optional, tag = "7")]
+    pub height: ::core::option::Option<i32>,
+    /// 上传的管理员
+    #[prost(int32, optional, tag = "8")]
+    pub uploaded_by: ::core::option::Option<i32>,
+    #[prost(message, optional, tag = "9")]
+    pub dateline: ::core::option::Option<::prost_types::Timestamp>,
+    /// 原文件
+    #[prost(string, tag = "10")]
+    pub file: ::prost::alloc::string::String,
+    /// 缩略图，仅图片有
+    #[prost(string, optional, tag = "11")]
+    pub thumbnail: ::core::option::Option<::prost::alloc::string::String>,
+    /// 原图的 WebP 版本
+    #[prost(string, optional, tag = "12")]
+    pub webp: ::core::option::Option<::prost::alloc::string::String>,
+    /// 缩略图的 WebP 版本
+    #[prost(string, optional, tag = "13")]
+    pub thumbnail_webp: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// -- 上传文件，内容相同的文件只保存一次
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UploadMediaRequest {
+    #[prost(string, tag = "1")]
+    pub filename: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    #[prost(int32, optional, tag = "3")]
+    pub uploaded_by: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UploadMediaReply {
+    #[prost(message, optional, tag = "1")]
+    pub media: ::core::option::Option<Media>,
+    /// 是否已经上传过相同内容的文件
+    #[prost(bool, tag = "2")]
+    pub duplicated: bool,
+}
+/// -- 媒体文件列表
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListMediaRequest {
+    #[prost(int32, optional, tag = "1")]
+    pub page: ::core::option::Option<i32>,
+    /// 只列出图片或只列出附件
+    #[prost(bool, optional, tag = "2")]
+    pub is_image: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListMediaReply {
+    #[prost(int32, tag = "1")]
+    pub page: i32,
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    #[prost(int64, tag = "3")]
+    pub page_totoal: i64,
+    #[prost(int64, tag = "4")]
+    pub record_total: i64,
+    #[prost(message, repeated, tag = "5")]
+    pub media: ::prost::alloc::vec::Vec<Media>,
+}
+/// -- 读取文件内容
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMediaFileRequest {
+    /// 存储中的文件名
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetMediaFileReply {
+    #[prost(string, tag = "1")]
+    pub mime: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: ::prost::alloc::vec::Vec<u8>,
+    /// 上传时的文件名，附件下载时使用
+    #[prost(string, tag = "3")]
+    pub filename: ::prost::alloc::string::String,
+}
+/// Generated client implementations.
+pub mod media_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct MediaServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl MediaServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MediaServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> MediaServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            MediaServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// 上传文件
+        pub async fn upload_media(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UploadMediaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UploadMediaReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.MediaService/UploadMedia",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.MediaService", "UploadMedia"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 媒体文件列表
+        pub async fn list_media(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListMediaRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListMediaReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.MediaService/ListMedia",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("pb.MediaService", "ListMedia"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 读取文件内容
+        pub async fn get_media_file(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetMediaFileRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMediaFileReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.MediaService/GetMediaFile",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.MediaService", "GetMediaFile"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod media_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MediaServiceServer.
+    #[async_trait]
+    pub trait MediaService: Send + Sync + 'static {
+        /// 上传文件
+        async fn upload_media(
+            &self,
+            request: tonic::Request<super::UploadMediaRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::UploadMediaReply>,
+            tonic::Status,
+        >;
+        /// 媒体文件列表
+        async fn list_media(
+            &self,
+            request: tonic::Request<super::ListMediaRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListMediaReply>, tonic::Status>;
+        /// 读取文件内容
+        async fn get_media_file(
+            &self,
+            request: tonic::Request<super::GetMediaFileRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetMediaFileReply>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct MediaServiceServer<T: MediaService> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T: MediaService> MediaServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for MediaServiceServer<T>
+    where
+        T: MediaService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/pb.MediaService/UploadMedia" => {
+                    #[allow(non_camel_case_types)]
+                    struct UploadMediaSvc<T: MediaService>(pub Arc<T>);
+                    impl<
+                        T: MediaService,
+                    > tonic::server::UnaryService<super::UploadMediaRequest>
+                    for UploadMediaSvc<T> {
+                        type Response = super::UploadMediaReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UploadMediaRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MediaService>::upload_media(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UploadMediaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.MediaService/ListMedia" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListMediaSvc<T: MediaService>(pub Arc<T>);
+                    impl<
+                        T: MediaService,
+                    > tonic::server::UnaryService<super::ListMediaRequest>
+                    for ListMediaSvc<T> {
+                        type Response = super::ListMediaReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListMediaRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MediaService>::list_media(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListMediaSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.MediaService/GetMediaFile" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetMediaFileSvc<T: MediaService>(pub Arc<T>);
+                    impl<
+                        T: MediaService,
+                    > tonic::server::UnaryService<super::GetMediaFileRequest>
+                    for GetMediaFileSvc<T> {
+                        type Response = super::GetMediaFileReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetMediaFileRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MediaService>::get_media_file(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetMediaFileSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", tonic::Code::Unimplemented as i32)
+                                .header(
+                                    http::header::CONTENT_TYPE,
+                                    tonic::metadata::GRPC_CONTENT_TYPE,
+                                )
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: MediaService> Clone for MediaServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: MediaService> tonic::server::NamedService for MediaServiceServer<T> {
+        const NAME: &'static str = "pb.MediaService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Category {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub is_del: bool,
+    /// 删除时间
+    #[prost(message, optional, tag = "4")]
+    pub deleted_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// 执行删除的管理员
+    #[prost(int32, optional, tag = "5")]
+    pub deleted_by: ::core::option::Option<i32>,
+    /// 版本号，每次修改加1
+    #[prost(int32, tag = "6")]
+    pub version: i32,
+    /// 已发布的文章数，只在列表中要求统计时提供
+    #[prost(int64, tag = "7")]
+    pub topic_count: i64,
+    /// 最新一篇已发布文章的发布时间
+    #[prost(message, optional, tag = "8")]
+    pub latest_dateline: ::core::option::Option<::prost_types::Timestamp>,
+    /// 上级分类，顶级分类为空
+    #[prost(int32, optional, tag = "9")]
+    pub parent_id: ::core::option::Option<i32>,
+    /// 排序权重，越小越靠前
+    #[prost(int32, tag = "10")]
+    pub sort_weight: i32,
+}
+/// 分类树中的节点
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CategoryNode {
+    #[prost(message, optional, tag = "1")]
+    pub category: ::core::option::Option<Category>,
+    /// 下级分类
+    #[prost(message, repeated, tag = "2")]
+    pub children: ::prost::alloc::vec::Vec<CategoryNode>,
+}
+/// 创建分类
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCategoryRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// 上级分类，不提供则为顶级分类
+    #[prost(int32, optional, tag = "2")]
+    pub parent_id: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CreateCategoryReply {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+}
+/// 修改分类
+/// 提供 version 时，分类已被修改过则返回 Aborted，并在 metadata 中附带当前的版本号
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditCategoryRequest {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// 修改前读取到的版本号，不提供则不检查
+    #[prost(int32, optional, tag = "3")]
+    pub version: ::core::option::Option<i32>,
+    /// 上级分类，不提供则保持不变。不能是自己或自己的下级分类
+    #[prost(int32, optional, tag = "4")]
+    pub parent_id: ::core::option::Option<i32>,
+    /// 改为顶级分类，此时忽略 parent_id
+    #[prost(bool, tag = "5")]
+    pub clear_parent: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EditCategoryReply {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(bool, tag = "2")]
+    pub ok: bool,
+    /// 修改后的版本号
+    #[prost(int32, tag = "3")]
+    pub version: i32,
+}
+/// 列出分类
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCategoryRequest {
+    /// 根据分类名称查找
+    #[prost(string, optional, tag = "1")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    /// 是否删除
+    #[prost(bool, optional, tag = "2")]
+    pub is_del: ::core::option::Option<bool>,
+    /// 是否统计每个分类的文章数和最新文章的发布时间
+    #[prost(bool, optional, tag = "3")]
+    pub with_stats: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCategoryReply {
+    #[prost(message, repeated, tag = "1")]
+    pub categories: ::prost::alloc::vec::Vec<Category>,
+}
+/// 分类树
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListCategoryTreeRequest {
+    /// 是否删除
+    #[prost(bool, optional, tag = "1")]
+    pub is_del: ::core::option::Option<bool>,
+    /// 是否统计每个分类的文章数和最新文章的发布时间
+    #[prost(bool, optional, tag = "2")]
+    pub with_stats: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCategoryTreeReply {
+    /// 顶级分类，上级分类不在结果中的分类也作为顶级分类
+    #[prost(message, repeated, tag = "1")]
+    pub nodes: ::prost::alloc::vec::Vec<CategoryNode>,
+}
+/// 删除/恢复分类
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ToggleCategoryRequest {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    /// 执行删除的管理员
+    #[prost(int32, optional, tag = "2")]
+    pub admin_id: ::core::option::Option<i32>,
+    /// 删除时有效
+    #[prost(enumeration = "CategoryDeleteMode", tag = "3")]
+    pub delete_mode: i32,
+    /// 移动文章时的目标分类
+    #[prost(int32, optional, tag = "4")]
+    pub move_to: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ToggleCategoryReply {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(bool, tag = "2")]
+    pub is_del: bool,
+    /// 移动到其它分类的文章数
+    #[prost(int64, tag = "3")]
+    pub moved: i64,
+}
+/// 分类是否存在
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CategoryExistsRequest {
+    #[prost(oneof = "category_exists_request::Condition", tags = "1, 2")]
+    pub condition: ::core::option::Option<category_exists_request::Condition>,
+}
+/// Nested message and enum types in `CategoryExistsRequest`.
+pub mod category_exists_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Condition {
+        #[prost(string, tag = "1")]
+        Name(::prost::alloc::string::String),
+        #[prost(int32, tag = "2")]
+        Id(i32),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CategoryExistsReply {
+    #[prost(bool, tag = "1")]
+    pub exists: bool,
+}
+/// 分类详情
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetCategoryRequest {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(bool, optional, tag = "2")]
+    pub is_del: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCategoryReply {
+    #[prost(message, optional, tag = "1")]
+    pub category: ::core::option::Option<Category>,
+}
+/// 彻底删除回收站中的分类，仍有文章的分类不会被删除
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PurgeCategoriesRequest {
+    #[prost(int32, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PurgeCategoriesReply {
+    /// 已彻底删除的分类
+    #[prost(int32, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i32>,
+}
+/// 调整分类顺序，按照 ids 的顺序重新设置排序权重，不在 ids 中的分类保持原来的权重
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReorderCategoriesRequest {
+    #[prost(int32, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReorderCategoriesReply {
+    /// 调整了顺序的分类数
+    #[prost(int32, tag = "1")]
+    pub updated: i32,
+}
+/// 删除分类时如何处理分类中的文章
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CategoryDeleteMode {
+    /// 分类中还有文章时不允许删除
+    Block = 0,
+    /// 先把文章移动到其它分类
+    Move = 1,
+    /// 保留文章，前台不再显示
+    Hide = 2,
+}
+impl CategoryDeleteMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CategoryDeleteMode::Block => "CATEGORY_DELETE_MODE_BLOCK",
+            CategoryDeleteMode::Move => "CATEGORY_DELETE_MODE_MOVE",
+            CategoryDeleteMode::Hide => "CATEGORY_DELETE_MODE_HIDE",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CATEGORY_DELETE_MODE_BLOCK" => Some(Self::Block),
+            "CATEGORY_DELETE_MODE_MOVE" => Some(Self::Move),
+            "CATEGORY_DELETE_MODE_HIDE" => Some(Self::Hide),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod category_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct CategoryServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl CategoryServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> CategoryServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> CategoryServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            CategoryServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// 创建分类
+        pub async fn create_category(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateCategoryReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CategoryService/CreateCategory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CategoryService", "CreateCategory"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 修改分类
+        pub async fn edit_category(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EditCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EditCategoryReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CategoryService/EditCategory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CategoryService", "EditCategory"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 分类列表
+        pub async fn list_category(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCategoryReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CategoryService/ListCategory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CategoryService", "ListCategory"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 分类树
+        pub async fn list_category_tree(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCategoryTreeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCategoryTreeReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CategoryService/ListCategoryTree",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CategoryService", "ListCategoryTree"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 删除/恢复分类
+        pub async fn toggle_category(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ToggleCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ToggleCategoryReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CategoryService/ToggleCategory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CategoryService", "ToggleCategory"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 分类是否存在
+        pub async fn category_exists(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CategoryExistsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CategoryExistsReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CategoryService/CategoryExists",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CategoryService", "CategoryExists"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 获取分类详情
+        pub async fn get_category(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCategoryReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CategoryService/GetCategory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CategoryService", "GetCategory"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 彻底删除分类
+        pub async fn purge_categories(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PurgeCategoriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PurgeCategoriesReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CategoryService/PurgeCategories",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CategoryService", "PurgeCategories"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 调整分类顺序
+        pub async fn reorder_categories(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReorderCategoriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReorderCategoriesReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CategoryService/ReorderCategories",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CategoryService", "ReorderCategories"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod category_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with CategoryServiceServer.
+    #[async_trait]
+    pub trait CategoryService: Send + Sync + 'static {
+        /// 创建分类
+        async fn create_category(
+            &self,
+            request: tonic::Request<super::CreateCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateCategoryReply>,
+            tonic::Status,
+        >;
+        /// 修改分类
+        async fn edit_category(
+            &self,
+            request: tonic::Request<super::EditCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EditCategoryReply>,
+            tonic::Status,
+        >;
+        /// 分类列表
+        async fn list_category(
+            &self,
+            request: tonic::Request<super::ListCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCategoryReply>,
+            tonic::Status,
+        >;
+        /// 分类树
+        async fn list_category_tree(
+            &self,
+            request: tonic::Request<super::ListCategoryTreeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCategoryTreeReply>,
+            tonic::Status,
+        >;
+        /// 删除/恢复分类
+        async fn toggle_category(
+            &self,
+            request: tonic::Request<super::ToggleCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ToggleCategoryReply>,
+            tonic::Status,
+        >;
+        /// 分类是否存在
+        async fn category_exists(
+            &self,
+            request: tonic::Request<super::CategoryExistsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CategoryExistsReply>,
+            tonic::Status,
+        >;
+        /// 获取分类详情
+        async fn get_category(
+            &self,
+            request: tonic::Request<super::GetCategoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCategoryReply>,
+            tonic::Status,
+        >;
+        /// 彻底删除分类
+        async fn purge_categories(
+            &self,
+            request: tonic::Request<super::PurgeCategoriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PurgeCategoriesReply>,
+            tonic::Status,
+        >;
+        /// 调整分类顺序
+        async fn reorder_categories(
+            &self,
+            request: tonic::Request<super::ReorderCategoriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReorderCategoriesReply>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct CategoryServiceServer<T: CategoryService> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T: CategoryService> CategoryServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for CategoryServiceServer<T>
+    where
+        T: CategoryService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/pb.CategoryService/CreateCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateCategorySvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::CreateCategoryRequest>
+                    for CreateCategorySvc<T> {
+                        type Response = super::CreateCategoryReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoryService>::create_category(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateCategorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CategoryService/EditCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct EditCategorySvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::EditCategoryRequest>
+                    for EditCategorySvc<T> {
+                        type Response = super::EditCategoryReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EditCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoryService>::edit_category(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EditCategorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CategoryService/ListCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCategorySvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::ListCategoryRequest>
+                    for ListCategorySvc<T> {
+                        type Response = super::ListCategoryReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoryService>::list_category(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListCategorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CategoryService/ListCategoryTree" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCategoryTreeSvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::ListCategoryTreeRequest>
+                    for ListCategoryTreeSvc<T> {
+                        type Response = super::ListCategoryTreeReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCategoryTreeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoryService>::list_category_tree(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListCategoryTreeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CategoryService/ToggleCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ToggleCategorySvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::ToggleCategoryRequest>
+                    for ToggleCategorySvc<T> {
+                        type Response = super::ToggleCategoryReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ToggleCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoryService>::toggle_category(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ToggleCategorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CategoryService/CategoryExists" => {
+                    #[allow(non_camel_case_types)]
+                    struct CategoryExistsSvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::CategoryExistsRequest>
+                    for CategoryExistsSvc<T> {
+                        type Response = super::CategoryExistsReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CategoryExistsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoryService>::category_exists(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CategoryExistsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CategoryService/GetCategory" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCategorySvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::GetCategoryRequest>
+                    for GetCategorySvc<T> {
+                        type Response = super::GetCategoryReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCategoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoryService>::get_category(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCategorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CategoryService/PurgeCategories" => {
+                    #[allow(non_camel_case_types)]
+                    struct PurgeCategoriesSvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::PurgeCategoriesRequest>
+                    for PurgeCategoriesSvc<T> {
+                        type Response = super::PurgeCategoriesReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PurgeCategoriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoryService>::purge_categories(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PurgeCategoriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CategoryService/ReorderCategories" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReorderCategoriesSvc<T: CategoryService>(pub Arc<T>);
+                    impl<
+                        T: CategoryService,
+                    > tonic::server::UnaryService<super::ReorderCategoriesRequest>
+                    for ReorderCategoriesSvc<T> {
+                        type Response = super::ReorderCategoriesReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReorderCategoriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CategoryService>::reorder_categories(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReorderCategoriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", tonic::Code::Unimplemented as i32)
+                                .header(
+                                    http::header::CONTENT_TYPE,
+                                    tonic::metadata::GRPC_CONTENT_TYPE,
+                                )
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: CategoryService> Clone for CategoryServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: CategoryService> tonic::server::NamedService for CategoryServiceServer<T> {
+        const NAME: &'static str = "pb.CategoryService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Comment {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(int64, tag = "2")]
+    pub topic_id: i64,
+    /// 回复的评论
+    #[prost(int64, optional, tag = "3")]
+    pub parent_id: ::core::option::Option<i64>,
+    /// 昵称
+    #[prost(string, tag = "4")]
+    pub author: ::prost::alloc::string::String,
+    /// 邮箱，不对外显示
+    #[prost(string, tag = "5")]
+    pub email: ::prost::alloc::string::String,
+    /// 纯文本
+    #[prost(string, tag = "6")]
+    pub content: ::prost::alloc::string::String,
+    #[prost(enumeration = "CommentStatus", tag = "7")]
+    pub status: i32,
+    #[prost(string, tag = "8")]
+    pub ip: ::prost::alloc::string::String,
+    #[prost(string, tag = "9")]
+    pub user_agent: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "10")]
+    pub dateline: ::core::option::Option<::prost_types::Timestamp>,
+    /// 回复，仅在评论串中返回
+    #[prost(message, repeated, tag = "11")]
+    pub replies: ::prost::alloc::vec::Vec<Comment>,
+}
+/// -- 发表评论，新评论需要审核后才显示
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateCommentRequest {
+    #[prost(int64, tag = "1")]
+    pub topic_id: i64,
+    /// 回复的评论，必须属于同一篇文章
+    #[prost(int64, optional, tag = "2")]
+    pub parent_id: ::core::option::Option<i64>,
+    #[prost(string, tag = "3")]
+    pub author: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub email: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub content: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub ip: ::prost::alloc::string::String,
+    #[prost(string, tag = "7")]
+    pub user_agent: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CreateCommentReply {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(enumeration = "CommentStatus", tag = "2")]
+    pub status: i32,
+}
+/// -- 评论列表，用于审核
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListCommentRequest {
+    /// 页码
+    #[prost(int32, optional, tag = "1")]
+    pub page: ::core::option::Option<i32>,
+    /// 文章
+    #[prost(int64, optional, tag = "2")]
+    pub topic_id: ::core::option::Option<i64>,
+    /// 状态
+    #[prost(enumeration = "CommentStatus", optional, tag = "3")]
+    pub status: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListCommentReply {
+    /// 当前页码
+    #[prost(int32, tag = "1")]
+    pub page: i32,
+    /// 每页条数
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    /// 总页数
+    #[prost(int64, tag = "3")]
+    pub page_totoal: i64,
+    /// 总记录数
+    #[prost(int64, tag = "4")]
+    pub record_total: i64,
+    /// 按ID倒序
+    #[prost(message, repeated, tag = "5")]
+    pub comments: ::prost::alloc::vec::Vec<Comment>,
+}
+/// -- 文章的评论串，只包含已通过的评论
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetCommentThreadRequest {
+    #[prost(int64, tag = "1")]
+    pub topic_id: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCommentThreadReply {
+    /// 顶层评论按时间正序，回复在 replies 中
+    #[prost(message, repeated, tag = "1")]
+    pub comments: ::prost::alloc::vec::Vec<Comment>,
+    /// 评论串中的评论总数
+    #[prost(int64, tag = "2")]
+    pub total: i64,
+}
+/// -- 审核评论
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ModerateCommentRequest {
+    #[prost(int64, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i64>,
+    #[prost(enumeration = "CommentStatus", tag = "2")]
+    pub status: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ModerateCommentReply {
+    #[prost(int64, tag = "1")]
+    pub rows_affected: i64,
+}
+/// -- 文章的评论数，只统计已通过的评论
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CountCommentRequest {
+    #[prost(int64, repeated, tag = "1")]
+    pub topic_ids: ::prost::alloc::vec::Vec<i64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CountCommentReply {
+    /// 文章ID => 评论数，没有评论的文章不返回
+    #[prost(map = "int64, int64", tag = "1")]
+    pub counts: ::std::collections::HashMap<i64, i64>,
+}
+/// 评论状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CommentStatus {
+    /// 待审核
+    Pending = 0,
+    /// 已通过
+    Approved = 1,
+    /// 垃圾评论
+    Spam = 2,
+    /// 已删除
+    Deleted = 3,
+}
+impl CommentStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CommentStatus::Pending => "COMMENT_STATUS_PENDING",
+            CommentStatus::Approved => "COMMENT_STATUS_APPROVED",
+            CommentStatus::Spam => "COMMENT_STATUS_SPAM",
+            CommentStatus::Deleted => "COMMENT_STATUS_DELETED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "COMMENT_STATUS_PENDING" => Some(Self::Pending),
+            "COMMENT_STATUS_APPROVED" => Some(Self::Approved),
+            "COMMENT_STATUS_SPAM" => Some(Self::Spam),
+            "COMMENT_STATUS_DELETED" => Some(Self::Deleted),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod comment_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct CommentServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl CommentServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> CommentServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> CommentServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            CommentServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// 发表评论
+        pub async fn create_comment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateCommentReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CommentService/CreateComment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CommentService", "CreateComment"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 评论列表
+        pub async fn list_comment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCommentReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CommentService/ListComment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CommentService", "ListComment"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 文章的评论串
+        pub async fn get_comment_thread(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCommentThreadRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCommentThreadReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CommentService/GetCommentThread",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CommentService", "GetCommentThread"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 审核评论
+        pub async fn moderate_comment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ModerateCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ModerateCommentReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CommentService/ModerateComment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CommentService", "ModerateComment"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 文章的评论数
+        pub async fn count_comment(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CountCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CountCommentReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.CommentService/CountComment",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.CommentService", "CountComment"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod comment_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with CommentServiceServer.
+    #[async_trait]
+    pub trait CommentService: Send + Sync + 'static {
+        /// 发表评论
+        async fn create_comment(
+            &self,
+            request: tonic::Request<super::CreateCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateCommentReply>,
+            tonic::Status,
+        >;
+        /// 评论列表
+        async fn list_comment(
+            &self,
+            request: tonic::Request<super::ListCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListCommentReply>,
+            tonic::Status,
+        >;
+        /// 文章的评论串
+        async fn get_comment_thread(
+            &self,
+            request: tonic::Request<super::GetCommentThreadRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetCommentThreadReply>,
+            tonic::Status,
+        >;
+        /// 审核评论
+        async fn moderate_comment(
+            &self,
+            request: tonic::Request<super::ModerateCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ModerateCommentReply>,
+            tonic::Status,
+        >;
+        /// 文章的评论数
+        async fn count_comment(
+            &self,
+            request: tonic::Request<super::CountCommentRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CountCommentReply>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct CommentServiceServer<T: CommentService> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T: CommentService> CommentServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for CommentServiceServer<T>
+    where
+        T: CommentService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/pb.CommentService/CreateComment" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateCommentSvc<T: CommentService>(pub Arc<T>);
+                    impl<
+                        T: CommentService,
+                    > tonic::server::UnaryService<super::CreateCommentRequest>
+                    for CreateCommentSvc<T> {
+                        type Response = super::CreateCommentReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateCommentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CommentService>::create_comment(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateCommentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CommentService/ListComment" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListCommentSvc<T: CommentService>(pub Arc<T>);
+                    impl<
+                        T: CommentService,
+                    > tonic::server::UnaryService<super::ListCommentRequest>
+                    for ListCommentSvc<T> {
+                        type Response = super::ListCommentReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListCommentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CommentService>::list_comment(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListCommentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CommentService/GetCommentThread" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCommentThreadSvc<T: CommentService>(pub Arc<T>);
+                    impl<
+                        T: CommentService,
+                    > tonic::server::UnaryService<super::GetCommentThreadRequest>
+                    for GetCommentThreadSvc<T> {
+                        type Response = super::GetCommentThreadReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCommentThreadRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CommentService>::get_comment_thread(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetCommentThreadSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CommentService/ModerateComment" => {
+                    #[allow(non_camel_case_types)]
+                    struct ModerateCommentSvc<T: CommentService>(pub Arc<T>);
+                    impl<
+                        T: CommentService,
+                    > tonic::server::UnaryService<super::ModerateCommentRequest>
+                    for ModerateCommentSvc<T> {
+                        type Response = super::ModerateCommentReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ModerateCommentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CommentService>::moderate_comment(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ModerateCommentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.CommentService/CountComment" => {
+                    #[allow(non_camel_case_types)]
+                    struct CountCommentSvc<T: CommentService>(pub Arc<T>);
+                    impl<
+                        T: CommentService,
+                    > tonic::server::UnaryService<super::CountCommentRequest>
+                    for CountCommentSvc<T> {
+                        type Response = super::CountCommentReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CountCommentRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CommentService>::count_comment(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CountCommentSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", tonic::Code::Unimplemented as i32)
+                                .header(
+                                    http::header::CONTENT_TYPE,
+                                    tonic::metadata::GRPC_CONTENT_TYPE,
+                                )
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: CommentService> Clone for CommentServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: CommentService> tonic::server::NamedService for CommentServiceServer<T> {
+        const NAME: &'static str = "pb.CommentService";
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Topic {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(string, tag = "2")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub category_id: i32,
+    #[prost(string, tag = "4")]
+    pub summary: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub content: ::prost::alloc::string::String,
+    #[prost(int32, tag = "6")]
+    pub hit: i32,
+    #[prost(bool, tag = "7")]
+    pub is_del: bool,
+    #[prost(message, optional, tag = "8")]
+    pub dateline: ::core::option::Option<::prost_types::Timestamp>,
+    /// 由 Markdown 渲染并净化后的 HTML
+    #[prost(string, tag = "9")]
+    pub content_html: ::prost::alloc::string::String,
+    #[prost(enumeration = "TopicStatus", tag = "10")]
+    pub status: i32,
+    /// 定时发布的时间
+    #[prost(message, optional, tag = "11")]
+    pub publish_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// 标签名称
+    #[prost(string, repeated, tag = "12")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 永久链接
+    #[prost(string, tag = "13")]
+    pub slug: ::prost::alloc::string::String,
+    /// 删除时间
+    #[prost(message, optional, tag = "14")]
+    pub deleted_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// 执行删除的管理员
+    #[prost(int32, optional, tag = "15")]
+    pub deleted_by: ::core::option::Option<i32>,
+    /// 作者
+    #[prost(int32, optional, tag = "16")]
+    pub author_id: ::core::option::Option<i32>,
+    /// 字数
+    #[prost(int32, tag = "17")]
+    pub word_count: i32,
+    /// 预计阅读时间（分钟）
+    #[prost(int32, tag = "18")]
+    pub reading_time: i32,
+    /// 置顶范围
+    #[prost(enumeration = "PinScope", tag = "19")]
+    pub pin_scope: i32,
+    /// 置顶顺序，越小越靠前
+    #[prost(int32, tag = "20")]
+    pub pin_order: i32,
+    /// 是否推荐
+    #[prost(bool, tag = "21")]
+    pub is_featured: bool,
+    /// 版本号，每次修改加1
+    #[prost(int32, tag = "22")]
+    pub version: i32,
+    /// 最后修改文章的管理员
+    #[prost(int32, optional, tag = "23")]
+    pub last_edited_by: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TagNames {
+    #[prost(string, repeated, tag = "1")]
+    pub names: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// 时间区间，包含开始时间，不包含结束时间。可以只提供其中一个
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DatelineRange {
+    #[prost(message, optional, tag = "1")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// -- 创建文章
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateTopicRequest {
+    #[prost(string, tag = "1")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub category_id: i32,
+    /// Markdown 格式
+    #[prost(string, tag = "3")]
+    pub content: ::prost::alloc::string::String,
+    /// 如果没有提供摘要，则自动从内容中截取
+    #[prost(string, optional, tag = "4")]
+    pub summary: ::core::option::Option<::prost::alloc::string::String>,
+    /// 默认为已发布
+    #[prost(enumeration = "TopicStatus", optional, tag = "5")]
+    pub status: ::core::option::Option<i32>,
+    /// 定时发布时必须提供
+    #[prost(message, optional, tag = "6")]
+    pub publish_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// 发布时间，可用于补录旧文章
+    #[prost(message, optional, tag = "7")]
+    pub dateline: ::core::option::Option<::prost_types::Timestamp>,
+    /// 标签，不存在的标签会自动创建
+    #[prost(string, repeated, tag = "8")]
+    pub tags: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 永久链接，不提供则根据标题生成
+    #[prost(string, optional, tag = "9")]
+    pub slug: ::core::option::Option<::prost::alloc::string::String>,
+    /// 作者，即创建文章的管理员
+    #[prost(int32, optional, tag = "10")]
+    pub author_id: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct CreateTopicReply {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+/// -- 修改文章
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EditTopicRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(string, tag = "2")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub category_id: i32,
+    /// 如果没有提供摘要，则自动从内容中截取
+    #[prost(string, optional, tag = "4")]
+    pub summary: ::core::option::Option<::prost::alloc::string::String>,
+    /// Markdown 格式
+    #[prost(string, tag = "5")]
+    pub content: ::prost::alloc::string::String,
+    /// 不提供则保持原状态
+    #[prost(enumeration = "TopicStatus", optional, tag = "6")]
+    pub status: ::core::option::Option<i32>,
+    /// 定时发布时必须提供
+    #[prost(message, optional, tag = "7")]
+    pub publish_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// 发布时间，不提供则保持不变
+    #[prost(message, optional, tag = "8")]
+    pub dateline: ::core::option::Option<::prost_types::Timestamp>,
+    /// 标签，不提供则保持不变
+    #[prost(message, optional, tag = "9")]
+    pub tags: ::core::option::Option<TagNames>,
+    /// 永久链接，不提供则保持不变，为空则根据标题重新生成
+    #[prost(string, optional, tag = "10")]
+    pub slug: ::core::option::Option<::prost::alloc::string::String>,
+    /// 修改文章的管理员，记录为最后修改者；文章还没有作者时作为作者
+    #[prost(int32, optional, tag = "11")]
+    pub author_id: ::core::option::Option<i32>,
+    /// 修改前读取到的版本号，不提供则不检查。
+    /// 文章已被修改过则返回 Aborted，并在 metadata 中附带当前的版本号
+    #[prost(int32, optional, tag = "12")]
+    pub version: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EditTopicReply {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(bool, tag = "2")]
+    pub ok: bool,
+    /// 修改后的版本号
+    #[prost(int32, tag = "3")]
+    pub version: i32,
+}
+/// -- 文章列表
+/// 推荐使用游标翻页：第一页不提供 cursor，之后使用上次返回的 next_cursor/prev_cursor
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTopicRequest {
+    /// 页码，提供游标时忽略
+    #[prost(int32, optional, tag = "1")]
+    pub page: ::core::option::Option<i32>,
+    /// 分类
+    #[prost(int32, optional, tag = "2")]
+    pub category_id: ::core::option::Option<i32>,
+    /// 关键字
+    #[prost(string, optional, tag = "3")]
+    pub keyword: ::core::option::Option<::prost::alloc::string::String>,
+    /// 是否删除
+    #[prost(bool, optional, tag = "4")]
+    pub is_del: ::core::option::Option<bool>,
+    /// 时间区间
+    #[prost(message, optional, tag = "5")]
+    pub dateline_range: ::core::option::Option<DatelineRange>,
+    /// 状态
+    #[prost(enumeration = "TopicStatus", optional, tag = "6")]
+    pub status: ::core::option::Option<i32>,
+    /// 标签名称
+    #[prost(string, optional, tag = "7")]
+    pub tag: ::core::option::Option<::prost::alloc::string::String>,
+    /// 每页条数，默认30，最大100
+    #[prost(int32, optional, tag = "8")]
+    pub page_size: ::core::option::Option<i32>,
+    /// 排序方式，默认有关键字时按相关度，否则按发布时间
+    #[prost(enumeration = "TopicSort", optional, tag = "9")]
+    pub sort: ::core::option::Option<i32>,
+    /// 翻页游标
+    #[prost(string, optional, tag = "10")]
+    pub cursor: ::core::option::Option<::prost::alloc::string::String>,
+    /// 不统计总记录数和总页数
+    #[prost(bool, optional, tag = "11")]
+    pub skip_total: ::core::option::Option<bool>,
+    /// 作者
+    #[prost(int32, optional, tag = "12")]
+    pub author_id: ::core::option::Option<i32>,
+    /// 第一页之前先列出置顶的文章：全站置顶总是列出，分类置顶只在按该分类筛选时列出。
+    /// 置顶的文章同样需要满足其它条件，并且不再出现在之后的列表中
+    #[prost(bool, optional, tag = "13")]
+    pub pinned_first: ::core::option::Option<bool>,
+    /// 所属分类是否删除
+    #[prost(bool, optional, tag = "14")]
+    pub category_is_del: ::core::option::Option<bool>,
+    /// 按分类筛选时，是否包括所有下级分类的文章
+    #[prost(bool, optional, tag = "15")]
+    pub include_descendants: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTopicReply {
+    /// 当前页码
+    #[prost(int32, tag = "1")]
+    pub page: i32,
+    /// 每页条数
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    /// 总页数
+    #[prost(int64, tag = "3")]
+    pub page_totoal: i64,
+    /// 总记录数
+    #[prost(int64, tag = "4")]
+    pub record_total: i64,
+    /// 文章列表
+    #[prost(message, repeated, tag = "5")]
+    pub topics: ::prost::alloc::vec::Vec<Topic>,
+    /// 关键字高亮，仅在按关键字搜索时返回
+    #[prost(message, repeated, tag = "6")]
+    pub highlights: ::prost::alloc::vec::Vec<TopicHighlight>,
+    /// 下一页的游标，没有下一页时为空
+    #[prost(string, optional, tag = "7")]
+    pub next_cursor: ::core::option::Option<::prost::alloc::string::String>,
+    /// 上一页的游标，没有上一页时为空
+    #[prost(string, optional, tag = "8")]
+    pub prev_cursor: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// 搜索结果高亮片段，已做 HTML 转义，命中的词用 <mark> 包裹
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TopicHighlight {
+    /// 文章ID
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// 高亮后的标题
+    #[prost(string, tag = "2")]
+    pub title: ::prost::alloc::string::String,
+    /// 高亮后的内容片段
+    #[prost(string, tag = "3")]
+    pub snippet: ::prost::alloc::string::String,
+}
+/// -- 删除/恢复文章
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ToggleTopicRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// 执行删除的管理员
+    #[prost(int32, optional, tag = "2")]
+    pub admin_id: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ToggleTopicReply {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(bool, tag = "2")]
+    pub is_del: bool,
+}
+/// -- 获取文章详情
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTopicRequest {
+    #[prost(bool, optional, tag = "2")]
+    pub is_del: ::core::option::Option<bool>,
+    /// 是否同时增加点击量
+    #[prost(bool, optional, tag = "3")]
+    pub inc_hit: ::core::option::Option<bool>,
+    /// 状态
+    #[prost(enumeration = "TopicStatus", optional, tag = "4")]
+    pub status: ::core::option::Option<i32>,
+    /// 访客标识，同一访客短时间内重复访问只计一次点击
+    #[prost(string, optional, tag = "6")]
+    pub visitor_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// 访客的 User-Agent，爬虫的访问不计点击
+    #[prost(string, optional, tag = "7")]
+    pub user_agent: ::core::option::Option<::prost::alloc::string::String>,
+    /// 所属分类是否删除，前台不显示已删除分类中的文章
+    #[prost(bool, optional, tag = "8")]
+    pub category_is_del: ::core::option::Option<bool>,
+    #[prost(oneof = "get_topic_request::Condition", tags = "1, 5")]
+    pub condition: ::core::option::Option<get_topic_request::Condition>,
+}
+/// Nested message and enum types in `GetTopicRequest`.
+pub mod get_topic_request {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Condition {
+        #[prost(int64, tag = "1")]
+        Id(i64),
+        /// 也可以使用修改前的旧链接
+        #[prost(string, tag = "5")]
+        Slug(::prost::alloc::string::String),
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTopicReply {
+    #[prost(message, optional, tag = "1")]
+    pub topic: ::core::option::Option<Topic>,
+}
+/// -- 文章修订
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TopicRevision {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(int64, tag = "2")]
+    pub topic_id: i64,
+    #[prost(string, tag = "3")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(int32, tag = "4")]
+    pub category_id: i32,
+    #[prost(string, tag = "5")]
+    pub summary: ::prost::alloc::string::String,
+    #[prost(string, tag = "6")]
+    pub content: ::prost::alloc::string::String,
+    /// 修订时间
+    #[prost(message, optional, tag = "7")]
+    pub dateline: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// -- 修订列表
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListTopicRevisionRequest {
+    #[prost(int64, tag = "1")]
+    pub topic_id: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListTopicRevisionReply {
+    /// 按修订时间倒序，不包含内容
+    #[prost(message, repeated, tag = "1")]
+    pub revisions: ::prost::alloc::vec::Vec<TopicRevision>,
+}
+/// -- 获取修订
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetTopicRevisionRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTopicRevisionReply {
+    #[prost(message, optional, tag = "1")]
+    pub revision: ::core::option::Option<TopicRevision>,
+}
+/// -- 对比修订
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiffLine {
+    #[prost(enumeration = "diff_line::Tag", tag = "1")]
+    pub tag: i32,
+    #[prost(string, tag = "2")]
+    pub text: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `DiffLine`.
+pub mod diff_line {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Tag {
+        Equal = 0,
+        Delete = 1,
+        Insert = 2,
+    }
+    impl Tag {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Tag::Equal => "EQUAL",
+                Tag::Delete => "DELETE",
+                Tag::Insert => "INSERT",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "EQUAL" => Some(Self::Equal),
+                "DELETE" => Some(Self::Delete),
+                "INSERT" => Some(Self::Insert),
+                _ => None,
+            }
+        }
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DiffTopicRevisionRequest {
+    /// 旧修订
+    #[prost(int64, tag = "1")]
+    pub from_id: i64,
+    /// 新修订
+    #[prost(int64, tag = "2")]
+    pub to_id: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DiffTopicRevisionReply {
+    #[prost(message, optional, tag = "1")]
+    pub from: ::core::option::Option<TopicRevision>,
+    #[prost(message, optional, tag = "2")]
+    pub to: ::core::option::Option<TopicRevision>,
+    #[prost(message, repeated, tag = "3")]
+    pub title: ::prost::alloc::vec::Vec<DiffLine>,
+    #[prost(message, repeated, tag = "4")]
+    pub summary: ::prost::alloc::vec::Vec<DiffLine>,
+    #[prost(message, repeated, tag = "5")]
+    pub content: ::prost::alloc::vec::Vec<DiffLine>,
+}
+/// -- 恢复修订，恢复后会产生一条新的修订
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RestoreTopicRevisionRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// 恢复前读取到的文章版本号，不提供则不检查
+    #[prost(int32, optional, tag = "2")]
+    pub version: ::core::option::Option<i32>,
+    /// 执行恢复的管理员，记录为文章的最后修改者
+    #[prost(int32, optional, tag = "3")]
+    pub admin_id: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct RestoreTopicRevisionReply {
+    #[prost(int64, tag = "1")]
+    pub topic_id: i64,
+    #[prost(bool, tag = "2")]
+    pub ok: bool,
+    /// 恢复后的文章版本号
+    #[prost(int32, tag = "3")]
+    pub version: i32,
+}
+/// -- 相关文章
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetRelatedTopicsRequest {
+    /// 文章ID
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// 返回的条数，默认5，最大20
+    #[prost(int32, optional, tag = "2")]
+    pub limit: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetRelatedTopicsReply {
+    /// 按相关程度倒序，不包含内容
+    #[prost(message, repeated, tag = "1")]
+    pub topics: ::prost::alloc::vec::Vec<Topic>,
+}
+/// -- 归档
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListArchiveRequest {
+    /// 是否删除
+    #[prost(bool, optional, tag = "1")]
+    pub is_del: ::core::option::Option<bool>,
+    /// 状态
+    #[prost(enumeration = "TopicStatus", optional, tag = "2")]
+    pub status: ::core::option::Option<i32>,
+    /// 所属分类是否删除
+    #[prost(bool, optional, tag = "3")]
+    pub category_is_del: ::core::option::Option<bool>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct Archive {
+    #[prost(int32, tag = "1")]
+    pub year: i32,
+    #[prost(int32, tag = "2")]
+    pub month: i32,
+    /// 文章数量
+    #[prost(int64, tag = "3")]
+    pub topic_count: i64,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListArchiveReply {
+    /// 按年月倒序，使用数据库的时区
+    #[prost(message, repeated, tag = "1")]
+    pub archives: ::prost::alloc::vec::Vec<Archive>,
+}
+/// -- 导出文章，包括已删除的文章，按ID正序
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ExportTopicsRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportTopicResult {
+    /// 在导入数据中的序号，从0开始
+    #[prost(int32, tag = "1")]
+    pub index: i32,
+    /// 文章ID，失败时为0
+    #[prost(int64, tag = "2")]
+    pub id: i64,
+    /// 永久链接，与已有的文章重复时会追加序号
+    #[prost(string, tag = "3")]
+    pub slug: ::prost::alloc::string::String,
+    #[prost(enumeration = "ImportAction", tag = "4")]
+    pub action: i32,
+    /// 失败的原因
+    #[prost(string, tag = "5")]
+    pub message: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportTopicsReply {
+    #[prost(int32, tag = "1")]
+    pub created: i32,
+    #[prost(int32, tag = "2")]
+    pub updated: i32,
+    #[prost(int32, tag = "3")]
+    pub failed: i32,
+    #[prost(message, repeated, tag = "4")]
+    pub results: ::prost::alloc::vec::Vec<ImportTopicResult>,
+}
+/// -- 置顶/取消置顶文章，新置顶的文章排在最后
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PinTopicRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// PIN_SCOPE_NONE 为取消置顶
+    #[prost(enumeration = "PinScope", tag = "2")]
+    pub scope: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct PinTopicReply {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(bool, tag = "2")]
+    pub ok: bool,
+}
+/// -- 调整置顶顺序，按照 ids 的顺序重新排列，未置顶的文章忽略
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReorderPinnedTopicsRequest {
+    #[prost(int64, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i64>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ReorderPinnedTopicsReply {
+    /// 调整了顺序的文章数
+    #[prost(int32, tag = "1")]
+    pub updated: i32,
+}
+/// -- 置顶的文章，包括已删除的文章，按置顶范围、分类和置顶顺序排列，不包含内容
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListPinnedTopicsRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPinnedTopicsReply {
+    #[prost(message, repeated, tag = "1")]
+    pub topics: ::prost::alloc::vec::Vec<Topic>,
+}
+/// -- 推荐/取消推荐文章
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FeatureTopicRequest {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(bool, tag = "2")]
+    pub featured: bool,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct FeatureTopicReply {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(bool, tag = "2")]
+    pub ok: bool,
+}
+/// -- 推荐文章，只包括已发布且未删除的文章，按发布时间倒序，不包含内容
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ListFeaturedTopicsRequest {
+    /// 返回的条数，默认5，最大20
+    #[prost(int32, optional, tag = "1")]
+    pub limit: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListFeaturedTopicsReply {
+    #[prost(message, repeated, tag = "1")]
+    pub topics: ::prost::alloc::vec::Vec<Topic>,
+}
+/// -- 批量操作，在同一个事务中完成
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkTopicRequest {
+    #[prost(int64, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i64>,
+    /// 执行删除的管理员
+    #[prost(int32, optional, tag = "2")]
+    pub admin_id: ::core::option::Option<i32>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MoveTopicsRequest {
+    #[prost(int64, repeated, tag = "1")]
+    pub ids: ::prost::alloc::vec::Vec<i64>,
+    /// 目标分类
+    #[prost(int32, tag = "2")]
+    pub category_id: i32,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkTopicResult {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// 文章是否处于要求的状态
+    #[prost(bool, tag = "2")]
+    pub ok: bool,
+    /// 是否有修改，已处于要求的状态时为 false
+    #[prost(bool, tag = "3")]
+    pub changed: bool,
+    /// 失败的原因
+    #[prost(string, tag = "4")]
+    pub message: ::prost::alloc::string::String,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkTopicReply {
+    /// 与请求中的ID一一对应，重复的ID只返回一次
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<BulkTopicResult>,
+    /// 修改的文章数
+    #[prost(int32, tag = "2")]
+    pub changed: i32,
+}
+/// 文章状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TopicStatus {
+    /// 草稿
+    Draft = 0,
+    /// 已发布
+    Published = 1,
+    /// 定时发布
+    Scheduled = 2,
+}
+impl TopicStatus {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TopicStatus::Draft => "TOPIC_STATUS_DRAFT",
+            TopicStatus::Published => "TOPIC_STATUS_PUBLISHED",
+            TopicStatus::Scheduled => "TOPIC_STATUS_SCHEDULED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TOPIC_STATUS_DRAFT" => Some(Self::Draft),
+            "TOPIC_STATUS_PUBLISHED" => Some(Self::Published),
+            "TOPIC_STATUS_SCHEDULED" => Some(Self::Scheduled),
+            _ => None,
+        }
+    }
+}
+/// 置顶范围
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PinScope {
+    /// 不置顶
+    None = 0,
+    /// 全站置顶
+    Global = 1,
+    /// 在所属分类中置顶
+    Category = 2,
+}
+impl PinScope {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            PinScope::None => "PIN_SCOPE_NONE",
+            PinScope::Global => "PIN_SCOPE_GLOBAL",
+            PinScope::Category => "PIN_SCOPE_CATEGORY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "PIN_SCOPE_NONE" => Some(Self::None),
+            "PIN_SCOPE_GLOBAL" => Some(Self::Global),
+            "PIN_SCOPE_CATEGORY" => Some(Self::Category),
+            _ => None,
+        }
+    }
+}
+/// 文章列表的排序方式，相同时按ID排序
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum TopicSort {
+    /// 按发布时间倒序
+    Dateline = 0,
+    /// 按点击量倒序
+    Hit = 1,
+    /// 按标题正序
+    Title = 2,
+    /// 按关键字相关度倒序
+    Relevance = 3,
+    /// 按字数倒序
+    WordCount = 4,
+}
+impl TopicSort {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            TopicSort::Dateline => "TOPIC_SORT_DATELINE",
+            TopicSort::Hit => "TOPIC_SORT_HIT",
+            TopicSort::Title => "TOPIC_SORT_TITLE",
+            TopicSort::Relevance => "TOPIC_SORT_RELEVANCE",
+            TopicSort::WordCount => "TOPIC_SORT_WORD_COUNT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "TOPIC_SORT_DATELINE" => Some(Self::Dateline),
+            "TOPIC_SORT_HIT" => Some(Self::Hit),
+            "TOPIC_SORT_TITLE" => Some(Self::Title),
+            "TOPIC_SORT_RELEVANCE" => Some(Self::Relevance),
+            "TOPIC_SORT_WORD_COUNT" => Some(Self::WordCount),
+            _ => None,
+        }
+    }
+}
+/// -- 导入文章，依次按ID、永久链接、旧的永久链接查找已有的文章，找到则更新，否则创建并保留原来的ID。
+/// 没有ID的文章在最后导入，结果仍按导入数据中的顺序返回
+/// 导入的数据使用 Topic，其中 content_html 会根据 content 重新生成
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ImportAction {
+    /// 已创建
+    Created = 0,
+    /// 已更新
+    Updated = 1,
+    /// 失败
+    Failed = 2,
+}
+impl ImportAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            ImportAction::Created => "IMPORT_ACTION_CREATED",
+            ImportAction::Updated => "IMPORT_ACTION_UPDATED",
+            ImportAction::Failed => "IMPORT_ACTION_FAILED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "IMPORT_ACTION_CREATED" => Some(Self::Created),
+            "IMPORT_ACTION_UPDATED" => Some(Self::Updated),
+            "IMPORT_ACTION_FAILED" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod topic_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    #[derive(Debug, Clone)]
+    pub struct TopicServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl TopicServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> TopicServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> TopicServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            TopicServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// 创建文章
+        pub async fn create_topic(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateTopicRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateTopicReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/CreateTopic",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "CreateTopic"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 修改文章
+        pub async fn edit_topic(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EditTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::EditTopicReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/EditTopic",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("pb.TopicService", "EditTopic"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 文章列表
+        pub async fn list_topic(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListTopicReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/ListTopic",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("pb.TopicService", "ListTopic"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 删除/恢复文章
+        pub async fn toggle_topic(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ToggleTopicRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ToggleTopicReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/ToggleTopic",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "ToggleTopic"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 获取文章详情
+        pub async fn get_topic(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetTopicReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pb.TopicService/GetTopic");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("pb.TopicService", "GetTopic"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 修订列表
+        pub async fn list_topic_revision(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListTopicRevisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTopicRevisionReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/ListTopicRevision",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "ListTopicRevision"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 获取修订
+        pub async fn get_topic_revision(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTopicRevisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTopicRevisionReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/GetTopicRevision",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "GetTopicRevision"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 对比修订
+        pub async fn diff_topic_revision(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DiffTopicRevisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DiffTopicRevisionReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/DiffTopicRevision",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "DiffTopicRevision"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 恢复修订
+        pub async fn restore_topic_revision(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestoreTopicRevisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RestoreTopicRevisionReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/RestoreTopicRevision",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "RestoreTopicRevision"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 相关文章
+        pub async fn get_related_topics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetRelatedTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetRelatedTopicsReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/GetRelatedTopics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "GetRelatedTopics"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 按年月归档
+        pub async fn list_archive(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListArchiveRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListArchiveReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/ListArchive",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "ListArchive"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 导出文章
+        pub async fn export_topics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExportTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::Topic>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/ExportTopics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "ExportTopics"));
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// 导入文章
+        pub async fn import_topics(
+            &mut self,
+            request: impl tonic::IntoStreamingRequest<Message = super::Topic>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportTopicsReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/ImportTopics",
+            );
+            let mut req = request.into_streaming_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "ImportTopics"));
+            self.inner.client_streaming(req, path, codec).await
+        }
+        /// 批量删除文章
+        pub async fn delete_topics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BulkTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTopicReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/DeleteTopics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "DeleteTopics"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 批量恢复文章
+        pub async fn restore_topics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BulkTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTopicReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/RestoreTopics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "RestoreTopics"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 批量移动文章到其它分类
+        pub async fn move_topics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MoveTopicsRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTopicReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/MoveTopics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "MoveTopics"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 彻底删除回收站中的文章，同时删除其标签、修订、旧链接和评论
+        pub async fn purge_topics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BulkTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTopicReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/PurgeTopics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "PurgeTopics"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 置顶/取消置顶文章
+        pub async fn pin_topic(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PinTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::PinTopicReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/pb.TopicService/PinTopic");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("pb.TopicService", "PinTopic"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 调整置顶顺序
+        pub async fn reorder_pinned_topics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReorderPinnedTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReorderPinnedTopicsReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/ReorderPinnedTopics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "ReorderPinnedTopics"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 置顶的文章
+        pub async fn list_pinned_topics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPinnedTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPinnedTopicsReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/ListPinnedTopics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "ListPinnedTopics"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 推荐/取消推荐文章
+        pub async fn feature_topic(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FeatureTopicRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FeatureTopicReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/FeatureTopic",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "FeatureTopic"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 推荐文章
+        pub async fn list_featured_topics(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListFeaturedTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFeaturedTopicsReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.TopicService/ListFeaturedTopics",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.TopicService", "ListFeaturedTopics"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod topic_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with TopicServiceServer.
+    #[async_trait]
+    pub trait TopicService: Send + Sync + 'static {
+        /// 创建文章
+        async fn create_topic(
+            &self,
+            request: tonic::Request<super::CreateTopicRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreateTopicReply>,
+            tonic::Status,
+        >;
+        /// 修改文章
+        async fn edit_topic(
+            &self,
+            request: tonic::Request<super::EditTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::EditTopicReply>, tonic::Status>;
+        /// 文章列表
+        async fn list_topic(
+            &self,
+            request: tonic::Request<super::ListTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListTopicReply>, tonic::Status>;
+        /// 删除/恢复文章
+        async fn toggle_topic(
+            &self,
+            request: tonic::Request<super::ToggleTopicRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ToggleTopicReply>,
+            tonic::Status,
+        >;
+        /// 获取文章详情
+        async fn get_topic(
+            &self,
+            request: tonic::Request<super::GetTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetTopicReply>, tonic::Status>;
+        /// 修订列表
+        async fn list_topic_revision(
+            &self,
+            request: tonic::Request<super::ListTopicRevisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListTopicRevisionReply>,
+            tonic::Status,
+        >;
+        /// 获取修订
+        async fn get_topic_revision(
+            &self,
+            request: tonic::Request<super::GetTopicRevisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTopicRevisionReply>,
+            tonic::Status,
+        >;
+        /// 对比修订
+        async fn diff_topic_revision(
+            &self,
+            request: tonic::Request<super::DiffTopicRevisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DiffTopicRevisionReply>,
+            tonic::Status,
+        >;
+        /// 恢复修订
+        async fn restore_topic_revision(
+            &self,
+            request: tonic::Request<super::RestoreTopicRevisionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RestoreTopicRevisionReply>,
+            tonic::Status,
+        >;
+        /// 相关文章
+        async fn get_related_topics(
+            &self,
+            request: tonic::Request<super::GetRelatedTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetRelatedTopicsReply>,
+            tonic::Status,
+        >;
+        /// 按年月归档
+        async fn list_archive(
+            &self,
+            request: tonic::Request<super::ListArchiveRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListArchiveReply>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ExportTopics method.
+        type ExportTopicsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::Topic, tonic::Status>,
+            >
+            + Send
+            + 'static;
+        /// 导出文章
+        async fn export_topics(
+            &self,
+            request: tonic::Request<super::ExportTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ExportTopicsStream>,
+            tonic::Status,
+        >;
+        /// 导入文章
+        async fn import_topics(
+            &self,
+            request: tonic::Request<tonic::Streaming<super::Topic>>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportTopicsReply>,
+            tonic::Status,
+        >;
+        /// 批量删除文章
+        async fn delete_topics(
+            &self,
+            request: tonic::Request<super::BulkTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTopicReply>, tonic::Status>;
+        /// 批量恢复文章
+        async fn restore_topics(
+            &self,
+            request: tonic::Request<super::BulkTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTopicReply>, tonic::Status>;
+        /// 批量移动文章到其它分类
+        async fn move_topics(
+            &self,
+            request: tonic::Request<super::MoveTopicsRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTopicReply>, tonic::Status>;
+        /// 彻底删除回收站中的文章，同时删除其标签、修订、旧链接和评论
+        async fn purge_topics(
+            &self,
+            request: tonic::Request<super::BulkTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTopicReply>, tonic::Status>;
+        /// 置顶/取消置顶文章
+        async fn pin_topic(
+            &self,
+            request: tonic::Request<super::PinTopicRequest>,
+        ) -> std::result::Result<tonic::Response<super::PinTopicReply>, tonic::Status>;
+        /// 调整置顶顺序
+        async fn reorder_pinned_topics(
+            &self,
+            request: tonic::Request<super::ReorderPinnedTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ReorderPinnedTopicsReply>,
+            tonic::Status,
+        >;
+        /// 置顶的文章
+        async fn list_pinned_topics(
+            &self,
+            request: tonic::Request<super::ListPinnedTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPinnedTopicsReply>,
+            tonic::Status,
+        >;
+        /// 推荐/取消推荐文章
+        async fn feature_topic(
+            &self,
+            request: tonic::Request<super::FeatureTopicRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FeatureTopicReply>,
+            tonic::Status,
+        >;
+        /// 推荐文章
+        async fn list_featured_topics(
+            &self,
+            request: tonic::Request<super::ListFeaturedTopicsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListFeaturedTopicsReply>,
+            tonic::Status,
+        >;
+    }
+    #[derive(Debug)]
+    pub struct TopicServiceServer<T: TopicService> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T: TopicService> TopicServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for TopicServiceServer<T>
+    where
+        T: TopicService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/pb.TopicService/CreateTopic" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateTopicSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::CreateTopicRequest>
+                    for CreateTopicSvc<T> {
+                        type Response = super::CreateTopicReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateTopicRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::create_topic(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateTopicSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/EditTopic" => {
+                    #[allow(non_camel_case_types)]
+                    struct EditTopicSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::EditTopicRequest>
+                    for EditTopicSvc<T> {
+                        type Response = super::EditTopicReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EditTopicRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::edit_topic(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EditTopicSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/ListTopic" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTopicSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::ListTopicRequest>
+                    for ListTopicSvc<T> {
+                        type Response = super::ListTopicReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTopicRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::list_topic(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListTopicSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/ToggleTopic" => {
+                    #[allow(non_camel_case_types)]
+                    struct ToggleTopicSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::ToggleTopicRequest>
+                    for ToggleTopicSvc<T> {
+                        type Response = super::ToggleTopicReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ToggleTopicRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::toggle_topic(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ToggleTopicSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/GetTopic" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTopicSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::GetTopicRequest>
+                    for GetTopicSvc<T> {
+                        type Response = super::GetTopicReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTopicRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::get_topic(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTopicSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/ListTopicRevision" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListTopicRevisionSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::ListTopicRevisionRequest>
+                    for ListTopicRevisionSvc<T> {
+                        type Response = super::ListTopicRevisionReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListTopicRevisionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::list_topic_revision(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListTopicRevisionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/GetTopicRevision" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTopicRevisionSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::GetTopicRevisionRequest>
+                    for GetTopicRevisionSvc<T> {
+                        type Response = super::GetTopicRevisionReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTopicRevisionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::get_topic_revision(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetTopicRevisionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/DiffTopicRevision" => {
+                    #[allow(non_camel_case_types)]
+                    struct DiffTopicRevisionSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::DiffTopicRevisionRequest>
+                    for DiffTopicRevisionSvc<T> {
+                        type Response = super::DiffTopicRevisionReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DiffTopicRevisionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::diff_topic_revision(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DiffTopicRevisionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/RestoreTopicRevision" => {
+                    #[allow(non_camel_case_types)]
+                    struct RestoreTopicRevisionSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::RestoreTopicRevisionRequest>
+                    for RestoreTopicRevisionSvc<T> {
+                        type Response = super::RestoreTopicRevisionReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RestoreTopicRevisionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::restore_topic_revision(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RestoreTopicRevisionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/GetRelatedTopics" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetRelatedTopicsSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::GetRelatedTopicsRequest>
+                    for GetRelatedTopicsSvc<T> {
+                        type Response = super::GetRelatedTopicsReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRelatedTopicsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::get_related_topics(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetRelatedTopicsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/ListArchive" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListArchiveSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::ListArchiveRequest>
+                    for ListArchiveSvc<T> {
+                        type Response = super::ListArchiveReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListArchiveRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::list_archive(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListArchiveSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/ExportTopics" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportTopicsSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::ServerStreamingService<super::ExportTopicsRequest>
+                    for ExportTopicsSvc<T> {
+                        type Response = super::Topic;
+                        type ResponseStream = T::ExportTopicsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExportTopicsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::export_topics(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExportTopicsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/ImportTopics" => {
+                    #[allow(non_camel_case_types)]
+                    struct ImportTopicsSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::ClientStreamingService<super::Topic>
+                    for ImportTopicsSvc<T> {
+                        type Response = super::ImportTopicsReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<tonic::Streaming<super::Topic>>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::import_topics(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ImportTopicsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.client_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/DeleteTopics" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateCategorySvc<T: CategoryService>(pub Arc<T>);
+                    struct DeleteTopicsSvc<T: TopicService>(pub Arc<T>);
                     impl<
-                        T: CategoryService,
-                    > tonic::server::UnaryService<super::CreateCategoryRequest>
-                    for CreateCategorySvc<T> {
-                        type Response = super::CreateCategoryReply;
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::BulkTopicRequest>
+                    for DeleteTopicsSvc<T> {
+                        type Response = super::BulkTopicReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CreateCategoryRequest>,
+                            request: tonic::Request<super::BulkTopicRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CategoryService>::create_category(&inner, request)
-                                    .await
+                                <T as TopicService>::delete_topics(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -515,7 +5806,7 @@ pub mod category_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CreateCategorySvc(inner);
+                        let method = DeleteTopicsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -531,25 +5822,25 @@ pub mod category_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.CategoryService/EditCategory" => {
+                "/pb.TopicService/RestoreTopics" => {
                     #[allow(non_camel_case_types)]
-                    struct EditCategorySvc<T: CategoryService>(pub Arc<T>);
+                    struct RestoreTopicsSvc<T: TopicService>(pub Arc<T>);
                     impl<
-                        T: CategoryService,
-                    > tonic::server::UnaryService<super::EditCategoryRequest>
-                    for EditCategorySvc<T> {
-                        type Response = super::EditCategoryReply;
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::BulkTopicRequest>
+                    for RestoreTopicsSvc<T> {
+                        type Response = super::BulkTopicReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::EditCategoryRequest>,
+                            request: tonic::Request<super::BulkTopicRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CategoryService>::edit_category(&inner, request).await
+                                <T as TopicService>::restore_topics(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -560,7 +5851,7 @@ pub mod category_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = EditCategorySvc(inner);
+                        let method = RestoreTopicsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -576,25 +5867,25 @@ pub mod category_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.CategoryService/ListCategory" => {
+                "/pb.TopicService/MoveTopics" => {
                     #[allow(non_camel_case_types)]
-                    struct ListCategorySvc<T: CategoryService>(pub Arc<T>);
+                    struct MoveTopicsSvc<T: TopicService>(pub Arc<T>);
                     impl<
-                        T: CategoryService,
-                    > tonic::server::UnaryService<super::ListCategoryRequest>
-                    for ListCategorySvc<T> {
-                        type Response = super::ListCategoryReply;
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::MoveTopicsRequest>
+                    for MoveTopicsSvc<T> {
+                        type Response = super::BulkTopicReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ListCategoryRequest>,
+                            request: tonic::Request<super::MoveTopicsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CategoryService>::list_category(&inner, request).await
+                                <T as TopicService>::move_topics(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -605,7 +5896,7 @@ pub mod category_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListCategorySvc(inner);
+                        let method = MoveTopicsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -621,25 +5912,115 @@ pub mod category_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.CategoryService/ToggleCategory" => {
+                "/pb.TopicService/PurgeTopics" => {
                     #[allow(non_camel_case_types)]
-                    struct ToggleCategorySvc<T: CategoryService>(pub Arc<T>);
+                    struct PurgeTopicsSvc<T: TopicService>(pub Arc<T>);
                     impl<
-                        T: CategoryService,
-                    > tonic::server::UnaryService<super::ToggleCategoryRequest>
-                    for ToggleCategorySvc<T> {
-                        type Response = super::ToggleCategoryReply;
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::BulkTopicRequest>
+                    for PurgeTopicsSvc<T> {
+                        type Response = super::BulkTopicReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ToggleCategoryRequest>,
+                            request: tonic::Request<super::BulkTopicRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CategoryService>::toggle_category(&inner, request)
+                                <T as TopicService>::purge_topics(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PurgeTopicsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/PinTopic" => {
+                    #[allow(non_camel_case_types)]
+                    struct PinTopicSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::PinTopicRequest>
+                    for PinTopicSvc<T> {
+                        type Response = super::PinTopicReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PinTopicRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::pin_topic(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PinTopicSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/ReorderPinnedTopics" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReorderPinnedTopicsSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::ReorderPinnedTopicsRequest>
+                    for ReorderPinnedTopicsSvc<T> {
+                        type Response = super::ReorderPinnedTopicsReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReorderPinnedTopicsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::reorder_pinned_topics(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -651,7 +6032,7 @@ pub mod category_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ToggleCategorySvc(inner);
+                        let method = ReorderPinnedTopicsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -667,25 +6048,25 @@ pub mod category_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.CategoryService/CategoryExists" => {
+                "/pb.TopicService/ListPinnedTopics" => {
                     #[allow(non_camel_case_types)]
-                    struct CategoryExistsSvc<T: CategoryService>(pub Arc<T>);
+                    struct ListPinnedTopicsSvc<T: TopicService>(pub Arc<T>);
                     impl<
-                        T: CategoryService,
-                    > tonic::server::UnaryService<super::CategoryExistsRequest>
-                    for CategoryExistsSvc<T> {
-                        type Response = super::CategoryExistsReply;
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::ListPinnedTopicsRequest>
+                    for ListPinnedTopicsSvc<T> {
+                        type Response = super::ListPinnedTopicsReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CategoryExistsRequest>,
+                            request: tonic::Request<super::ListPinnedTopicsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CategoryService>::category_exists(&inner, request)
+                                <T as TopicService>::list_pinned_topics(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -697,7 +6078,7 @@ pub mod category_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CategoryExistsSvc(inner);
+                        let method = ListPinnedTopicsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -713,25 +6094,25 @@ pub mod category_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.CategoryService/GetCategory" => {
+                "/pb.TopicService/FeatureTopic" => {
                     #[allow(non_camel_case_types)]
-                    struct GetCategorySvc<T: CategoryService>(pub Arc<T>);
+                    struct FeatureTopicSvc<T: TopicService>(pub Arc<T>);
                     impl<
-                        T: CategoryService,
-                    > tonic::server::UnaryService<super::GetCategoryRequest>
-                    for GetCategorySvc<T> {
-                        type Response = super::GetCategoryReply;
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::FeatureTopicRequest>
+                    for FeatureTopicSvc<T> {
+                        type Response = super::FeatureTopicReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetCategoryRequest>,
+                            request: tonic::Request<super::FeatureTopicRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CategoryService>::get_category(&inner, request).await
+                                <T as TopicService>::feature_topic(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -742,7 +6123,53 @@ pub mod category_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetCategorySvc(inner);
+                        let method = FeatureTopicSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.TopicService/ListFeaturedTopics" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListFeaturedTopicsSvc<T: TopicService>(pub Arc<T>);
+                    impl<
+                        T: TopicService,
+                    > tonic::server::UnaryService<super::ListFeaturedTopicsRequest>
+                    for ListFeaturedTopicsSvc<T> {
+                        type Response = super::ListFeaturedTopicsReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ListFeaturedTopicsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TopicService>::list_featured_topics(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListFeaturedTopicsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -776,7 +6203,7 @@ pub mod category_service_server {
             }
         }
     }
-    impl<T: CategoryService> Clone for CategoryServiceServer<T> {
+    impl<T: TopicService> Clone for TopicServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -788,164 +6215,170 @@ pub mod category_service_server {
             }
         }
     }
-    impl<T: CategoryService> tonic::server::NamedService for CategoryServiceServer<T> {
-        const NAME: &'static str = "pb.CategoryService";
+    impl<T: TopicService> tonic::server::NamedService for TopicServiceServer<T> {
+        const NAME: &'static str = "pb.TopicService";
     }
 }
+/// 系列，由按顺序排列的多篇文章组成
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Topic {
-    #[prost(int64, tag = "1")]
-    pub id: i64,
+pub struct Series {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
     #[prost(string, tag = "2")]
     pub title: ::prost::alloc::string::String,
-    #[prost(int32, tag = "3")]
-    pub category_id: i32,
-    #[prost(string, tag = "4")]
-    pub summary: ::prost::alloc::string::String,
-    #[prost(string, tag = "5")]
-    pub content: ::prost::alloc::string::String,
-    #[prost(int32, tag = "6")]
-    pub hit: i32,
-    #[prost(bool, tag = "7")]
-    pub is_del: bool,
-    #[prost(message, optional, tag = "8")]
+    #[prost(string, tag = "3")]
+    pub description: ::prost::alloc::string::String,
+    /// 系列中的文章数，包括未发布和已删除的文章
+    #[prost(int32, tag = "4")]
+    pub topic_count: i32,
+    #[prost(message, optional, tag = "5")]
     pub dateline: ::core::option::Option<::prost_types::Timestamp>,
 }
+/// -- 创建系列
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CreateSeriesRequest {
+    #[prost(string, tag = "1")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub description: ::prost::alloc::string::String,
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct DatelineRange {
-    #[prost(message, optional, tag = "1")]
-    pub start: ::core::option::Option<::prost_types::Timestamp>,
-    #[prost(message, optional, tag = "2")]
-    pub end: ::core::option::Option<::prost_types::Timestamp>,
+pub struct CreateSeriesReply {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
 }
-/// -- 创建文章
+/// -- 修改系列
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CreateTopicRequest {
-    #[prost(string, tag = "1")]
+pub struct EditSeriesRequest {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(string, tag = "2")]
     pub title: ::prost::alloc::string::String,
-    #[prost(int32, tag = "2")]
-    pub category_id: i32,
     #[prost(string, tag = "3")]
-    pub content: ::prost::alloc::string::String,
-    /// 如果没有提供摘要，则自动从内容中截取
-    #[prost(string, optional, tag = "4")]
-    pub summary: ::core::option::Option<::prost::alloc::string::String>,
+    pub description: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct CreateTopicReply {
-    #[prost(int64, tag = "1")]
-    pub id: i64,
+pub struct EditSeriesReply {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(bool, tag = "2")]
+    pub ok: bool,
 }
-/// -- 修改文章
+/// -- 删除系列，系列中的文章不受影响
 #[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EditTopicRequest {
-    #[prost(int64, tag = "1")]
-    pub id: i64,
-    #[prost(string, tag = "2")]
-    pub title: ::prost::alloc::string::String,
-    #[prost(int32, tag = "3")]
-    pub category_id: i32,
-    /// 如果没有提供摘要，则自动从内容中截取
-    #[prost(string, optional, tag = "4")]
-    pub summary: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(string, tag = "5")]
-    pub content: ::prost::alloc::string::String,
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DeleteSeriesRequest {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct EditTopicReply {
-    #[prost(int64, tag = "1")]
-    pub id: i64,
+pub struct DeleteSeriesReply {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
     #[prost(bool, tag = "2")]
     pub ok: bool,
 }
-/// -- 文章列表
+/// -- 设置系列中的文章及其顺序
+/// 按照 topic_ids 的顺序排列，未列出的文章移出系列，已属于其它系列的文章移到该系列
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListTopicRequest {
-    /// 页码
-    #[prost(int32, optional, tag = "1")]
-    pub page: ::core::option::Option<i32>,
-    /// 分类
-    #[prost(int32, optional, tag = "2")]
-    pub category_id: ::core::option::Option<i32>,
-    /// 关键字
-    #[prost(string, optional, tag = "3")]
-    pub keyword: ::core::option::Option<::prost::alloc::string::String>,
-    /// 是否删除
-    #[prost(bool, optional, tag = "4")]
-    pub is_del: ::core::option::Option<bool>,
-    /// 时间区间
-    #[prost(message, optional, tag = "5")]
-    pub dateline_range: ::core::option::Option<DatelineRange>,
+pub struct SetSeriesTopicsRequest {
+    #[prost(int32, tag = "1")]
+    pub series_id: i32,
+    #[prost(int64, repeated, tag = "2")]
+    pub topic_ids: ::prost::alloc::vec::Vec<i64>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListTopicReply {
-    /// 当前页码
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetSeriesTopicsReply {
     #[prost(int32, tag = "1")]
-    pub page: i32,
-    /// 每页条数
+    pub series_id: i32,
     #[prost(int32, tag = "2")]
-    pub page_size: i32,
-    /// 总页数
-    #[prost(int64, tag = "3")]
-    pub page_totoal: i64,
-    /// 总记录数
-    #[prost(int64, tag = "4")]
-    pub record_total: i64,
-    /// 文章列表
-    #[prost(message, repeated, tag = "5")]
-    pub topics: ::prost::alloc::vec::Vec<Topic>,
+    pub topic_count: i32,
 }
-/// -- 删除/恢复文章
+/// -- 系列列表，按创建时间倒序
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct ToggleTopicRequest {
-    #[prost(int64, tag = "1")]
-    pub id: i64,
+pub struct ListSeriesRequest {}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListSeriesReply {
+    #[prost(message, repeated, tag = "1")]
+    pub series: ::prost::alloc::vec::Vec<Series>,
 }
+/// -- 获取系列及其中的文章，文章按顺序排列，不包含内容
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct ToggleTopicReply {
-    #[prost(int64, tag = "1")]
-    pub id: i64,
-    #[prost(bool, tag = "2")]
-    pub is_del: bool,
+pub struct GetSeriesRequest {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    /// 只包括（未）删除的文章
+    #[prost(bool, optional, tag = "2")]
+    pub is_del: ::core::option::Option<bool>,
+    /// 只包括指定状态的文章
+    #[prost(enumeration = "TopicStatus", optional, tag = "3")]
+    pub status: ::core::option::Option<i32>,
+    /// 只包括所属分类（未）删除的文章
+    #[prost(bool, optional, tag = "4")]
+    pub category_is_del: ::core::option::Option<bool>,
 }
-/// -- 获取文章详情
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSeriesReply {
+    #[prost(message, optional, tag = "1")]
+    pub series: ::core::option::Option<Series>,
+    #[prost(message, repeated, tag = "2")]
+    pub topics: ::prost::alloc::vec::Vec<Topic>,
+}
+/// -- 文章所属的系列，以及文章在系列中的位置
+/// is_del/status 用于筛选系列中的文章，文章本身不满足条件时 part 为0
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct GetTopicRequest {
+pub struct GetTopicSeriesRequest {
     #[prost(int64, tag = "1")]
-    pub id: i64,
+    pub topic_id: i64,
     #[prost(bool, optional, tag = "2")]
     pub is_del: ::core::option::Option<bool>,
-    /// 是否同时增加点击量
-    #[prost(bool, optional, tag = "3")]
-    pub inc_hit: ::core::option::Option<bool>,
+    #[prost(enumeration = "TopicStatus", optional, tag = "3")]
+    pub status: ::core::option::Option<i32>,
+    #[prost(bool, optional, tag = "4")]
+    pub category_is_del: ::core::option::Option<bool>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetTopicReply {
+pub struct GetTopicSeriesReply {
+    /// 文章不属于任何系列时为空
     #[prost(message, optional, tag = "1")]
-    pub topic: ::core::option::Option<Topic>,
+    pub series: ::core::option::Option<Series>,
+    /// 文章是第几篇，从1开始
+    #[prost(int32, tag = "2")]
+    pub part: i32,
+    /// 满足条件的文章总数
+    #[prost(int32, tag = "3")]
+    pub total: i32,
+    /// 上一篇，不包含内容
+    #[prost(message, optional, tag = "4")]
+    pub prev: ::core::option::Option<Topic>,
+    /// 下一篇，不包含内容
+    #[prost(message, optional, tag = "5")]
+    pub next: ::core::option::Option<Topic>,
 }
 /// Generated client implementations.
-pub mod topic_service_client {
+pub mod series_service_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
     #[derive(Debug, Clone)]
-    pub struct TopicServiceClient<T> {
+    pub struct SeriesServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
-    impl TopicServiceClient<tonic::transport::Channel> {
+    impl SeriesServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
         where
@@ -956,7 +6389,7 @@ pub mod topic_service_client {
             Ok(Self::new(conn))
         }
     }
-    impl<T> TopicServiceClient<T>
+    impl<T> SeriesServiceClient<T>
     where
         T: tonic::client::GrpcService<tonic::body::BoxBody>,
         T::Error: Into<StdError>,
@@ -974,7 +6407,7 @@ pub mod topic_service_client {
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> TopicServiceClient<InterceptedService<T, F>>
+        ) -> SeriesServiceClient<InterceptedService<T, F>>
         where
             F: tonic::service::Interceptor,
             T::ResponseBody: Default,
@@ -988,7 +6421,7 @@ pub mod topic_service_client {
                 http::Request<tonic::body::BoxBody>,
             >>::Error: Into<StdError> + Send + Sync,
         {
-            TopicServiceClient::new(InterceptedService::new(inner, interceptor))
+            SeriesServiceClient::new(InterceptedService::new(inner, interceptor))
         }
         /// Compress requests with the given encoding.
         ///
@@ -1021,12 +6454,12 @@ pub mod topic_service_client {
             self.inner = self.inner.max_encoding_message_size(limit);
             self
         }
-        /// 创建文章
-        pub async fn create_topic(
+        /// 创建系列
+        pub async fn create_series(
             &mut self,
-            request: impl tonic::IntoRequest<super::CreateTopicRequest>,
+            request: impl tonic::IntoRequest<super::CreateSeriesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CreateTopicReply>,
+            tonic::Response<super::CreateSeriesReply>,
             tonic::Status,
         > {
             self.inner
@@ -1040,18 +6473,21 @@ pub mod topic_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.TopicService/CreateTopic",
+                "/pb.SeriesService/CreateSeries",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("pb.TopicService", "CreateTopic"));
+                .insert(GrpcMethod::new("pb.SeriesService", "CreateSeries"));
             self.inner.unary(req, path, codec).await
         }
-        /// 修改文章
-        pub async fn edit_topic(
+        /// 修改系列
+        pub async fn edit_series(
             &mut self,
-            request: impl tonic::IntoRequest<super::EditTopicRequest>,
-        ) -> std::result::Result<tonic::Response<super::EditTopicReply>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::EditSeriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EditSeriesReply>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1063,17 +6499,21 @@ pub mod topic_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.TopicService/EditTopic",
+                "/pb.SeriesService/EditSeries",
             );
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("pb.TopicService", "EditTopic"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.SeriesService", "EditSeries"));
             self.inner.unary(req, path, codec).await
         }
-        /// 文章列表
-        pub async fn list_topic(
+        /// 删除系列
+        pub async fn delete_series(
             &mut self,
-            request: impl tonic::IntoRequest<super::ListTopicRequest>,
-        ) -> std::result::Result<tonic::Response<super::ListTopicReply>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::DeleteSeriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteSeriesReply>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1085,18 +6525,19 @@ pub mod topic_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.TopicService/ListTopic",
+                "/pb.SeriesService/DeleteSeries",
             );
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("pb.TopicService", "ListTopic"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.SeriesService", "DeleteSeries"));
             self.inner.unary(req, path, codec).await
         }
-        /// 删除/恢复文章
-        pub async fn toggle_topic(
+        /// 设置系列中的文章及其顺序
+        pub async fn set_series_topics(
             &mut self,
-            request: impl tonic::IntoRequest<super::ToggleTopicRequest>,
+            request: impl tonic::IntoRequest<super::SetSeriesTopicsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ToggleTopicReply>,
+            tonic::Response<super::SetSeriesTopicsReply>,
             tonic::Status,
         > {
             self.inner
@@ -1110,18 +6551,21 @@ pub mod topic_service_client {
                 })?;
             let codec = tonic::codec::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/pb.TopicService/ToggleTopic",
+                "/pb.SeriesService/SetSeriesTopics",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("pb.TopicService", "ToggleTopic"));
+                .insert(GrpcMethod::new("pb.SeriesService", "SetSeriesTopics"));
             self.inner.unary(req, path, codec).await
         }
-        /// 获取文章详情
-        pub async fn get_topic(
+        /// 系列列表
+        pub async fn list_series(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetTopicRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetTopicReply>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::ListSeriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListSeriesReply>,
+            tonic::Status,
+        > {
             self.inner
                 .ready()
                 .await
@@ -1132,61 +6576,129 @@ pub mod topic_service_client {
                     )
                 })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/pb.TopicService/GetTopic");
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.SeriesService/ListSeries",
+            );
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("pb.TopicService", "GetTopic"));
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.SeriesService", "ListSeries"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 获取系列
+        pub async fn get_series(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetSeriesReply>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.SeriesService/GetSeries",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.SeriesService", "GetSeries"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// 文章所属的系列
+        pub async fn get_topic_series(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTopicSeriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTopicSeriesReply>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/pb.SeriesService/GetTopicSeries",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("pb.SeriesService", "GetTopicSeries"));
             self.inner.unary(req, path, codec).await
         }
     }
 }
 /// Generated server implementations.
-pub mod topic_service_server {
+pub mod series_service_server {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with TopicServiceServer.
+    /// Generated trait containing gRPC methods that should be implemented for use with SeriesServiceServer.
     #[async_trait]
-    pub trait TopicService: Send + Sync + 'static {
-        /// 创建文章
-        async fn create_topic(
+    pub trait SeriesService: Send + Sync + 'static {
+        /// 创建系列
+        async fn create_series(
             &self,
-            request: tonic::Request<super::CreateTopicRequest>,
+            request: tonic::Request<super::CreateSeriesRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::CreateTopicReply>,
+            tonic::Response<super::CreateSeriesReply>,
             tonic::Status,
         >;
-        /// 修改文章
-        async fn edit_topic(
+        /// 修改系列
+        async fn edit_series(
             &self,
-            request: tonic::Request<super::EditTopicRequest>,
-        ) -> std::result::Result<tonic::Response<super::EditTopicReply>, tonic::Status>;
-        /// 文章列表
-        async fn list_topic(
+            request: tonic::Request<super::EditSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::EditSeriesReply>, tonic::Status>;
+        /// 删除系列
+        async fn delete_series(
             &self,
-            request: tonic::Request<super::ListTopicRequest>,
-        ) -> std::result::Result<tonic::Response<super::ListTopicReply>, tonic::Status>;
-        /// 删除/恢复文章
-        async fn toggle_topic(
+            request: tonic::Request<super::DeleteSeriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeleteSeriesReply>,
+            tonic::Status,
+        >;
+        /// 设置系列中的文章及其顺序
+        async fn set_series_topics(
             &self,
-            request: tonic::Request<super::ToggleTopicRequest>,
+            request: tonic::Request<super::SetSeriesTopicsRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ToggleTopicReply>,
+            tonic::Response<super::SetSeriesTopicsReply>,
             tonic::Status,
         >;
-        /// 获取文章详情
-        async fn get_topic(
+        /// 系列列表
+        async fn list_series(
             &self,
-            request: tonic::Request<super::GetTopicRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetTopicReply>, tonic::Status>;
+            request: tonic::Request<super::ListSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListSeriesReply>, tonic::Status>;
+        /// 获取系列
+        async fn get_series(
+            &self,
+            request: tonic::Request<super::GetSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::GetSeriesReply>, tonic::Status>;
+        /// 文章所属的系列
+        async fn get_topic_series(
+            &self,
+            request: tonic::Request<super::GetTopicSeriesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTopicSeriesReply>,
+            tonic::Status,
+        >;
     }
     #[derive(Debug)]
-    pub struct TopicServiceServer<T: TopicService> {
+    pub struct SeriesServiceServer<T: SeriesService> {
         inner: Arc<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
         max_decoding_message_size: Option<usize>,
         max_encoding_message_size: Option<usize>,
     }
-    impl<T: TopicService> TopicServiceServer<T> {
+    impl<T: SeriesService> SeriesServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -1237,9 +6749,9 @@ pub mod topic_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for TopicServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for SeriesServiceServer<T>
     where
-        T: TopicService,
+        T: SeriesService,
         B: Body + Send + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -1254,25 +6766,115 @@ pub mod topic_service_server {
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             match req.uri().path() {
-                "/pb.TopicService/CreateTopic" => {
+                "/pb.SeriesService/CreateSeries" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreateSeriesSvc<T: SeriesService>(pub Arc<T>);
+                    impl<
+                        T: SeriesService,
+                    > tonic::server::UnaryService<super::CreateSeriesRequest>
+                    for CreateSeriesSvc<T> {
+                        type Response = super::CreateSeriesReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateSeriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SeriesService>::create_series(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreateSeriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.SeriesService/EditSeries" => {
+                    #[allow(non_camel_case_types)]
+                    struct EditSeriesSvc<T: SeriesService>(pub Arc<T>);
+                    impl<
+                        T: SeriesService,
+                    > tonic::server::UnaryService<super::EditSeriesRequest>
+                    for EditSeriesSvc<T> {
+                        type Response = super::EditSeriesReply;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::EditSeriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SeriesService>::edit_series(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = EditSeriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/pb.SeriesService/DeleteSeries" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateTopicSvc<T: TopicService>(pub Arc<T>);
+                    struct DeleteSeriesSvc<T: SeriesService>(pub Arc<T>);
                     impl<
-                        T: TopicService,
-                    > tonic::server::UnaryService<super::CreateTopicRequest>
-                    for CreateTopicSvc<T> {
-                        type Response = super::CreateTopicReply;
+                        T: SeriesService,
+                    > tonic::server::UnaryService<super::DeleteSeriesRequest>
+                    for DeleteSeriesSvc<T> {
+                        type Response = super::DeleteSeriesReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::CreateTopicRequest>,
+                            request: tonic::Request<super::DeleteSeriesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TopicService>::create_topic(&inner, request).await
+                                <T as SeriesService>::delete_series(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1283,7 +6885,7 @@ pub mod topic_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = CreateTopicSvc(inner);
+                        let method = DeleteSeriesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1299,25 +6901,26 @@ pub mod topic_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.TopicService/EditTopic" => {
+                "/pb.SeriesService/SetSeriesTopics" => {
                     #[allow(non_camel_case_types)]
-                    struct EditTopicSvc<T: TopicService>(pub Arc<T>);
+                    struct SetSeriesTopicsSvc<T: SeriesService>(pub Arc<T>);
                     impl<
-                        T: TopicService,
-                    > tonic::server::UnaryService<super::EditTopicRequest>
-                    for EditTopicSvc<T> {
-                        type Response = super::EditTopicReply;
+                        T: SeriesService,
+                    > tonic::server::UnaryService<super::SetSeriesTopicsRequest>
+                    for SetSeriesTopicsSvc<T> {
+                        type Response = super::SetSeriesTopicsReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::EditTopicRequest>,
+                            request: tonic::Request<super::SetSeriesTopicsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TopicService>::edit_topic(&inner, request).await
+                                <T as SeriesService>::set_series_topics(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -1328,7 +6931,7 @@ pub mod topic_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = EditTopicSvc(inner);
+                        let method = SetSeriesTopicsSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1344,25 +6947,25 @@ pub mod topic_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.TopicService/ListTopic" => {
+                "/pb.SeriesService/ListSeries" => {
                     #[allow(non_camel_case_types)]
-                    struct ListTopicSvc<T: TopicService>(pub Arc<T>);
+                    struct ListSeriesSvc<T: SeriesService>(pub Arc<T>);
                     impl<
-                        T: TopicService,
-                    > tonic::server::UnaryService<super::ListTopicRequest>
-                    for ListTopicSvc<T> {
-                        type Response = super::ListTopicReply;
+                        T: SeriesService,
+                    > tonic::server::UnaryService<super::ListSeriesRequest>
+                    for ListSeriesSvc<T> {
+                        type Response = super::ListSeriesReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ListTopicRequest>,
+                            request: tonic::Request<super::ListSeriesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TopicService>::list_topic(&inner, request).await
+                                <T as SeriesService>::list_series(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1373,7 +6976,7 @@ pub mod topic_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ListTopicSvc(inner);
+                        let method = ListSeriesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1389,25 +6992,25 @@ pub mod topic_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.TopicService/ToggleTopic" => {
+                "/pb.SeriesService/GetSeries" => {
                     #[allow(non_camel_case_types)]
-                    struct ToggleTopicSvc<T: TopicService>(pub Arc<T>);
+                    struct GetSeriesSvc<T: SeriesService>(pub Arc<T>);
                     impl<
-                        T: TopicService,
-                    > tonic::server::UnaryService<super::ToggleTopicRequest>
-                    for ToggleTopicSvc<T> {
-                        type Response = super::ToggleTopicReply;
+                        T: SeriesService,
+                    > tonic::server::UnaryService<super::GetSeriesRequest>
+                    for GetSeriesSvc<T> {
+                        type Response = super::GetSeriesReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ToggleTopicRequest>,
+                            request: tonic::Request<super::GetSeriesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TopicService>::toggle_topic(&inner, request).await
+                                <T as SeriesService>::get_series(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1418,7 +7021,7 @@ pub mod topic_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ToggleTopicSvc(inner);
+                        let method = GetSeriesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1434,25 +7037,26 @@ pub mod topic_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.TopicService/GetTopic" => {
+                "/pb.SeriesService/GetTopicSeries" => {
                     #[allow(non_camel_case_types)]
-                    struct GetTopicSvc<T: TopicService>(pub Arc<T>);
+                    struct GetTopicSeriesSvc<T: SeriesService>(pub Arc<T>);
                     impl<
-                        T: TopicService,
-                    > tonic::server::UnaryService<super::GetTopicRequest>
-                    for GetTopicSvc<T> {
-                        type Response = super::GetTopicReply;
+                        T: SeriesService,
+                    > tonic::server::UnaryService<super::GetTopicSeriesRequest>
+                    for GetTopicSeriesSvc<T> {
+                        type Response = super::GetTopicSeriesReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetTopicRequest>,
+                            request: tonic::Request<super::GetTopicSeriesRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as TopicService>::get_topic(&inner, request).await
+                                <T as SeriesService>::get_topic_series(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -1463,7 +7067,7 @@ pub mod topic_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetTopicSvc(inner);
+                        let method = GetTopicSeriesSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1497,7 +7101,7 @@ pub mod topic_service_server {
             }
         }
     }
-    impl<T: TopicService> Clone for TopicServiceServer<T> {
+    impl<T: SeriesService> Clone for SeriesServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -1509,168 +7113,104 @@ pub mod topic_service_server {
             }
         }
     }
-    impl<T: TopicService> tonic::server::NamedService for TopicServiceServer<T> {
-        const NAME: &'static str = "pb.TopicService";
+    impl<T: SeriesService> tonic::server::NamedService for SeriesServiceServer<T> {
+        const NAME: &'static str = "pb.SeriesService";
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct Admin {
+pub struct Tag {
     #[prost(int32, tag = "1")]
     pub id: i32,
     #[prost(string, tag = "2")]
-    pub email: ::prost::alloc::string::String,
-    #[prost(string, optional, tag = "3")]
-    pub password: ::core::option::Option<::prost::alloc::string::String>,
-    #[prost(bool, tag = "4")]
-    pub is_del: bool,
+    pub name: ::prost::alloc::string::String,
+    /// 使用该标签的文章数
+    #[prost(int64, tag = "3")]
+    pub topic_count: i64,
 }
-/// -- 添加管理员
+/// -- 创建标签
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct CreateAdminRequest {
+pub struct CreateTagRequest {
     #[prost(string, tag = "1")]
-    pub email: ::prost::alloc::string::String,
-    #[prost(string, tag = "2")]
-    pub password: ::prost::alloc::string::String,
+    pub name: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct CreateAdminReply {
+pub struct CreateTagReply {
     #[prost(int32, tag = "1")]
     pub id: i32,
 }
-/// -- 管理员列表
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListAdminRequest {
-    /// 根据EMAIL进行查找
-    #[prost(string, optional, tag = "1")]
-    pub email: ::core::option::Option<::prost::alloc::string::String>,
-    /// 是否删除
-    #[prost(bool, optional, tag = "2")]
-    pub is_del: ::core::option::Option<bool>,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, PartialEq, ::prost::Message)]
-pub struct ListAdminReply {
-    #[prost(message, repeated, tag = "1")]
-    pub admins: ::prost::alloc::vec::Vec<Admin>,
-}
-/// -- 修改管理员
+/// -- 重命名标签
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct EditAdminRequest {
+pub struct RenameTagRequest {
     #[prost(int32, tag = "1")]
     pub id: i32,
     #[prost(string, tag = "2")]
-    pub email: ::prost::alloc::string::String,
-    /// 现用密码
-    #[prost(string, tag = "3")]
-    pub password: ::prost::alloc::string::String,
-    /// 如果有密码，则修改密码
-    #[prost(string, optional, tag = "4")]
-    pub new_password: ::core::option::Option<::prost::alloc::string::String>,
+    pub name: ::prost::alloc::string::String,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct EditAdminReply {
+pub struct RenameTagReply {
     #[prost(int32, tag = "1")]
     pub id: i32,
     #[prost(bool, tag = "2")]
     pub ok: bool,
 }
-/// -- 删除/恢复管理员
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct ToggleAdminRequest {
-    #[prost(int32, tag = "1")]
-    pub id: i32,
-}
-#[allow(clippy::derive_partial_eq_without_eq)]
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct ToggleAdminReply {
-    #[prost(int32, tag = "1")]
-    pub id: i32,
-    #[prost(bool, tag = "2")]
-    pub is_del: bool,
-}
-/// -- 管理员是否存在
+/// -- 合并标签，源标签下的文章全部转到目标标签，然后删除源标签
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct AdminExistsRequest {
-    #[prost(oneof = "admin_exists_request::Condition", tags = "1, 2")]
-    pub condition: ::core::option::Option<admin_exists_request::Condition>,
-}
-/// Nested message and enum types in `AdminExistsRequest`.
-pub mod admin_exists_request {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Condition {
-        #[prost(string, tag = "1")]
-        Email(::prost::alloc::string::String),
-        #[prost(int32, tag = "2")]
-        Id(i32),
-    }
+pub struct MergeTagRequest {
+    /// 源标签
+    #[prost(int32, repeated, tag = "1")]
+    pub from_ids: ::prost::alloc::vec::Vec<i32>,
+    /// 目标标签
+    #[prost(int32, tag = "2")]
+    pub to_id: i32,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct AdminExistsReply {
-    #[prost(bool, tag = "1")]
-    pub exists: bool,
+pub struct MergeTagReply {
+    #[prost(int32, tag = "1")]
+    pub to_id: i32,
+    /// 合并后目标标签的文章数
+    #[prost(int64, tag = "2")]
+    pub topic_count: i64,
 }
-/// -- 获取管理员
+/// -- 标签列表，按文章数倒序
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetAdminRequest {
-    #[prost(oneof = "get_admin_request::Condition", tags = "1, 2")]
-    pub condition: ::core::option::Option<get_admin_request::Condition>,
-}
-/// Nested message and enum types in `GetAdminRequest`.
-pub mod get_admin_request {
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Message)]
-    pub struct ByAuth {
-        #[prost(string, tag = "1")]
-        pub email: ::prost::alloc::string::String,
-        #[prost(string, tag = "2")]
-        pub password: ::prost::alloc::string::String,
-    }
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, Copy, PartialEq, ::prost::Message)]
-    pub struct ById {
-        #[prost(int32, tag = "1")]
-        pub id: i32,
-        #[prost(bool, optional, tag = "2")]
-        pub is_del: ::core::option::Option<bool>,
-    }
-    #[allow(clippy::derive_partial_eq_without_eq)]
-    #[derive(Clone, PartialEq, ::prost::Oneof)]
-    pub enum Condition {
-        /// 通过ID直接获取
-        #[prost(message, tag = "1")]
-        ById(ById),
-        /// 通过登录信息获取
-        #[prost(message, tag = "2")]
-        ByAuth(ByAuth),
-    }
+pub struct ListTagRequest {
+    /// 根据标签名称查找
+    #[prost(string, optional, tag = "1")]
+    pub name: ::core::option::Option<::prost::alloc::string::String>,
+    /// 只统计（未）删除的文章
+    #[prost(bool, optional, tag = "2")]
+    pub is_del: ::core::option::Option<bool>,
+    /// 只统计指定状态的文章
+    #[prost(enumeration = "TopicStatus", optional, tag = "3")]
+    pub status: ::core::option::Option<i32>,
+    /// 只统计所属分类（未）删除的文章
+    #[prost(bool, optional, tag = "4")]
+    pub category_is_del: ::core::option::Option<bool>,
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct GetAdminReply {
-    #[prost(message, optional, tag = "1")]
-    pub admin: ::core::option::Option<Admin>,
+pub struct ListTagReply {
+    #[prost(message, repeated, tag = "1")]
+    pub tags: ::prost::alloc::vec::Vec<Tag>,
 }
 /// Generated client implementations.
-pub mod admin_service_client {
+pub mod tag_service_client {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
     use tonic::codegen::http::Uri;
     #[derive(Debug, Clone)]
-    pub struct AdminServiceClient<T> {
+    pub struct TagServiceClient<T> {
         inner: tonic::client::Grpc<T>,
     }
-    impl AdminServiceClient<tonic::transport::Channel> {
+    impl TagServiceClient<tonic::transport::Channel> {
         /// Attempt to create a new client by connecting to a given endpoint.
         pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
         where
@@ -1681,7 +7221,7 @@ pub mod admin_service_client {
             Ok(Self::new(conn))
         }
     }
-    impl<T> AdminServiceClient<T>
+    impl<T> TagServiceClient<T>
     where
         T: tonic::client::GrpcService<tonic::body::BoxBody>,
         T::Error: Into<StdError>,
@@ -1699,7 +7239,7 @@ pub mod admin_service_client {
         pub fn with_interceptor<F>(
             inner: T,
             interceptor: F,
-        ) -> AdminServiceClient<InterceptedService<T, F>>
+        ) -> TagServiceClient<InterceptedService<T, F>>
         where
             F: tonic::service::Interceptor,
             T::ResponseBody: Default,
@@ -1713,92 +7253,44 @@ pub mod admin_service_client {
                 http::Request<tonic::body::BoxBody>,
             >>::Error: Into<StdError> + Send + Sync,
         {
-            AdminServiceClient::new(InterceptedService::new(inner, interceptor))
+            TagServiceClient::new(InterceptedService::new(inner, interceptor))
         }
         /// Compress requests with the given encoding.
         ///
         /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
-        }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
-        }
-        /// 添加管理员
-        pub async fn create_admin(
-            &mut self,
-            request: impl tonic::IntoRequest<super::CreateAdminRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateAdminReply>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/pb.AdminService/CreateAdmin",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("pb.AdminService", "CreateAdmin"));
-            self.inner.unary(req, path, codec).await
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
         }
-        /// 管理员列表
-        pub async fn list_admin(
-            &mut self,
-            request: impl tonic::IntoRequest<super::ListAdminRequest>,
-        ) -> std::result::Result<tonic::Response<super::ListAdminReply>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::new(
-                        tonic::Code::Unknown,
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/pb.AdminService/ListAdmin",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("pb.AdminService", "ListAdmin"));
-            self.inner.unary(req, path, codec).await
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
         }
-        /// 修改管理员
-        pub async fn edit_admin(
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// 创建标签
+        pub async fn create_tag(
             &mut self,
-            request: impl tonic::IntoRequest<super::EditAdminRequest>,
-        ) -> std::result::Result<tonic::Response<super::EditAdminReply>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::CreateTagRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateTagReply>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1809,21 +7301,16 @@ pub mod admin_service_client {
                     )
                 })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/pb.AdminService/EditAdmin",
-            );
+            let path = http::uri::PathAndQuery::from_static("/pb.TagService/CreateTag");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("pb.AdminService", "EditAdmin"));
+            req.extensions_mut().insert(GrpcMethod::new("pb.TagService", "CreateTag"));
             self.inner.unary(req, path, codec).await
         }
-        /// 删除/恢复管理员
-        pub async fn toggle_admin(
+        /// 重命名标签
+        pub async fn rename_tag(
             &mut self,
-            request: impl tonic::IntoRequest<super::ToggleAdminRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ToggleAdminReply>,
-            tonic::Status,
-        > {
+            request: impl tonic::IntoRequest<super::RenameTagRequest>,
+        ) -> std::result::Result<tonic::Response<super::RenameTagReply>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1834,22 +7321,16 @@ pub mod admin_service_client {
                     )
                 })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/pb.AdminService/ToggleAdmin",
-            );
+            let path = http::uri::PathAndQuery::from_static("/pb.TagService/RenameTag");
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("pb.AdminService", "ToggleAdmin"));
+            req.extensions_mut().insert(GrpcMethod::new("pb.TagService", "RenameTag"));
             self.inner.unary(req, path, codec).await
         }
-        /// 管理员是否存在
-        pub async fn admin_exists(
+        /// 合并标签
+        pub async fn merge_tag(
             &mut self,
-            request: impl tonic::IntoRequest<super::AdminExistsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::AdminExistsReply>,
-            tonic::Status,
-        > {
+            request: impl tonic::IntoRequest<super::MergeTagRequest>,
+        ) -> std::result::Result<tonic::Response<super::MergeTagReply>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1860,19 +7341,16 @@ pub mod admin_service_client {
                     )
                 })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/pb.AdminService/AdminExists",
-            );
+            let path = http::uri::PathAndQuery::from_static("/pb.TagService/MergeTag");
             let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("pb.AdminService", "AdminExists"));
+            req.extensions_mut().insert(GrpcMethod::new("pb.TagService", "MergeTag"));
             self.inner.unary(req, path, codec).await
         }
-        /// 获取管理员
-        pub async fn get_admin(
+        /// 标签列表
+        pub async fn list_tag(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetAdminRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetAdminReply>, tonic::Status> {
+            request: impl tonic::IntoRequest<super::ListTagRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListTagReply>, tonic::Status> {
             self.inner
                 .ready()
                 .await
@@ -1883,69 +7361,50 @@ pub mod admin_service_client {
                     )
                 })?;
             let codec = tonic::codec::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static("/pb.AdminService/GetAdmin");
+            let path = http::uri::PathAndQuery::from_static("/pb.TagService/ListTag");
             let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("pb.AdminService", "GetAdmin"));
+            req.extensions_mut().insert(GrpcMethod::new("pb.TagService", "ListTag"));
             self.inner.unary(req, path, codec).await
         }
     }
 }
 /// Generated server implementations.
-pub mod admin_service_server {
+pub mod tag_service_server {
     #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
     use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with AdminServiceServer.
+    /// Generated trait containing gRPC methods that should be implemented for use with TagServiceServer.
     #[async_trait]
-    pub trait AdminService: Send + Sync + 'static {
-        /// 添加管理员
-        async fn create_admin(
-            &self,
-            request: tonic::Request<super::CreateAdminRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::CreateAdminReply>,
-            tonic::Status,
-        >;
-        /// 管理员列表
-        async fn list_admin(
-            &self,
-            request: tonic::Request<super::ListAdminRequest>,
-        ) -> std::result::Result<tonic::Response<super::ListAdminReply>, tonic::Status>;
-        /// 修改管理员
-        async fn edit_admin(
+    pub trait TagService: Send + Sync + 'static {
+        /// 创建标签
+        async fn create_tag(
             &self,
-            request: tonic::Request<super::EditAdminRequest>,
-        ) -> std::result::Result<tonic::Response<super::EditAdminReply>, tonic::Status>;
-        /// 删除/恢复管理员
-        async fn toggle_admin(
+            request: tonic::Request<super::CreateTagRequest>,
+        ) -> std::result::Result<tonic::Response<super::CreateTagReply>, tonic::Status>;
+        /// 重命名标签
+        async fn rename_tag(
             &self,
-            request: tonic::Request<super::ToggleAdminRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::ToggleAdminReply>,
-            tonic::Status,
-        >;
-        /// 管理员是否存在
-        async fn admin_exists(
+            request: tonic::Request<super::RenameTagRequest>,
+        ) -> std::result::Result<tonic::Response<super::RenameTagReply>, tonic::Status>;
+        /// 合并标签
+        async fn merge_tag(
             &self,
-            request: tonic::Request<super::AdminExistsRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::AdminExistsReply>,
-            tonic::Status,
-        >;
-        /// 获取管理员
-        async fn get_admin(
+            request: tonic::Request<super::MergeTagRequest>,
+        ) -> std::result::Result<tonic::Response<super::MergeTagReply>, tonic::Status>;
+        /// 标签列表
+        async fn list_tag(
             &self,
-            request: tonic::Request<super::GetAdminRequest>,
-        ) -> std::result::Result<tonic::Response<super::GetAdminReply>, tonic::Status>;
+            request: tonic::Request<super::ListTagRequest>,
+        ) -> std::result::Result<tonic::Response<super::ListTagReply>, tonic::Status>;
     }
     #[derive(Debug)]
-    pub struct AdminServiceServer<T: AdminService> {
+    pub struct TagServiceServer<T: TagService> {
         inner: Arc<T>,
         accept_compression_encodings: EnabledCompressionEncodings,
         send_compression_encodings: EnabledCompressionEncodings,
         max_decoding_message_size: Option<usize>,
         max_encoding_message_size: Option<usize>,
     }
-    impl<T: AdminService> AdminServiceServer<T> {
+    impl<T: TagService> TagServiceServer<T> {
         pub fn new(inner: T) -> Self {
             Self::from_arc(Arc::new(inner))
         }
@@ -1996,9 +7455,9 @@ pub mod admin_service_server {
             self
         }
     }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for AdminServiceServer<T>
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for TagServiceServer<T>
     where
-        T: AdminService,
+        T: TagService,
         B: Body + Send + 'static,
         B::Error: Into<StdError> + Send + 'static,
     {
@@ -2013,115 +7472,25 @@ pub mod admin_service_server {
         }
         fn call(&mut self, req: http::Request<B>) -> Self::Future {
             match req.uri().path() {
-                "/pb.AdminService/CreateAdmin" => {
+                "/pb.TagService/CreateTag" => {
                     #[allow(non_camel_case_types)]
-                    struct CreateAdminSvc<T: AdminService>(pub Arc<T>);
-                    impl<
-                        T: AdminService,
-                    > tonic::server::UnaryService<super::CreateAdminRequest>
-                    for CreateAdminSvc<T> {
-                        type Response = super::CreateAdminReply;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::CreateAdminRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as AdminService>::create_admin(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let method = CreateAdminSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/pb.AdminService/ListAdmin" => {
-                    #[allow(non_camel_case_types)]
-                    struct ListAdminSvc<T: AdminService>(pub Arc<T>);
-                    impl<
-                        T: AdminService,
-                    > tonic::server::UnaryService<super::ListAdminRequest>
-                    for ListAdminSvc<T> {
-                        type Response = super::ListAdminReply;
-                        type Future = BoxFuture<
-                            tonic::Response<Self::Response>,
-                            tonic::Status,
-                        >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::ListAdminRequest>,
-                        ) -> Self::Future {
-                            let inner = Arc::clone(&self.0);
-                            let fut = async move {
-                                <T as AdminService>::list_admin(&inner, request).await
-                            };
-                            Box::pin(fut)
-                        }
-                    }
-                    let accept_compression_encodings = self.accept_compression_encodings;
-                    let send_compression_encodings = self.send_compression_encodings;
-                    let max_decoding_message_size = self.max_decoding_message_size;
-                    let max_encoding_message_size = self.max_encoding_message_size;
-                    let inner = self.inner.clone();
-                    let fut = async move {
-                        let method = ListAdminSvc(inner);
-                        let codec = tonic::codec::ProstCodec::default();
-                        let mut grpc = tonic::server::Grpc::new(codec)
-                            .apply_compression_config(
-                                accept_compression_encodings,
-                                send_compression_encodings,
-                            )
-                            .apply_max_message_size_config(
-                                max_decoding_message_size,
-                                max_encoding_message_size,
-                            );
-                        let res = grpc.unary(method, req).await;
-                        Ok(res)
-                    };
-                    Box::pin(fut)
-                }
-                "/pb.AdminService/EditAdmin" => {
-                    #[allow(non_camel_case_types)]
-                    struct EditAdminSvc<T: AdminService>(pub Arc<T>);
+                    struct CreateTagSvc<T: TagService>(pub Arc<T>);
                     impl<
-                        T: AdminService,
-                    > tonic::server::UnaryService<super::EditAdminRequest>
-                    for EditAdminSvc<T> {
-                        type Response = super::EditAdminReply;
+                        T: TagService,
+                    > tonic::server::UnaryService<super::CreateTagRequest>
+                    for CreateTagSvc<T> {
+                        type Response = super::CreateTagReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::EditAdminRequest>,
+                            request: tonic::Request<super::CreateTagRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as AdminService>::edit_admin(&inner, request).await
+                                <T as TagService>::create_tag(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2132,7 +7501,7 @@ pub mod admin_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = EditAdminSvc(inner);
+                        let method = CreateTagSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2148,25 +7517,25 @@ pub mod admin_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.AdminService/ToggleAdmin" => {
+                "/pb.TagService/RenameTag" => {
                     #[allow(non_camel_case_types)]
-                    struct ToggleAdminSvc<T: AdminService>(pub Arc<T>);
+                    struct RenameTagSvc<T: TagService>(pub Arc<T>);
                     impl<
-                        T: AdminService,
-                    > tonic::server::UnaryService<super::ToggleAdminRequest>
-                    for ToggleAdminSvc<T> {
-                        type Response = super::ToggleAdminReply;
+                        T: TagService,
+                    > tonic::server::UnaryService<super::RenameTagRequest>
+                    for RenameTagSvc<T> {
+                        type Response = super::RenameTagReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ToggleAdminRequest>,
+                            request: tonic::Request<super::RenameTagRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as AdminService>::toggle_admin(&inner, request).await
+                                <T as TagService>::rename_tag(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2177,7 +7546,7 @@ pub mod admin_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ToggleAdminSvc(inner);
+                        let method = RenameTagSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2193,25 +7562,25 @@ pub mod admin_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.AdminService/AdminExists" => {
+                "/pb.TagService/MergeTag" => {
                     #[allow(non_camel_case_types)]
-                    struct AdminExistsSvc<T: AdminService>(pub Arc<T>);
+                    struct MergeTagSvc<T: TagService>(pub Arc<T>);
                     impl<
-                        T: AdminService,
-                    > tonic::server::UnaryService<super::AdminExistsRequest>
-                    for AdminExistsSvc<T> {
-                        type Response = super::AdminExistsReply;
+                        T: TagService,
+                    > tonic::server::UnaryService<super::MergeTagRequest>
+                    for MergeTagSvc<T> {
+                        type Response = super::MergeTagReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AdminExistsRequest>,
+                            request: tonic::Request<super::MergeTagRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as AdminService>::admin_exists(&inner, request).await
+                                <T as TagService>::merge_tag(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2222,7 +7591,7 @@ pub mod admin_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = AdminExistsSvc(inner);
+                        let method = MergeTagSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2238,25 +7607,25 @@ pub mod admin_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/pb.AdminService/GetAdmin" => {
+                "/pb.TagService/ListTag" => {
                     #[allow(non_camel_case_types)]
-                    struct GetAdminSvc<T: AdminService>(pub Arc<T>);
+                    struct ListTagSvc<T: TagService>(pub Arc<T>);
                     impl<
-                        T: AdminService,
-                    > tonic::server::UnaryService<super::GetAdminRequest>
-                    for GetAdminSvc<T> {
-                        type Response = super::GetAdminReply;
+                        T: TagService,
+                    > tonic::server::UnaryService<super::ListTagRequest>
+                    for ListTagSvc<T> {
+                        type Response = super::ListTagReply;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetAdminRequest>,
+                            request: tonic::Request<super::ListTagRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as AdminService>::get_admin(&inner, request).await
+                                <T as TagService>::list_tag(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2267,7 +7636,7 @@ pub mod admin_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetAdminSvc(inner);
+                        let method = ListTagSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2301,7 +7670,7 @@ pub mod admin_service_server {
             }
         }
     }
-    impl<T: AdminService> Clone for AdminServiceServer<T> {
+    impl<T: TagService> Clone for TagServiceServer<T> {
         fn clone(&self) -> Self {
             let inner = self.inner.clone();
             Self {
@@ -2313,7 +7682,7 @@ pub mod admin_service_server {
             }
         }
     }
-    impl<T: AdminService> tonic::server::NamedService for AdminServiceServer<T> {
-        const NAME: &'static str = "pb.AdminService";
+    impl<T: TagService> tonic::server::NamedService for TagServiceServer<T> {
+        const NAME: &'static str = "pb.TagService";
     }
 }
@@ -1,4 +1,4 @@
-use chrono::{Datelike, Local, TimeZone, Timelike};
+use chrono::{Local, TimeZone};
 // 提供 blog_proto 结构体类型到 Rust 结构体类型的转换
 use serde::Serialize;
 
@@ -7,6 +7,18 @@ pub struct Category {
     pub id: i32,
     pub name: String,
     pub is_del: bool,
+    pub deleted_at: Option<Dateline>,
+    pub deleted_by: Option<i32>,
+    pub version: i32,
+    /// 已发布的文章数
+    pub topic_count: i64,
+    /// 最新一篇已发布文章的发布时间
+    pub latest_dateline: Option<Dateline>,
+    pub parent_id: Option<i32>,
+    /// 排序权重，越小越靠前
+    pub sort_weight: i32,
+    /// 在分类树中的层级，顶级分类为0
+    pub depth: i32,
 }
 
 impl From<blog_proto::Category> for Category {
@@ -15,10 +27,59 @@ impl From<blog_proto::Category> for Category {
             id: c.id,
             name: c.name,
             is_del: c.is_del,
+            deleted_at: c.deleted_at.map(|d| d.into()),
+            deleted_by: c.deleted_by,
+            version: c.version,
+            topic_count: c.topic_count,
+            latest_dateline: c.latest_dateline.map(|d| d.into()),
+            parent_id: c.parent_id,
+            sort_weight: c.sort_weight,
+            depth: 0,
         }
     }
 }
 
+/// 把分类树按先序展开成列表，并设置每个分类的层级
+pub fn flatten_tree(nodes: Vec<blog_proto::CategoryNode>) -> Vec<Category> {
+    fn walk(nodes: Vec<blog_proto::CategoryNode>, depth: i32, out: &mut Vec<Category>) {
+        for node in nodes {
+            if let Some(c) = node.category {
+                out.push(Category { depth, ..c.into() });
+            }
+            walk(node.children, depth + 1, out);
+        }
+    }
+    let mut out = vec![];
+    walk(nodes, 0, &mut out);
+    out
+}
+
+/// 面包屑导航中的一级分类
+#[derive(Serialize, Default)]
+pub struct Breadcrumb {
+    pub id: i32,
+    pub name: String,
+}
+
+/// 从顶级分类到指定分类的路径，分类不在列表中时为空
+pub fn breadcrumbs(categories: &[Category], category_id: i32) -> Vec<Breadcrumb> {
+    let mut out = vec![];
+    let mut id = Some(category_id);
+    while let Some(c) = id.and_then(|id| categories.iter().find(|c| c.id == id)) {
+        // 数据有误形成环时停止
+        if out.iter().any(|b: &Breadcrumb| b.id == c.id) {
+            break;
+        }
+        out.push(Breadcrumb {
+            id: c.id,
+            name: c.name.clone(),
+        });
+        id = c.parent_id;
+    }
+    out.reverse();
+    out
+}
+
 #[derive(Serialize, Default)]
 pub struct Dateline {
     pub timestamp: i64,
@@ -43,9 +104,9 @@ impl Dateline {
     }
 }
 
-impl ToString for Dateline {
-    fn to_string(&self) -> String {
-        self.datetime_str()
+impl std::fmt::Display for Dateline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.datetime_str())
     }
 }
 
@@ -65,30 +126,24 @@ impl From<prost_types::Timestamp> for Dateline {
     }
 }
 
-impl Into<chrono::DateTime<Local>> for Dateline {
-    fn into(self) -> chrono::DateTime<Local> {
-        Local.timestamp_opt(self.timestamp, 0).unwrap()
+impl From<Dateline> for chrono::DateTime<Local> {
+    fn from(dl: Dateline) -> Self {
+        Local.timestamp_opt(dl.timestamp, 0).unwrap()
     }
 }
 
-impl Into<chrono::DateTime<Local>> for &Dateline {
-    fn into(self) -> chrono::DateTime<Local> {
-        Local.timestamp_opt(self.timestamp, 0).unwrap()
+impl From<&Dateline> for chrono::DateTime<Local> {
+    fn from(dl: &Dateline) -> Self {
+        Local.timestamp_opt(dl.timestamp, 0).unwrap()
     }
 }
 
-impl Into<prost_types::Timestamp> for Dateline {
-    fn into(self) -> prost_types::Timestamp {
-        let dt: chrono::DateTime<Local> = self.into();
-        prost_types::Timestamp::date_time(
-            dt.year().into(),
-            dt.month() as u8,
-            dt.day() as u8,
-            dt.hour() as u8,
-            dt.minute() as u8,
-            dt.second() as u8,
-        )
-        .unwrap()
+impl From<Dateline> for prost_types::Timestamp {
+    fn from(dl: Dateline) -> Self {
+        prost_types::Timestamp {
+            seconds: dl.timestamp,
+            nanos: 0,
+        }
     }
 }
 
@@ -99,10 +154,37 @@ pub struct Topic {
     pub category_id: i32,
     pub summary: String,
     pub content: String,
+    pub content_html: String,
     pub hit: i32,
     pub is_del: bool,
     pub dateline: Dateline,
     pub category_name: String,
+    pub highlight: Option<TopicHighlight>,
+    /// draft / published / scheduled
+    pub status: String,
+    pub publish_at: Option<Dateline>,
+    pub tags: Vec<String>,
+    pub slug: String,
+    /// 已通过审核的评论数
+    pub comment_count: i64,
+    pub deleted_at: Option<Dateline>,
+    pub deleted_by: Option<i32>,
+    pub author_id: Option<i32>,
+    pub author: Option<Author>,
+    /// 最后修改文章的管理员
+    pub last_edited_by: Option<i32>,
+    /// 字数
+    pub word_count: i32,
+    /// 预计阅读时间（分钟）
+    pub reading_time: i32,
+    /// none / global / category
+    pub pin_scope: String,
+    pub pin_order: i32,
+    pub is_featured: bool,
+    /// 版本号，修改时用于检测并发修改
+    pub version: i32,
+    /// 从顶级分类到所属分类的路径
+    pub breadcrumbs: Vec<Breadcrumb>,
 }
 
 impl From<blog_proto::Topic> for Topic {
@@ -111,20 +193,274 @@ impl From<blog_proto::Topic> for Topic {
             Some(dl) => dl.into(),
             None => Default::default(),
         };
+        let status = topic_status_name(t.status()).to_string();
+        let pin_scope = pin_scope_name(t.pin_scope()).to_string();
         Self {
             id: t.id,
             title: t.title,
             category_id: t.category_id,
             summary: t.summary,
             content: t.content,
+            content_html: t.content_html,
             hit: t.hit,
             is_del: t.is_del,
             dateline: dl,
+            status,
+            publish_at: t.publish_at.map(|p| p.into()),
+            tags: t.tags,
+            slug: t.slug,
+            deleted_at: t.deleted_at.map(|d| d.into()),
+            deleted_by: t.deleted_by,
+            author_id: t.author_id,
+            last_edited_by: t.last_edited_by,
+            word_count: t.word_count,
+            reading_time: t.reading_time,
+            pin_scope,
+            pin_order: t.pin_order,
+            is_featured: t.is_featured,
+            version: t.version,
             ..Default::default()
         }
     }
 }
 
+pub fn topic_status_name(status: blog_proto::TopicStatus) -> &'static str {
+    match status {
+        blog_proto::TopicStatus::Draft => "draft",
+        blog_proto::TopicStatus::Published => "published",
+        blog_proto::TopicStatus::Scheduled => "scheduled",
+    }
+}
+
+pub fn pin_scope_name(scope: blog_proto::PinScope) -> &'static str {
+    match scope {
+        blog_proto::PinScope::None => "none",
+        blog_proto::PinScope::Global => "global",
+        blog_proto::PinScope::Category => "category",
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct Tag {
+    pub id: i32,
+    pub name: String,
+    pub topic_count: i64,
+}
+
+impl From<blog_proto::Tag> for Tag {
+    fn from(t: blog_proto::Tag) -> Self {
+        Self {
+            id: t.id,
+            name: t.name,
+            topic_count: t.topic_count,
+        }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct Series {
+    pub id: i32,
+    pub title: String,
+    pub description: String,
+    pub topic_count: i32,
+    pub dateline: Dateline,
+}
+
+impl From<blog_proto::Series> for Series {
+    fn from(s: blog_proto::Series) -> Self {
+        Self {
+            id: s.id,
+            title: s.title,
+            description: s.description,
+            topic_count: s.topic_count,
+            dateline: s.dateline.map(|d| d.into()).unwrap_or_default(),
+        }
+    }
+}
+
+/// 文章在系列中的位置，以及上一篇和下一篇
+#[derive(Serialize, Default)]
+pub struct SeriesNav {
+    pub series: Series,
+    /// 第几篇，从1开始
+    pub part: i32,
+    pub total: i32,
+    pub prev: Option<Topic>,
+    pub next: Option<Topic>,
+}
+
+#[derive(Serialize, Default)]
+pub struct Archive {
+    pub year: i32,
+    pub month: i32,
+    pub topic_count: i64,
+}
+
+impl From<blog_proto::Archive> for Archive {
+    fn from(a: blog_proto::Archive) -> Self {
+        Self {
+            year: a.year,
+            month: a.month,
+            topic_count: a.topic_count,
+        }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct Comment {
+    pub id: i64,
+    pub topic_id: i64,
+    pub parent_id: Option<i64>,
+    pub author: String,
+    pub email: String,
+    pub content: String,
+    /// pending / approved / spam / deleted
+    pub status: String,
+    pub ip: String,
+    pub user_agent: String,
+    pub dateline: Dateline,
+    pub replies: Vec<Comment>,
+}
+
+impl From<blog_proto::Comment> for Comment {
+    fn from(c: blog_proto::Comment) -> Self {
+        let dl = match c.dateline {
+            Some(dl) => dl.into(),
+            None => Default::default(),
+        };
+        let status = comment_status_name(c.status()).to_string();
+        Self {
+            id: c.id,
+            topic_id: c.topic_id,
+            parent_id: c.parent_id,
+            author: c.author,
+            email: c.email,
+            content: c.content,
+            status,
+            ip: c.ip,
+            user_agent: c.user_agent,
+            dateline: dl,
+            replies: c.replies.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+}
+
+pub fn comment_status_name(status: blog_proto::CommentStatus) -> &'static str {
+    match status {
+        blog_proto::CommentStatus::Pending => "pending",
+        blog_proto::CommentStatus::Approved => "approved",
+        blog_proto::CommentStatus::Spam => "spam",
+        blog_proto::CommentStatus::Deleted => "deleted",
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct Media {
+    pub id: i64,
+    pub hash: String,
+    pub filename: String,
+    pub mime: String,
+    pub size: i64,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub uploaded_by: Option<i32>,
+    pub dateline: Dateline,
+    /// 存储中的文件名，访问地址为 /media/{文件名}
+    pub file: String,
+    pub thumbnail: Option<String>,
+    pub webp: Option<String>,
+    pub thumbnail_webp: Option<String>,
+}
+
+impl From<blog_proto::Media> for Media {
+    fn from(m: blog_proto::Media) -> Self {
+        let dl = match m.dateline {
+            Some(dl) => dl.into(),
+            None => Default::default(),
+        };
+        Self {
+            id: m.id,
+            hash: m.hash,
+            filename: m.filename,
+            mime: m.mime,
+            size: m.size,
+            width: m.width,
+            height: m.height,
+            uploaded_by: m.uploaded_by,
+            dateline: dl,
+            file: m.file,
+            thumbnail: m.thumbnail,
+            webp: m.webp,
+            thumbnail_webp: m.thumbnail_webp,
+        }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct TopicHighlight {
+    pub title: String,
+    pub snippet: String,
+}
+
+impl From<blog_proto::TopicHighlight> for TopicHighlight {
+    fn from(h: blog_proto::TopicHighlight) -> Self {
+        Self {
+            title: h.title,
+            snippet: h.snippet,
+        }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct TopicRevision {
+    pub id: i64,
+    pub topic_id: i64,
+    pub title: String,
+    pub category_id: i32,
+    pub summary: String,
+    pub content: String,
+    pub dateline: Dateline,
+}
+
+impl From<blog_proto::TopicRevision> for TopicRevision {
+    fn from(r: blog_proto::TopicRevision) -> Self {
+        let dl = match r.dateline {
+            Some(dl) => dl.into(),
+            None => Default::default(),
+        };
+        Self {
+            id: r.id,
+            topic_id: r.topic_id,
+            title: r.title,
+            category_id: r.category_id,
+            summary: r.summary,
+            content: r.content,
+            dateline: dl,
+        }
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct DiffLine {
+    /// equal / delete / insert
+    pub tag: String,
+    pub text: String,
+}
+
+impl From<blog_proto::DiffLine> for DiffLine {
+    fn from(l: blog_proto::DiffLine) -> Self {
+        let tag = match l.tag() {
+            blog_proto::diff_line::Tag::Equal => "equal",
+            blog_proto::diff_line::Tag::Delete => "delete",
+            blog_proto::diff_line::Tag::Insert => "insert",
+        };
+        Self {
+            tag: tag.to_string(),
+            text: l.text,
+        }
+    }
+}
+
 #[derive(Serialize, Default)]
 pub struct Paginate<T: Serialize> {
     pub page: i32,
@@ -132,4 +468,33 @@ pub struct Paginate<T: Serialize> {
     pub page_totoal: i64,
     pub record_total: i64,
     pub data: Vec<T>,
+    /// 下一页的游标
+    pub next_cursor: Option<String>,
+    /// 上一页的游标
+    pub prev_cursor: Option<String>,
+}
+
+/// 前台展示的作者信息，不包含邮箱
+#[derive(Serialize, Default, Clone)]
+pub struct Author {
+    pub id: i32,
+    /// 没有设置显示名称时为“作者{id}”
+    pub name: String,
+    pub avatar: String,
+    pub bio: String,
+}
+
+impl From<blog_proto::Admin> for Author {
+    fn from(a: blog_proto::Admin) -> Self {
+        let name = match a.display_name.trim() {
+            "" => format!("作者{}", a.id),
+            name => name.to_string(),
+        };
+        Self {
+            id: a.id,
+            name,
+            avatar: a.avatar,
+            bio: a.bio,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+/// 从环境变量读取以秒为单位的间隔，未设置或无法解析时使用默认值。
+/// 间隔最小为1秒，`tokio::time::interval` 不接受为0的间隔
+pub fn from_env(name: &str, default_secs: u64) -> Duration {
+    let secs = std::env::var(name)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(default_secs);
+    Duration::from_secs(secs.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_env() {
+        const NAME: &str = "BLOG_UTILS_TEST_INTERVAL";
+        std::env::remove_var(NAME);
+        assert_eq!(from_env(NAME, 30), Duration::from_secs(30));
+        std::env::set_var(NAME, "0");
+        assert_eq!(from_env(NAME, 30), Duration::from_secs(1));
+        std::env::set_var(NAME, "abc");
+        assert_eq!(from_env(NAME, 30), Duration::from_secs(30));
+        std::env::set_var(NAME, "120");
+        assert_eq!(from_env(NAME, 30), Duration::from_secs(120));
+    }
+}
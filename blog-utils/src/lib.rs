@@ -1 +1,3 @@
+pub mod interval;
 pub mod password;
+pub mod url;
@@ -0,0 +1,24 @@
+/// 百分号编码，仅保留非保留字符。用于 RFC 5987 文件名和 URL 的路径、查询参数
+pub fn percent_encode(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percent_encode() {
+        assert_eq!(percent_encode("report-2023.pdf"), "report-2023.pdf");
+        assert_eq!(percent_encode("a b;\".zip"), "a%20b%3B%22.zip");
+        assert_eq!(percent_encode("文档.pdf"), "%E6%96%87%E6%A1%A3.pdf");
+        assert_eq!(percent_encode("a&b=c#d/e"), "a%26b%3Dc%23d%2Fe");
+    }
+}
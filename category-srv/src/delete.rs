@@ -0,0 +1,58 @@
+// 删除分类时处理分类中的文章
+use blog_proto::CategoryDeleteMode;
+use sqlx::{PgConnection, Row};
+
+/// 按照删除方式处理分类中的文章，返回移动到其它分类的文章数
+pub async fn clear_topics(
+    conn: &mut PgConnection,
+    id: i32,
+    mode: CategoryDeleteMode,
+    move_to: Option<i32>,
+) -> Result<i64, tonic::Status> {
+    match mode {
+        CategoryDeleteMode::Block => {
+            let row =
+                sqlx::query("SELECT COUNT(*) FROM topics WHERE category_id=$1 AND is_del=false")
+                    .bind(id)
+                    .fetch_one(&mut *conn)
+                    .await
+                    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            let count: i64 = row.get(0);
+            if count > 0 {
+                return Err(tonic::Status::failed_precondition(format!(
+                    "分类中还有{}篇文章，请先移动或隐藏这些文章",
+                    count
+                )));
+            }
+            Ok(0)
+        }
+        CategoryDeleteMode::Move => {
+            let move_to = move_to.ok_or(tonic::Status::invalid_argument("请选择目标分类"))?;
+            if move_to == id {
+                return Err(tonic::Status::invalid_argument("不能移动到要删除的分类"));
+            }
+            let exists =
+                sqlx::query("SELECT id FROM categories WHERE id=$1 AND is_del=false FOR SHARE")
+                    .bind(move_to)
+                    .fetch_optional(&mut *conn)
+                    .await
+                    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            if exists.is_none() {
+                return Err(tonic::Status::invalid_argument("目标分类不存在或已删除"));
+            }
+            // 回收站中的文章也一起移动，之后才能彻底删除分类
+            let rows_affected = sqlx::query(
+                "UPDATE topics SET category_id=$2,version=version+1 WHERE category_id=$1",
+            )
+            .bind(id)
+            .bind(move_to)
+            .execute(&mut *conn)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?
+            .rows_affected();
+            Ok(rows_affected as i64)
+        }
+        // 文章保留在分类中，前台按分类的删除状态隐藏
+        CategoryDeleteMode::Hide => Ok(0),
+    }
+}
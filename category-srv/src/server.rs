@@ -1,14 +1,19 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use blog_proto::{
     category_exists_request::Condition, category_service_server::CategoryService,
-    CategoryExistsReply, CategoryExistsRequest, CreateCategoryReply, CreateCategoryRequest,
-    EditCategoryReply, EditCategoryRequest, GetCategoryReply, GetCategoryRequest,
-    ListCategoryReply, ListCategoryRequest, ToggleCategoryReply, ToggleCategoryRequest,
+    CategoryDeleteMode, CategoryExistsReply, CategoryExistsRequest, CreateCategoryReply,
+    CreateCategoryRequest, EditCategoryReply, EditCategoryRequest, GetCategoryReply,
+    GetCategoryRequest, ListCategoryReply, ListCategoryRequest, ListCategoryTreeReply,
+    ListCategoryTreeRequest, PurgeCategoriesReply, PurgeCategoriesRequest, ReorderCategoriesReply,
+    ReorderCategoriesRequest, ToggleCategoryReply, ToggleCategoryRequest, TopicStatus,
 };
-use sqlx::{PgPool, Row};
+use chrono::{DateTime, Local};
+use sqlx::{postgres::PgRow, PgPool, Row};
 use tonic::Response;
 
+use crate::{delete, trash, tree};
+
 pub struct Category {
     pool: Arc<PgPool>,
 }
@@ -19,6 +24,51 @@ impl Category {
             pool: Arc::new(pool),
         }
     }
+
+    /// 按条件查询分类，需要时统计每个分类已发布的文章数和最新文章的发布时间
+    async fn list(
+        &self,
+        name: Option<String>,
+        is_del: Option<bool>,
+        with_stats: Option<bool>,
+    ) -> Result<Vec<blog_proto::Category>, tonic::Status> {
+        // 不需要统计时，聚合子查询的条件恒为假，不会扫描文章表
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                c.id,c.name,c.is_del,c.deleted_at,c.deleted_by,c.version,c.parent_id,c.sort_weight,
+                COALESCE(s.topic_count, 0) AS topic_count, s.latest_dateline
+            FROM categories c
+            LEFT JOIN (
+                SELECT category_id, COUNT(*) AS topic_count, MAX(dateline) AS latest_dateline
+                FROM topics
+                WHERE $3::boolean AND is_del = false AND status = $4
+                GROUP BY category_id
+            ) s ON s.category_id = c.id
+            WHERE ($1::varchar IS NULL OR c.name ILIKE $1::varchar)
+                AND ($2::boolean IS NULL OR c.is_del = $2::boolean)
+            ORDER BY c.sort_weight, c.id"#,
+        )
+        .bind(name.map(|name| format!("%{}%", name)))
+        .bind(is_del)
+        .bind(with_stats.unwrap_or(false))
+        .bind(TopicStatus::Published as i16)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let categories = rows
+            .iter()
+            .map(|row| {
+                let latest_dateline: Option<DateTime<Local>> = row.get("latest_dateline");
+                blog_proto::Category {
+                    topic_count: row.get("topic_count"),
+                    latest_dateline: latest_dateline.and_then(|dt| dt_conver(&dt)),
+                    ..category_from_row(row)
+                }
+            })
+            .collect();
+        Ok(categories)
+    }
 }
 
 #[tonic::async_trait]
@@ -52,7 +102,7 @@ impl CategoryService for Category {
         &self,
         request: tonic::Request<CreateCategoryRequest>,
     ) -> Result<tonic::Response<CreateCategoryReply>, tonic::Status> {
-        let CreateCategoryRequest { name } = request.into_inner();
+        let CreateCategoryRequest { name, parent_id } = request.into_inner();
         let exists_request = tonic::Request::new(CategoryExistsRequest {
             condition: Some(Condition::Name(name.clone())),
         });
@@ -60,11 +110,23 @@ impl CategoryService for Category {
         if exists_reply.exists {
             return Err(tonic::Status::already_exists("分类已存在"));
         }
-        let res = sqlx::query("insert into categories (name) values ($1) returning id")
-            .bind(name)
-            .fetch_one(&*self.pool)
-            .await
-            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        if let Some(parent_id) = parent_id {
+            let mut conn = self
+                .pool
+                .acquire()
+                .await
+                .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            tree::check_parent(&mut conn, None, parent_id).await?;
+        }
+        // 新分类排在最后
+        let res = sqlx::query(
+            "insert into categories (name,parent_id,sort_weight) values ($1,$2,(select coalesce(max(sort_weight),0)+1 from categories)) returning id",
+        )
+        .bind(name)
+        .bind(parent_id)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
         let reply = CreateCategoryReply { id: res.get("id") };
         Ok(tonic::Response::new(reply))
     }
@@ -73,7 +135,14 @@ impl CategoryService for Category {
         &self,
         request: tonic::Request<EditCategoryRequest>,
     ) -> Result<tonic::Response<EditCategoryReply>, tonic::Status> {
-        let EditCategoryRequest { id, name } = request.into_inner();
+        let EditCategoryRequest {
+            id,
+            name,
+            version,
+            parent_id,
+            clear_parent,
+        } = request.into_inner();
+        let parent_id = parent_id.filter(|_| !clear_parent);
         let row = sqlx::query("select count(*) from categories where name=$1 and id<>$2")
             .bind(&name)
             .bind(id)
@@ -84,16 +153,58 @@ impl CategoryService for Category {
         if count > 0i64 {
             return Err(tonic::Status::already_exists("分类已存在"));
         }
-        let rows_affected = sqlx::query("update categories set name=$1 where id=$2")
-            .bind(&name)
-            .bind(id)
-            .execute(&*self.pool)
+        let mut tx = self
+            .pool
+            .begin()
             .await
-            .map_err(|err| tonic::Status::internal(err.to_string()))?
-            .rows_affected();
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        if let Some(parent_id) = parent_id {
+            // 锁表避免两个并发的修改各自通过检查后形成环
+            sqlx::query("lock table categories in share row exclusive mode")
+                .execute(&mut *tx)
+                .await
+                .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            tree::check_parent(&mut tx, Some(id), parent_id).await?;
+        }
+        let row = sqlx::query(
+            r#"
+            update categories set
+                name=$1,
+                parent_id=(case when $5 then null else coalesce($4, parent_id) end),
+                version=version+1
+            where id=$2 and ($3::int is null or version=$3::int)
+            returning version"#,
+        )
+        .bind(&name)
+        .bind(id)
+        .bind(version)
+        .bind(parent_id)
+        .bind(clear_parent)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let new_version: Option<i32> = row.map(|row| row.get("version"));
+        if new_version.is_none() && version.is_some() {
+            // 没有更新任何记录：分类不存在，或者版本号已经改变
+            let current = sqlx::query("select version from categories where id=$1")
+                .bind(id)
+                .fetch_optional(&*self.pool)
+                .await
+                .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            if let Some(current) = current {
+                return Err(blog_proto::version_conflict(
+                    "分类已被其他人修改",
+                    current.get("version"),
+                ));
+            }
+        }
         let reply = EditCategoryReply {
             id,
-            ok: rows_affected > 0,
+            ok: new_version.is_some(),
+            version: new_version.unwrap_or_default(),
         };
         Ok(tonic::Response::new(reply))
     }
@@ -105,25 +216,18 @@ impl CategoryService for Category {
         let GetCategoryRequest { id, is_del } = request.into_inner();
         let query = match is_del {
             Some(is_del) => {
-                sqlx::query("select id,name,is_del from categories where id=$1 and is_del=$2")
+                sqlx::query("select id,name,is_del,deleted_at,deleted_by,version,parent_id,sort_weight from categories where id=$1 and is_del=$2")
                     .bind(id)
                     .bind(is_del)
             }
-            None => sqlx::query("select id,name,is_del from categories where id=$1").bind(is_del),
+            None => sqlx::query("select id,name,is_del,deleted_at,deleted_by,version,parent_id,sort_weight from categories where id=$1").bind(id),
         };
         let row = query
             .fetch_optional(&*self.pool)
             .await
             .map_err(|err| tonic::Status::internal(err.to_string()))?;
-        let reply = match row {
-            Some(row) => GetCategoryReply {
-                category: Some(blog_proto::Category {
-                    id: row.get("id"),
-                    name: row.get("name"),
-                    is_del: row.get("is_del"),
-                }),
-            },
-            None => GetCategoryReply { category: None },
+        let reply = GetCategoryReply {
+            category: row.as_ref().map(category_from_row),
         };
         Ok(tonic::Response::new(reply))
     }
@@ -132,67 +236,156 @@ impl CategoryService for Category {
         &self,
         request: tonic::Request<ListCategoryRequest>,
     ) -> Result<tonic::Response<ListCategoryReply>, tonic::Status> {
-        let ListCategoryRequest { name, is_del } = request.into_inner();
-        let query = match name {
-            Some(name) => {
-                let name = format!("%{}%", name);
-                match is_del {
-                    Some(is_del) => {
-                        sqlx::query(
-                            "select id,name,is_del from categories where name ilike $1 and is_del=$2 order by id"
-                        )
-                        .bind(name.clone())
-                        .bind(is_del)
-                    }
-                    None => sqlx::query("select id,name,is_del from categories where name ilike $1 order by id")
-                    .bind(name),
-                }
-            }
-            None => match is_del {
-                Some(is_del) => {
-                    sqlx::query("select id,name,is_del from categories where is_del=$1 order by id")
-                        .bind(is_del)
-                }
-                None => sqlx::query("select id,name,is_del from categories order by id"),
-            },
-        };
-        let rows = query
-            .fetch_all(&*self.pool)
-            .await
-            .map_err(|err| tonic::Status::internal(err.to_string()))?;
-
-        if rows.is_empty() {
+        let ListCategoryRequest {
+            name,
+            is_del,
+            with_stats,
+        } = request.into_inner();
+        let categories = self.list(name, is_del, with_stats).await?;
+        if categories.is_empty() {
             return Err(tonic::Status::not_found("没有符合条件的分类"));
         }
-        let mut categories = Vec::with_capacity(rows.len());
-        for row in rows {
-            categories.push(blog_proto::Category {
-                id: row.get("id"),
-                name: row.get("name"),
-                is_del: row.get("is_del"),
-            });
-        }
         let reply = ListCategoryReply { categories };
         Ok(tonic::Response::new(reply))
     }
 
+    async fn list_category_tree(
+        &self,
+        request: tonic::Request<ListCategoryTreeRequest>,
+    ) -> Result<tonic::Response<ListCategoryTreeReply>, tonic::Status> {
+        let ListCategoryTreeRequest { is_del, with_stats } = request.into_inner();
+        let categories = self.list(None, is_del, with_stats).await?;
+        let reply = ListCategoryTreeReply {
+            nodes: tree::build(categories),
+        };
+        Ok(tonic::Response::new(reply))
+    }
+
     async fn toggle_category(
         &self,
         request: tonic::Request<ToggleCategoryRequest>,
     ) -> Result<Response<ToggleCategoryReply>, tonic::Status> {
-        let ToggleCategoryRequest { id } = request.into_inner();
-        let row =
-            sqlx::query("update categories set is_del=(not is_del) where id=$1 returning is_del")
-                .bind(id)
-                .fetch_optional(&*self.pool)
-                .await
-                .map_err(|err| tonic::Status::internal(err.to_string()))?;
-        if let Some(row) = row {
-            return Ok(tonic::Response::new(ToggleCategoryReply {
-                id,
-                is_del: row.get(0),
-            }));
+        let ToggleCategoryRequest {
+            id,
+            admin_id,
+            delete_mode,
+            move_to,
+        } = request.into_inner();
+        let delete_mode = CategoryDeleteMode::try_from(delete_mode)
+            .map_err(|_| tonic::Status::invalid_argument("错误的删除方式"))?;
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let is_del: bool = match sqlx::query("select is_del from categories where id=$1 for update")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?
+        {
+            Some(row) => row.get("is_del"),
+            None => return Err(tonic::Status::not_found("不存在的分类")),
+        };
+        // 删除时先处理分类中的文章，恢复时不需要
+        let moved = if is_del {
+            0
+        } else {
+            delete::clear_topics(&mut tx, id, delete_mode, move_to).await?
+        };
+        // 删除时记录删除时间和管理员，恢复时清空
+        let row = sqlx::query(
+            r#"
+            update categories set
+                is_del=(not is_del),
+                deleted_at=(case when is_del then null else current_timestamp end),
+                deleted_by=(case when is_del then null else $2 end),
+                version=version+1
+            where id=$1
+            returning is_del"#,
+        )
+        .bind(id)
+        .bind(admin_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(ToggleCategoryReply {
+            id,
+            is_del: row.get(0),
+            moved,
+        }))
+    }
+
+    async fn purge_categories(
+        &self,
+        request: tonic::Request<PurgeCategoriesRequest>,
+    ) -> Result<Response<PurgeCategoriesReply>, tonic::Status> {
+        let PurgeCategoriesRequest { ids } = request.into_inner();
+        let ids = trash::purge(&self.pool, &ids)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(PurgeCategoriesReply { ids }))
+    }
+
+    async fn reorder_categories(
+        &self,
+        request: tonic::Request<ReorderCategoriesRequest>,
+    ) -> Result<Response<ReorderCategoriesReply>, tonic::Status> {
+        let ReorderCategoriesRequest { ids } = request.into_inner();
+        let mut seen = HashSet::with_capacity(ids.len());
+        if !ids.iter().all(|id| seen.insert(*id)) {
+            return Err(tonic::Status::invalid_argument("分类ID重复"));
+        }
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let updated = sqlx::query(
+            r#"
+            update categories set sort_weight = o.ord
+            from unnest($1::int[]) with ordinality as o(id, ord)
+            where categories.id = o.id"#,
+        )
+        .bind(&ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?
+        .rows_affected();
+        // 有不存在的分类时放弃修改
+        if updated as usize != ids.len() {
+            return Err(tonic::Status::not_found("不存在的分类"));
         }
-        Err(tonic::Status::not_found("不存在的分类"))
+        tx.commit()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(ReorderCategoriesReply {
+            updated: updated as i32,
+        }))
     }
 }
+
+fn category_from_row(row: &PgRow) -> blog_proto::Category {
+    let deleted_at: Option<DateTime<Local>> = row.get("deleted_at");
+    blog_proto::Category {
+        id: row.get("id"),
+        name: row.get("name"),
+        is_del: row.get("is_del"),
+        deleted_at: deleted_at.and_then(|dt| dt_conver(&dt)),
+        deleted_by: row.get("deleted_by"),
+        version: row.get("version"),
+        parent_id: row.get("parent_id"),
+        sort_weight: row.get("sort_weight"),
+        ..Default::default()
+    }
+}
+
+fn dt_conver(dt: &DateTime<Local>) -> Option<prost_types::Timestamp> {
+    Some(prost_types::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: 0,
+    })
+}
@@ -0,0 +1,56 @@
+// 回收站清理：周期性地彻底删除超过保留期的分类
+use std::time::Duration;
+
+use sqlx::{PgPool, Row};
+
+pub async fn run(pool: PgPool, interval: Duration, retention_days: i32) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        match purge_expired(&pool, retention_days).await {
+            Ok(0) => {}
+            Ok(n) => println!("purged {} category(s) from trash", n),
+            Err(err) => eprintln!("purge trash failed: {}", err),
+        }
+    }
+}
+
+async fn purge_expired(pool: &PgPool, retention_days: i32) -> Result<usize, sqlx::Error> {
+    let ids: Vec<i32> = sqlx::query(
+        "SELECT id FROM categories WHERE is_del AND deleted_at < CURRENT_TIMESTAMP - make_interval(days => $1)",
+    )
+    .bind(retention_days)
+    .fetch_all(pool)
+    .await?
+    .iter()
+    .map(|row| row.get("id"))
+    .collect();
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    Ok(purge(pool, &ids).await?.len())
+}
+
+/// 彻底删除回收站中的分类，返回已删除的分类ID。仍有文章（包括回收站中的文章）的分类不会被删除，
+/// 被删除分类的下级分类成为顶级分类
+pub async fn purge(pool: &PgPool, ids: &[i32]) -> Result<Vec<i32>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    let rows = sqlx::query(
+        r#"
+        DELETE FROM categories c
+        WHERE c.id = ANY($1) AND c.is_del
+            AND NOT EXISTS (SELECT 1 FROM topics t WHERE t.category_id = c.id)
+        RETURNING c.id"#,
+    )
+    .bind(ids)
+    .fetch_all(&mut *tx)
+    .await?;
+    let ids: Vec<i32> = rows.iter().map(|row| row.get("id")).collect();
+    // 下级分类改为顶级分类
+    sqlx::query("UPDATE categories SET parent_id = NULL WHERE parent_id = ANY($1)")
+        .bind(&ids)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(ids)
+}
@@ -0,0 +1,111 @@
+// 分类的层级关系
+use std::collections::{HashMap, HashSet};
+
+use blog_proto::{Category, CategoryNode};
+use sqlx::{PgConnection, Row};
+
+/// 把分类列表组织成树，同级分类保持列表中的顺序。
+/// 上级分类不在列表中的分类（例如上级分类已删除）作为顶级分类
+pub fn build(categories: Vec<Category>) -> Vec<CategoryNode> {
+    let ids: HashSet<i32> = categories.iter().map(|c| c.id).collect();
+    let mut roots = vec![];
+    let mut children: HashMap<i32, Vec<Category>> = HashMap::new();
+    for c in categories {
+        match c.parent_id.filter(|id| ids.contains(id)) {
+            Some(parent_id) => children.entry(parent_id).or_default().push(c),
+            None => roots.push(c),
+        }
+    }
+    roots.into_iter().map(|c| node(c, &mut children)).collect()
+}
+
+fn node(category: Category, children: &mut HashMap<i32, Vec<Category>>) -> CategoryNode {
+    let nodes = children
+        .remove(&category.id)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|c| node(c, children))
+        .collect();
+    CategoryNode {
+        category: Some(category),
+        children: nodes,
+    }
+}
+
+/// 检查 parent_id 能否作为分类 id 的上级分类：必须是未删除的分类，并且不能是分类自己或它的下级分类
+pub async fn check_parent(
+    conn: &mut PgConnection,
+    id: Option<i32>,
+    parent_id: i32,
+) -> Result<(), tonic::Status> {
+    let exists = sqlx::query("SELECT id FROM categories WHERE id=$1 AND is_del=false")
+        .bind(parent_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    if exists.is_none() {
+        return Err(tonic::Status::invalid_argument("上级分类不存在或已删除"));
+    }
+    let Some(id) = id else {
+        return Ok(());
+    };
+    // 从新的上级分类向上查找，遇到分类自己说明会形成环
+    let row = sqlx::query(
+        r#"
+        WITH RECURSIVE ancestors AS (
+            SELECT id, parent_id FROM categories WHERE id = $1
+            UNION
+            SELECT c.id, c.parent_id FROM categories c INNER JOIN ancestors a ON c.id = a.parent_id
+        )
+        SELECT EXISTS (SELECT 1 FROM ancestors WHERE id = $2)"#,
+    )
+    .bind(parent_id)
+    .bind(id)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    if row.get::<bool, _>(0) {
+        return Err(tonic::Status::invalid_argument(
+            "上级分类不能是分类自己或它的下级分类",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cate(id: i32, parent_id: Option<i32>) -> Category {
+        Category {
+            id,
+            name: format!("c{}", id),
+            parent_id,
+            ..Default::default()
+        }
+    }
+
+    fn ids(nodes: &[CategoryNode]) -> Vec<i32> {
+        nodes
+            .iter()
+            .map(|n| n.category.as_ref().unwrap().id)
+            .collect()
+    }
+
+    #[test]
+    fn test_build() {
+        let tree = build(vec![
+            cate(1, None),
+            cate(2, Some(1)),
+            cate(3, Some(2)),
+            cate(4, None),
+            cate(5, Some(1)),
+            // 上级分类不在列表中
+            cate(6, Some(99)),
+        ]);
+        assert_eq!(ids(&tree), vec![1, 4, 6]);
+        assert_eq!(ids(&tree[0].children), vec![2, 5]);
+        assert_eq!(ids(&tree[0].children[0].children), vec![3]);
+        assert!(tree[1].children.is_empty());
+    }
+}
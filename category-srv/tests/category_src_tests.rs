@@ -7,6 +7,7 @@ async fn test_create_category() {
         .unwrap();
     let request = tonic::Request::new(CreateCategoryRequest {
         name: "分类1".into(),
+        parent_id: None,
     });
     let reply = client.create_category(request).await.unwrap();
     let reply = reply.into_inner();
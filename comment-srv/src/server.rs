@@ -0,0 +1,335 @@
+use std::{collections::HashMap, sync::Arc};
+
+use blog_proto::{
+    comment_service_server::CommentService, CommentStatus, CountCommentReply, CountCommentRequest,
+    CreateCommentReply, CreateCommentRequest, GetCommentThreadReply, GetCommentThreadRequest,
+    ListCommentReply, ListCommentRequest, ModerateCommentReply, ModerateCommentRequest,
+};
+use chrono::{DateTime, Local};
+use sqlx::{postgres::PgRow, PgPool, Row};
+
+use crate::thread;
+
+/// 评论列表每页条数
+const PAGE_SIZE: i32 = 30;
+/// 昵称的最大长度（字符数）
+const AUTHOR_MAX_LEN: usize = 50;
+/// 邮箱的最大长度（字符数）
+const EMAIL_MAX_LEN: usize = 255;
+/// 评论内容的最大长度（字符数）
+const CONTENT_MAX_LEN: usize = 2000;
+/// IP 的最大长度（字符数）
+const IP_MAX_LEN: usize = 45;
+/// User-Agent 的最大长度（字符数），过长时截断
+const USER_AGENT_MAX_LEN: usize = 255;
+
+pub struct Comment {
+    pool: Arc<PgPool>,
+}
+
+impl Comment {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool: Arc::new(pool),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl CommentService for Comment {
+    async fn create_comment(
+        &self,
+        request: tonic::Request<CreateCommentRequest>,
+    ) -> Result<tonic::Response<CreateCommentReply>, tonic::Status> {
+        let CreateCommentRequest {
+            topic_id,
+            parent_id,
+            author,
+            email,
+            content,
+            ip,
+            user_agent,
+        } = request.into_inner();
+        let author = check_author(&author).map_err(tonic::Status::invalid_argument)?;
+        let email = check_email(&email).map_err(tonic::Status::invalid_argument)?;
+        let content = check_content(&content).map_err(tonic::Status::invalid_argument)?;
+        let ip: String = ip.trim().chars().take(IP_MAX_LEN).collect();
+        let user_agent: String = user_agent.trim().chars().take(USER_AGENT_MAX_LEN).collect();
+
+        // 只能评论已发布、且所属分类未删除的文章
+        let exists = sqlx::query(
+            r#"
+            SELECT id FROM topics
+            WHERE id=$1 AND is_del=false AND status=1
+              AND category_id IN (SELECT id FROM categories WHERE is_del=false)"#,
+        )
+        .bind(topic_id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        if exists.is_none() {
+            return Err(tonic::Status::not_found("不存在的文章"));
+        }
+        // 只能回复同一篇文章下已通过的评论
+        if let Some(parent_id) = parent_id {
+            let parent =
+                sqlx::query("SELECT id FROM comments WHERE id=$1 AND topic_id=$2 AND status=$3")
+                    .bind(parent_id)
+                    .bind(topic_id)
+                    .bind(CommentStatus::Approved as i16)
+                    .fetch_optional(&*self.pool)
+                    .await
+                    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            if parent.is_none() {
+                return Err(tonic::Status::invalid_argument("回复的评论不存在"));
+            }
+        }
+
+        let status = CommentStatus::Pending;
+        let row = sqlx::query(
+            r#"
+            INSERT INTO comments (topic_id, parent_id, author, email, content, status, ip, user_agent)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id"#,
+        )
+        .bind(topic_id)
+        .bind(parent_id)
+        .bind(author)
+        .bind(email)
+        .bind(content)
+        .bind(status as i16)
+        .bind(ip)
+        .bind(user_agent)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(CreateCommentReply {
+            id: row.get("id"),
+            status: status.into(),
+        }))
+    }
+
+    async fn list_comment(
+        &self,
+        request: tonic::Request<ListCommentRequest>,
+    ) -> Result<tonic::Response<ListCommentReply>, tonic::Status> {
+        let ListCommentRequest {
+            page,
+            topic_id,
+            status,
+        } = request.into_inner();
+        let status = match status {
+            Some(status) => {
+                Some(comment_status(status).map_err(tonic::Status::invalid_argument)? as i16)
+            }
+            None => None,
+        };
+        let page = page.unwrap_or(0).max(0);
+        let page_size = PAGE_SIZE;
+        let offset = page * page_size;
+
+        let row = sqlx::query(
+            r#"
+            SELECT COUNT(*) FROM comments
+            WHERE ($1::bigint IS NULL OR topic_id = $1::bigint)
+                AND ($2::smallint IS NULL OR status = $2::smallint)"#,
+        )
+        .bind(topic_id)
+        .bind(status)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let record_total: i64 = row.get(0);
+        let page_totoal = f64::ceil(record_total as f64 / page_size as f64) as i64;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id, topic_id, parent_id, author, email, content, status, ip, user_agent, dateline
+            FROM comments
+            WHERE ($3::bigint IS NULL OR topic_id = $3::bigint)
+                AND ($4::smallint IS NULL OR status = $4::smallint)
+            ORDER BY id DESC
+            LIMIT $1
+            OFFSET $2"#,
+        )
+        .bind(page_size)
+        .bind(offset)
+        .bind(topic_id)
+        .bind(status)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let comments = rows.iter().map(comment_from_row).collect();
+        Ok(tonic::Response::new(ListCommentReply {
+            page,
+            page_size,
+            page_totoal,
+            record_total,
+            comments,
+        }))
+    }
+
+    async fn get_comment_thread(
+        &self,
+        request: tonic::Request<GetCommentThreadRequest>,
+    ) -> Result<tonic::Response<GetCommentThreadReply>, tonic::Status> {
+        let GetCommentThreadRequest { topic_id } = request.into_inner();
+        let rows = sqlx::query(
+            r#"
+            SELECT id, topic_id, parent_id, author, email, content, status, ip, user_agent, dateline
+            FROM comments
+            WHERE topic_id = $1 AND status = $2
+            ORDER BY id"#,
+        )
+        .bind(topic_id)
+        .bind(CommentStatus::Approved as i16)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let total = rows.len() as i64;
+        let comments = rows
+            .iter()
+            .map(|row| {
+                let mut c = comment_from_row(row);
+                // 不对外提供访客的个人信息
+                c.email.clear();
+                c.ip.clear();
+                c.user_agent.clear();
+                c
+            })
+            .collect();
+        Ok(tonic::Response::new(GetCommentThreadReply {
+            comments: thread::build(comments),
+            total,
+        }))
+    }
+
+    async fn moderate_comment(
+        &self,
+        request: tonic::Request<ModerateCommentRequest>,
+    ) -> Result<tonic::Response<ModerateCommentReply>, tonic::Status> {
+        let ModerateCommentRequest { ids, status } = request.into_inner();
+        let status = comment_status(status).map_err(tonic::Status::invalid_argument)?;
+        let rows_affected = sqlx::query("UPDATE comments SET status=$1 WHERE id = ANY($2)")
+            .bind(status as i16)
+            .bind(&ids)
+            .execute(&*self.pool)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?
+            .rows_affected();
+        Ok(tonic::Response::new(ModerateCommentReply {
+            rows_affected: rows_affected as i64,
+        }))
+    }
+
+    async fn count_comment(
+        &self,
+        request: tonic::Request<CountCommentRequest>,
+    ) -> Result<tonic::Response<CountCommentReply>, tonic::Status> {
+        let CountCommentRequest { topic_ids } = request.into_inner();
+        if topic_ids.is_empty() {
+            return Ok(tonic::Response::new(CountCommentReply::default()));
+        }
+        let rows = sqlx::query(
+            r#"
+            SELECT topic_id, COUNT(*) AS comment_count
+            FROM comments
+            WHERE topic_id = ANY($1) AND status = $2
+            GROUP BY topic_id"#,
+        )
+        .bind(&topic_ids)
+        .bind(CommentStatus::Approved as i16)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let counts: HashMap<i64, i64> = rows
+            .iter()
+            .map(|row| (row.get("topic_id"), row.get("comment_count")))
+            .collect();
+        Ok(tonic::Response::new(CountCommentReply { counts }))
+    }
+}
+
+fn comment_status(status: i32) -> Result<CommentStatus, &'static str> {
+    CommentStatus::try_from(status).map_err(|_| "错误的评论状态")
+}
+
+fn check_author(author: &str) -> Result<&str, &'static str> {
+    let author = author.trim();
+    if author.is_empty() {
+        return Err("昵称不能为空");
+    }
+    if author.chars().count() > AUTHOR_MAX_LEN {
+        return Err("昵称过长");
+    }
+    Ok(author)
+}
+
+/// 邮箱可以不填
+fn check_email(email: &str) -> Result<&str, &'static str> {
+    let email = email.trim();
+    if email.chars().count() > EMAIL_MAX_LEN {
+        return Err("邮箱过长");
+    }
+    if !email.is_empty() {
+        match email.split_once('@') {
+            Some((name, domain)) if !name.is_empty() && domain.contains('.') => {}
+            _ => return Err("错误的邮箱"),
+        }
+    }
+    Ok(email)
+}
+
+fn check_content(content: &str) -> Result<&str, &'static str> {
+    let content = content.trim();
+    if content.is_empty() {
+        return Err("评论内容不能为空");
+    }
+    if content.chars().count() > CONTENT_MAX_LEN {
+        return Err("评论内容过长");
+    }
+    Ok(content)
+}
+
+fn comment_from_row(row: &PgRow) -> blog_proto::Comment {
+    let dt: DateTime<Local> = row.get("dateline");
+    let status: i16 = row.get("status");
+    blog_proto::Comment {
+        id: row.get("id"),
+        topic_id: row.get("topic_id"),
+        parent_id: row.get("parent_id"),
+        author: row.get("author"),
+        email: row.get("email"),
+        content: row.get("content"),
+        status: status.into(),
+        ip: row.get("ip"),
+        user_agent: row.get("user_agent"),
+        dateline: dt_conver(&dt),
+        replies: vec![],
+    }
+}
+
+fn dt_conver(dt: &DateTime<Local>) -> Option<prost_types::Timestamp> {
+    Some(prost_types::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_input() {
+        assert_eq!(check_author(" 张三 "), Ok("张三"));
+        assert!(check_author("  ").is_err());
+        assert!(check_author(&"长".repeat(AUTHOR_MAX_LEN + 1)).is_err());
+        assert_eq!(check_email(""), Ok(""));
+        assert_eq!(check_email("a@b.com"), Ok("a@b.com"));
+        assert!(check_email("a@b").is_err());
+        assert!(check_email("@b.com").is_err());
+        assert!(check_content("\n").is_err());
+        assert!(check_content(&"长".repeat(CONTENT_MAX_LEN)).is_ok());
+    }
+}
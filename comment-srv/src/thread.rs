@@ -0,0 +1,58 @@
+// 评论串：把回复挂到所回复的评论下面
+use std::collections::{HashMap, HashSet};
+
+use blog_proto::Comment;
+
+/// 按时间正序的评论组装成评论串。所回复的评论不在列表中（未通过审核或已删除）时，当作顶层评论显示
+pub fn build(comments: Vec<Comment>) -> Vec<Comment> {
+    let ids: HashSet<i64> = comments.iter().map(|c| c.id).collect();
+    let mut children: HashMap<Option<i64>, Vec<Comment>> = HashMap::new();
+    for c in comments {
+        let parent_id = c.parent_id.filter(|id| ids.contains(id));
+        children.entry(parent_id).or_default().push(c);
+    }
+    attach(&mut children, None)
+}
+
+fn attach(
+    children: &mut HashMap<Option<i64>, Vec<Comment>>,
+    parent_id: Option<i64>,
+) -> Vec<Comment> {
+    let mut list = children.remove(&parent_id).unwrap_or_default();
+    for c in &mut list {
+        c.replies = attach(children, Some(c.id));
+    }
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn comment(id: i64, parent_id: Option<i64>) -> Comment {
+        Comment {
+            id,
+            parent_id,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_build() {
+        let thread = build(vec![
+            comment(1, None),
+            comment(2, Some(1)),
+            comment(3, None),
+            comment(4, Some(2)),
+            comment(5, Some(1)),
+            // 所回复的评论不可见
+            comment(7, Some(6)),
+        ]);
+        let ids: Vec<i64> = thread.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![1, 3, 7]);
+        let replies: Vec<i64> = thread[0].replies.iter().map(|c| c.id).collect();
+        assert_eq!(replies, vec![2, 5]);
+        assert_eq!(thread[0].replies[0].replies[0].id, 4);
+        assert!(thread[1].replies.is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+// 识别上传文件的类型，为图片生成缩略图和 WebP 版本
+use std::io::Cursor;
+
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits};
+
+/// 缩略图的最大宽度和高度
+const THUMBNAIL_SIZE: u32 = 320;
+/// 图片的最大宽度和高度，防止解码时占用过多内存
+const MAX_DIMENSION: u32 = 10000;
+
+pub struct Processed {
+    pub mime: &'static str,
+    pub ext: &'static str,
+    pub image: Option<Variants>,
+}
+
+/// 图片的尺寸以及生成的文件
+pub struct Variants {
+    pub width: u32,
+    pub height: u32,
+    /// 与原图格式相同的缩略图
+    pub thumbnail: Vec<u8>,
+    /// 原图的 WebP 版本，原图就是 WebP 时为空
+    pub webp: Option<Vec<u8>>,
+    /// 缩略图的 WebP 版本，原图就是 WebP 时为空
+    pub thumbnail_webp: Option<Vec<u8>>,
+}
+
+pub fn process(data: &[u8]) -> Result<Processed, String> {
+    if let Ok(format) = image::guess_format(data) {
+        if let Some((mime, ext)) = image_type(format) {
+            let variants =
+                variants(data, format).map_err(|err| format!("无法处理的图片：{}", err))?;
+            return Ok(Processed {
+                mime,
+                ext,
+                image: Some(variants),
+            });
+        }
+    }
+    let (mime, ext) = attachment_type(data).ok_or("不支持的文件类型")?;
+    Ok(Processed {
+        mime,
+        ext,
+        image: None,
+    })
+}
+
+fn image_type(format: ImageFormat) -> Option<(&'static str, &'static str)> {
+    match format {
+        ImageFormat::Png => Some(("image/png", "png")),
+        ImageFormat::Jpeg => Some(("image/jpeg", "jpg")),
+        ImageFormat::Gif => Some(("image/gif", "gif")),
+        ImageFormat::WebP => Some(("image/webp", "webp")),
+        _ => None,
+    }
+}
+
+/// 允许上传的附件类型，根据文件头识别
+fn attachment_type(data: &[u8]) -> Option<(&'static str, &'static str)> {
+    if data.starts_with(b"%PDF-") {
+        Some(("application/pdf", "pdf"))
+    } else if data.starts_with(b"PK\x03\x04") {
+        Some(("application/zip", "zip"))
+    } else {
+        None
+    }
+}
+
+fn variants(data: &[u8], format: ImageFormat) -> Result<Variants, image::ImageError> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    let mut reader = ImageReader::with_format(Cursor::new(data), format);
+    reader.limits(limits);
+    let mut decoder = reader.into_decoder()?;
+    // 按照 EXIF 中的方向旋转照片
+    let orientation = decoder.orientation()?;
+    let mut img = DynamicImage::from_decoder(decoder)?;
+    img.apply_orientation(orientation);
+
+    let thumbnail = if img.width() > THUMBNAIL_SIZE || img.height() > THUMBNAIL_SIZE {
+        img.thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+    } else {
+        img.clone()
+    };
+    let (webp, thumbnail_webp) = if format == ImageFormat::WebP {
+        (None, None)
+    } else {
+        (
+            Some(encode(&img, ImageFormat::WebP)?),
+            Some(encode(&thumbnail, ImageFormat::WebP)?),
+        )
+    };
+    Ok(Variants {
+        width: img.width(),
+        height: img.height(),
+        thumbnail: encode(&thumbnail, format)?,
+        webp,
+        thumbnail_webp,
+    })
+}
+
+fn encode(img: &DynamicImage, format: ImageFormat) -> Result<Vec<u8>, image::ImageError> {
+    // JPEG 不支持透明通道，GIF 只支持 RGBA，其它格式统一为 8 位色深
+    let img = match format {
+        ImageFormat::Jpeg => DynamicImage::ImageRgb8(img.to_rgb8()),
+        ImageFormat::Gif => DynamicImage::ImageRgba8(img.to_rgba8()),
+        _ if img.color().has_alpha() => DynamicImage::ImageRgba8(img.to_rgba8()),
+        _ => DynamicImage::ImageRgb8(img.to_rgb8()),
+    };
+    let mut buf = Cursor::new(vec![]);
+    img.write_to(&mut buf, format)?;
+    Ok(buf.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let img = DynamicImage::new_rgba8(width, height);
+        encode(&img, ImageFormat::Png).unwrap()
+    }
+
+    #[test]
+    fn test_process_image() {
+        let p = process(&png(800, 400)).unwrap();
+        assert_eq!((p.mime, p.ext), ("image/png", "png"));
+        let v = p.image.unwrap();
+        assert_eq!((v.width, v.height), (800, 400));
+        let thumbnail = image::load_from_memory(&v.thumbnail).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (320, 160));
+        let webp = image::load_from_memory(&v.webp.unwrap()).unwrap();
+        assert_eq!((webp.width(), webp.height()), (800, 400));
+        let thumbnail_webp = v.thumbnail_webp.unwrap();
+        assert_eq!(
+            image::guess_format(&thumbnail_webp).unwrap(),
+            ImageFormat::WebP
+        );
+
+        // 小图不放大
+        let v = process(&png(100, 50)).unwrap().image.unwrap();
+        let thumbnail = image::load_from_memory(&v.thumbnail).unwrap();
+        assert_eq!((thumbnail.width(), thumbnail.height()), (100, 50));
+    }
+
+    #[test]
+    fn test_process_attachment() {
+        let p = process(b"%PDF-1.4\n").unwrap();
+        assert_eq!((p.mime, p.ext), ("application/pdf", "pdf"));
+        assert!(p.image.is_none());
+        assert!(process(b"<html></html>").is_err());
+        // 文件头是图片但内容损坏
+        assert!(process(b"\x89PNG\r\n\x1a\n").is_err());
+    }
+}
@@ -0,0 +1,264 @@
+use std::sync::Arc;
+
+use blog_proto::{
+    media_service_server::MediaService, GetMediaFileReply, GetMediaFileRequest, ListMediaReply,
+    ListMediaRequest, UploadMediaReply, UploadMediaRequest,
+};
+use chrono::{DateTime, Local};
+use sha2::{Digest, Sha256};
+use sqlx::{postgres::PgRow, PgPool, Row};
+
+use crate::{
+    process,
+    store::{self, Store},
+};
+
+/// 上传文件的最大字节数
+pub const MAX_SIZE: usize = 10 * 1024 * 1024;
+/// 媒体文件列表每页条数
+const PAGE_SIZE: i32 = 24;
+/// 文件名的最大长度（字符数），过长时截断
+const FILENAME_MAX_LEN: usize = 255;
+
+pub struct Media {
+    pool: Arc<PgPool>,
+    store: Arc<Store>,
+}
+
+impl Media {
+    pub fn new(pool: PgPool, store: Store) -> Self {
+        Self {
+            pool: Arc::new(pool),
+            store: Arc::new(store),
+        }
+    }
+
+    async fn find_by_hash(&self, hash: &str) -> Result<Option<PgRow>, tonic::Status> {
+        sqlx::query(
+            "SELECT id,hash,filename,mime,ext,size,width,height,uploaded_by,dateline FROM media WHERE hash=$1",
+        )
+        .bind(hash)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))
+    }
+}
+
+#[tonic::async_trait]
+impl MediaService for Media {
+    async fn upload_media(
+        &self,
+        request: tonic::Request<UploadMediaRequest>,
+    ) -> Result<tonic::Response<UploadMediaReply>, tonic::Status> {
+        let UploadMediaRequest {
+            filename,
+            data,
+            uploaded_by,
+        } = request.into_inner();
+        if data.is_empty() {
+            return Err(tonic::Status::invalid_argument("文件不能为空"));
+        }
+        if data.len() > MAX_SIZE {
+            return Err(tonic::Status::invalid_argument(format!(
+                "文件不能超过{}MB",
+                MAX_SIZE / 1024 / 1024
+            )));
+        }
+        let hash = hex::encode(Sha256::digest(&data));
+        if let Some(row) = self.find_by_hash(&hash).await? {
+            return Ok(tonic::Response::new(UploadMediaReply {
+                media: Some(media_from_row(&row)),
+                duplicated: true,
+            }));
+        }
+
+        // 解码和编码图片比较耗时，不占用异步运行时的线程
+        let (data, processed) = tokio::task::spawn_blocking(move || {
+            let processed = process::process(&data);
+            (data, processed)
+        })
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let processed = processed.map_err(tonic::Status::invalid_argument)?;
+
+        let mut files = vec![(store::file_name(&hash, false, processed.ext), &data)];
+        if let Some(v) = &processed.image {
+            files.push((store::file_name(&hash, true, processed.ext), &v.thumbnail));
+            if let Some(webp) = &v.webp {
+                files.push((store::file_name(&hash, false, "webp"), webp));
+            }
+            if let Some(webp) = &v.thumbnail_webp {
+                files.push((store::file_name(&hash, true, "webp"), webp));
+            }
+        }
+        for (name, data) in files {
+            self.store
+                .write(&name, data)
+                .await
+                .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        }
+
+        let filename = check_filename(&filename, &hash, processed.ext);
+        let (width, height) = match &processed.image {
+            Some(v) => (Some(v.width as i32), Some(v.height as i32)),
+            None => (None, None),
+        };
+        let row = sqlx::query(
+            r#"
+            INSERT INTO media (hash, filename, mime, ext, size, width, height, uploaded_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (hash) DO NOTHING
+            RETURNING id,hash,filename,mime,ext,size,width,height,uploaded_by,dateline"#,
+        )
+        .bind(&hash)
+        .bind(filename)
+        .bind(processed.mime)
+        .bind(processed.ext)
+        .bind(data.len() as i64)
+        .bind(width)
+        .bind(height)
+        .bind(uploaded_by)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        // 同时上传了相同的文件
+        let (row, duplicated) = match row {
+            Some(row) => (row, false),
+            None => match self.find_by_hash(&hash).await? {
+                Some(row) => (row, true),
+                None => return Err(tonic::Status::internal("保存媒体文件失败")),
+            },
+        };
+        Ok(tonic::Response::new(UploadMediaReply {
+            media: Some(media_from_row(&row)),
+            duplicated,
+        }))
+    }
+
+    async fn list_media(
+        &self,
+        request: tonic::Request<ListMediaRequest>,
+    ) -> Result<tonic::Response<ListMediaReply>, tonic::Status> {
+        let ListMediaRequest { page, is_image } = request.into_inner();
+        let page = page.unwrap_or(0).max(0);
+        let page_size = PAGE_SIZE;
+        let offset = page * page_size;
+
+        let row = sqlx::query(
+            "SELECT COUNT(*) FROM media WHERE ($1::boolean IS NULL OR (width IS NOT NULL) = $1::boolean)",
+        )
+        .bind(is_image)
+        .fetch_one(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let record_total: i64 = row.get(0);
+        let page_totoal = f64::ceil(record_total as f64 / page_size as f64) as i64;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT id,hash,filename,mime,ext,size,width,height,uploaded_by,dateline
+            FROM media
+            WHERE ($3::boolean IS NULL OR (width IS NOT NULL) = $3::boolean)
+            ORDER BY id DESC
+            LIMIT $1
+            OFFSET $2"#,
+        )
+        .bind(page_size)
+        .bind(offset)
+        .bind(is_image)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(ListMediaReply {
+            page,
+            page_size,
+            page_totoal,
+            record_total,
+            media: rows.iter().map(media_from_row).collect(),
+        }))
+    }
+
+    async fn get_media_file(
+        &self,
+        request: tonic::Request<GetMediaFileRequest>,
+    ) -> Result<tonic::Response<GetMediaFileReply>, tonic::Status> {
+        let GetMediaFileRequest { name } = request.into_inner();
+        let not_found = || tonic::Status::not_found("不存在的文件");
+        let (hash, thumbnail, ext) = store::parse_name(&name).ok_or_else(not_found)?;
+        let row = self.find_by_hash(hash).await?.ok_or_else(not_found)?;
+        let media = media_from_row(&row);
+        let is_image = media.width.is_some();
+        // 只提供实际生成了的文件
+        let mime = if ext == row.get::<String, _>("ext") && (is_image || !thumbnail) {
+            media.mime
+        } else if ext == "webp" && is_image {
+            "image/webp".to_string()
+        } else {
+            return Err(not_found());
+        };
+        let data = self
+            .store
+            .read(&name)
+            .await
+            .map_err(|err| match err.kind() {
+                std::io::ErrorKind::NotFound => not_found(),
+                _ => tonic::Status::internal(err.to_string()),
+            })?;
+        Ok(tonic::Response::new(GetMediaFileReply {
+            mime,
+            data,
+            filename: media.filename,
+        }))
+    }
+}
+
+/// 只保留文件名，去掉路径，没有文件名时使用存储中的文件名
+fn check_filename(filename: &str, hash: &str, ext: &str) -> String {
+    let filename = filename
+        .rsplit(['/', '\\'])
+        .next()
+        .unwrap_or_default()
+        .trim();
+    if filename.is_empty() {
+        return store::file_name(hash, false, ext);
+    }
+    filename.chars().take(FILENAME_MAX_LEN).collect()
+}
+
+fn media_from_row(row: &PgRow) -> blog_proto::Media {
+    let dt: DateTime<Local> = row.get("dateline");
+    let hash: String = row.get("hash");
+    let ext: String = row.get("ext");
+    let width: Option<i32> = row.get("width");
+    // 原图就是 WebP 时，WebP 版本就是原图和缩略图本身
+    let (thumbnail, webp, thumbnail_webp) = match width {
+        Some(_) => (
+            Some(store::file_name(&hash, true, &ext)),
+            Some(store::file_name(&hash, false, "webp")),
+            Some(store::file_name(&hash, true, "webp")),
+        ),
+        None => (None, None, None),
+    };
+    blog_proto::Media {
+        id: row.get("id"),
+        file: store::file_name(&hash, false, &ext),
+        hash,
+        filename: row.get("filename"),
+        mime: row.get("mime"),
+        size: row.get("size"),
+        width,
+        height: row.get("height"),
+        uploaded_by: row.get("uploaded_by"),
+        dateline: dt_conver(&dt),
+        thumbnail,
+        webp,
+        thumbnail_webp,
+    }
+}
+
+fn dt_conver(dt: &DateTime<Local>) -> Option<prost_types::Timestamp> {
+    Some(prost_types::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: 0,
+    })
+}
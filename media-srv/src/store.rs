@@ -0,0 +1,92 @@
+// 媒体文件在本地文件系统中的存储，按哈希的前两位分目录
+use std::{io, path::PathBuf};
+
+/// 缩略图文件名的后缀
+const THUMBNAIL_SUFFIX: &str = "_thumb";
+
+pub struct Store {
+    root: PathBuf,
+}
+
+impl Store {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.root.join(&name[..2]).join(name)
+    }
+
+    /// 写入文件。内容相同的文件名也相同，已存在时不再写入
+    pub async fn write(&self, name: &str, data: &[u8]) -> io::Result<()> {
+        let path = self.path(name);
+        if tokio::fs::try_exists(&path).await? {
+            return Ok(());
+        }
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await?;
+        }
+        // 先写入临时文件再改名，避免读到写了一半的文件
+        let tmp = path.with_file_name(format!("{}.tmp", name));
+        tokio::fs::write(&tmp, data).await?;
+        match tokio::fs::rename(&tmp, &path).await {
+            // 同时上传相同的文件时，另一个请求已经写入
+            Err(_) if tokio::fs::try_exists(&path).await? => Ok(()),
+            res => res,
+        }
+    }
+
+    pub async fn read(&self, name: &str) -> io::Result<Vec<u8>> {
+        tokio::fs::read(self.path(name)).await
+    }
+}
+
+/// 存储中的文件名：{hash}.{ext}，缩略图为 {hash}_thumb.{ext}
+pub fn file_name(hash: &str, thumbnail: bool, ext: &str) -> String {
+    let suffix = if thumbnail { THUMBNAIL_SUFFIX } else { "" };
+    format!("{}{}.{}", hash, suffix, ext)
+}
+
+/// 解析存储中的文件名，返回哈希、是否缩略图以及扩展名。
+/// 只接受 file_name 生成的文件名，避免读取存储目录之外的文件
+pub fn parse_name(name: &str) -> Option<(&str, bool, &str)> {
+    let (stem, ext) = name.split_once('.')?;
+    let (hash, thumbnail) = match stem.strip_suffix(THUMBNAIL_SUFFIX) {
+        Some(hash) => (hash, true),
+        None => (stem, false),
+    };
+    let is_hash = hash.len() == 64
+        && hash
+            .chars()
+            .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+    let is_ext =
+        !ext.is_empty() && ext.len() <= 10 && ext.chars().all(|c| c.is_ascii_alphanumeric());
+    if is_hash && is_ext {
+        Some((hash, thumbnail, ext))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_name() {
+        let hash = "ab".repeat(32);
+        assert_eq!(
+            parse_name(&file_name(&hash, false, "png")),
+            Some((hash.as_str(), false, "png"))
+        );
+        assert_eq!(
+            parse_name(&file_name(&hash, true, "webp")),
+            Some((hash.as_str(), true, "webp"))
+        );
+        assert_eq!(parse_name(&format!("{}.", hash)), None);
+        assert_eq!(parse_name(&format!("{}.png", &hash[1..])), None);
+        assert_eq!(parse_name(&format!("{}.png", hash.to_uppercase())), None);
+        assert_eq!(parse_name(&format!("{}.p/g", hash)), None);
+        assert_eq!(parse_name("../../etc/passwd"), None);
+    }
+}
@@ -1,9 +1,19 @@
+-- 相关文章使用标题的三元组相似度
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
 -- 分类
 CREATE TABLE categories  (
   id SERIAL PRIMARY KEY,
   name VARCHAR(100) NOT NULL,
-  is_del BOOLEAN NOT NULL DEFAULT FALSE
+  is_del BOOLEAN NOT NULL DEFAULT FALSE,
+  deleted_at TIMESTAMP WITH TIME ZONE, -- 删除时间，恢复时清空
+  deleted_by INT, -- 执行删除的管理员
+  version INT NOT NULL DEFAULT 1, -- 版本号，每次修改加1，用于检测并发修改
+  parent_id INT, -- 上级分类，顶级分类为空
+  sort_weight INT NOT NULL DEFAULT 0 -- 排序权重，越小越靠前
 );
+CREATE INDEX idx_categories_deleted_at ON categories (deleted_at) WHERE is_del;
+CREATE INDEX idx_categories_parent_id ON categories (parent_id);
 
 -- 文章
 CREATE TABLE topics (
@@ -12,9 +22,120 @@ CREATE TABLE topics (
   category_id INT NOT NULL,
   summary VARCHAR(255) NOT NULL,
   content VARCHAR NOT NULL,
+  content_html VARCHAR NOT NULL DEFAULT '',
   hit INT NOT NULL DEFAULT 0,
   dateline TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP,
-  is_del BOOLEAN NOT NULL DEFAULT FALSE
+  is_del BOOLEAN NOT NULL DEFAULT FALSE,
+  search_vector TSVECTOR NOT NULL DEFAULT '',
+  status SMALLINT NOT NULL DEFAULT 1, -- 0:草稿 1:已发布 2:定时发布
+  publish_at TIMESTAMP WITH TIME ZONE, -- 定时发布的时间
+  slug VARCHAR(255) NOT NULL UNIQUE, -- 永久链接
+  deleted_at TIMESTAMP WITH TIME ZONE, -- 删除时间，恢复时清空
+  deleted_by INT, -- 执行删除的管理员
+  author_id INT, -- 作者，创建文章的管理员
+  last_edited_by INT, -- 最后修改文章的管理员
+  word_count INT NOT NULL DEFAULT 0, -- 字数，中日文按字、其它文字按词计算
+  reading_time INT NOT NULL DEFAULT 0, -- 预计阅读时间（分钟）
+  pin_scope SMALLINT NOT NULL DEFAULT 0, -- 置顶范围 0:不置顶 1:全站置顶 2:分类置顶
+  pin_order INT NOT NULL DEFAULT 0, -- 置顶顺序，越小越靠前
+  is_featured BOOLEAN NOT NULL DEFAULT FALSE, -- 是否推荐
+  version INT NOT NULL DEFAULT 1 -- 版本号，每次修改加1，用于检测并发修改
+);
+-- 全文检索：search_vector 由 topic-srv 分词后写入，标题/摘要/内容的权重依次为 A/B/C。
+-- 为空的文章（例如增加该字段前的文章）由 topic-srv 启动时补建
+CREATE INDEX idx_topics_search_vector ON topics USING GIN (search_vector);
+CREATE INDEX idx_topics_scheduled ON topics (publish_at) WHERE status = 2;
+-- 文章列表的游标翻页
+CREATE INDEX idx_topics_dateline ON topics (dateline DESC, id DESC);
+CREATE INDEX idx_topics_hit ON topics (hit DESC, id DESC);
+CREATE INDEX idx_topics_title ON topics (title, id);
+CREATE INDEX idx_topics_word_count ON topics (word_count DESC, id DESC);
+-- 回收站按删除时间清理
+CREATE INDEX idx_topics_deleted_at ON topics (deleted_at) WHERE is_del;
+-- 置顶和推荐的文章
+CREATE INDEX idx_topics_pinned ON topics (pin_scope, pin_order) WHERE pin_scope <> 0;
+CREATE INDEX idx_topics_featured ON topics (dateline DESC) WHERE is_featured;
+-- 作者的文章列表
+CREATE INDEX idx_topics_author_id ON topics (author_id, dateline DESC);
+-- 按分类统计已发布的文章数和最新的发布时间
+CREATE INDEX idx_topics_category_published ON topics (category_id, dateline) WHERE is_del = false AND status = 1;
+
+-- 文章修改前使用过的永久链接，访问时跳转到新的链接
+CREATE TABLE topic_slugs (
+  slug VARCHAR(255) PRIMARY KEY,
+  topic_id BIGINT NOT NULL
+);
+
+-- 文章修订历史，每次创建和修改文章时写入
+CREATE TABLE topic_revisions (
+  id BIGSERIAL PRIMARY KEY,
+  topic_id BIGINT NOT NULL,
+  title VARCHAR(255) NOT NULL,
+  category_id INT NOT NULL,
+  summary VARCHAR(255) NOT NULL,
+  content VARCHAR NOT NULL,
+  dateline TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX idx_topic_revisions_topic_id ON topic_revisions (topic_id);
+
+-- 标签
+CREATE TABLE tags (
+  id SERIAL PRIMARY KEY,
+  name VARCHAR(50) NOT NULL UNIQUE
+);
+
+-- 文章与标签的对应关系
+CREATE TABLE topic_tags (
+  topic_id BIGINT NOT NULL,
+  tag_id INT NOT NULL,
+  PRIMARY KEY (topic_id, tag_id)
+);
+CREATE INDEX idx_topic_tags_tag_id ON topic_tags (tag_id);
+
+-- 系列，例如分为多篇的教程
+CREATE TABLE series (
+  id SERIAL PRIMARY KEY,
+  title VARCHAR(255) NOT NULL,
+  description VARCHAR(1000) NOT NULL DEFAULT '',
+  dateline TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- 系列中的文章，一篇文章最多属于一个系列
+CREATE TABLE series_topics (
+  topic_id BIGINT PRIMARY KEY,
+  series_id INT NOT NULL,
+  position INT NOT NULL -- 在系列中的顺序，从1开始
+);
+CREATE INDEX idx_series_topics_series_id ON series_topics (series_id, position);
+
+-- 评论，由 comment-srv 管理
+CREATE TABLE comments (
+  id BIGSERIAL PRIMARY KEY,
+  topic_id BIGINT NOT NULL,
+  parent_id BIGINT, -- 回复的评论
+  author VARCHAR(50) NOT NULL,
+  email VARCHAR(255) NOT NULL DEFAULT '',
+  content VARCHAR(2000) NOT NULL,
+  status SMALLINT NOT NULL DEFAULT 0, -- 0:待审核 1:已通过 2:垃圾评论 3:已删除
+  ip VARCHAR(45) NOT NULL DEFAULT '',
+  user_agent VARCHAR(255) NOT NULL DEFAULT '',
+  dateline TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX idx_comments_topic_id ON comments (topic_id, status);
+CREATE INDEX idx_comments_status ON comments (status, id DESC);
+
+-- 媒体文件，由 media-srv 管理。文件按内容的 SHA-256 去重后保存在本地文件系统中
+CREATE TABLE media (
+  id BIGSERIAL PRIMARY KEY,
+  hash CHAR(64) NOT NULL UNIQUE, -- 文件内容的 SHA-256
+  filename VARCHAR(255) NOT NULL, -- 上传时的文件名
+  mime VARCHAR(100) NOT NULL,
+  ext VARCHAR(10) NOT NULL, -- 存储中的扩展名
+  size BIGINT NOT NULL,
+  width INT, -- 图片的尺寸，附件为空
+  height INT,
+  uploaded_by INT, -- 上传的管理员
+  dateline TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT CURRENT_TIMESTAMP
 );
 
 -- 管理员
@@ -22,5 +143,10 @@ CREATE TABLE admins (
   id SERIAL PRIMARY KEY,
   email VARCHAR(255) NOT NULL,
   password VARCHAR(255) NOT NULL,
-  is_del BOOLEAN NOT NULL DEFAULT FALSE
+  is_del BOOLEAN NOT NULL DEFAULT FALSE,
+  deleted_at TIMESTAMP WITH TIME ZONE, -- 删除时间，恢复时清空
+  deleted_by INT, -- 执行删除的管理员
+  display_name VARCHAR(100) NOT NULL DEFAULT '', -- 作者页面显示的名称
+  avatar VARCHAR(255) NOT NULL DEFAULT '', -- 头像地址
+  bio VARCHAR(1000) NOT NULL DEFAULT '' -- 个人简介
 );
\ No newline at end of file
@@ -0,0 +1,199 @@
+// 批量删除、恢复、移动和彻底删除文章
+use std::collections::HashMap;
+
+use blog_proto::{BulkTopicReply, BulkTopicResult};
+use sqlx::{PgPool, Postgres, Row, Transaction};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Action {
+    /// 放入回收站，记录执行删除的管理员
+    Delete(Option<i32>),
+    Restore,
+    /// 移动到指定的分类
+    Move(i32),
+    /// 从回收站中彻底删除
+    Purge,
+}
+
+/// 文章当前的状态：是否删除、分类
+type TopicState = (bool, i32);
+
+/// 在同一个事务中锁定并修改所有文章，任何一步出错都不会有修改
+pub async fn apply(
+    pool: &PgPool,
+    ids: &[i64],
+    action: Action,
+) -> Result<BulkTopicReply, tonic::Status> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    if let Action::Move(category_id) = action {
+        let exists = sqlx::query("SELECT id FROM categories WHERE id=$1 AND is_del=false")
+            .bind(category_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        if exists.is_none() {
+            return Err(tonic::Status::invalid_argument("不存在的分类"));
+        }
+    }
+    let rows =
+        sqlx::query("SELECT id,is_del,category_id FROM topics WHERE id = ANY($1) FOR UPDATE")
+            .bind(ids)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    let topics: HashMap<i64, TopicState> = rows
+        .iter()
+        .map(|row| (row.get("id"), (row.get("is_del"), row.get("category_id"))))
+        .collect();
+    let results = plan(ids, &topics, action);
+    let changed_ids: Vec<i64> = results.iter().filter(|r| r.changed).map(|r| r.id).collect();
+    if !changed_ids.is_empty() {
+        match action {
+            Action::Purge => purge(&mut tx, &changed_ids).await?,
+            _ => {
+                let query = match action {
+                    Action::Delete(_) => sqlx::query(
+                        "UPDATE topics SET is_del=true,deleted_at=CURRENT_TIMESTAMP,deleted_by=$2,version=version+1 WHERE id = ANY($1)",
+                    ),
+                    Action::Restore => sqlx::query(
+                        "UPDATE topics SET is_del=false,deleted_at=NULL,deleted_by=NULL,version=version+1 WHERE id = ANY($1)",
+                    ),
+                    _ => sqlx::query(
+                        "UPDATE topics SET category_id=$2,version=version+1 WHERE id = ANY($1)",
+                    ),
+                }
+                .bind(&changed_ids);
+                let query = match action {
+                    Action::Delete(admin_id) => query.bind(admin_id),
+                    Action::Move(category_id) => query.bind(category_id),
+                    _ => query,
+                };
+                query
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            }
+        }
+    }
+    tx.commit()
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok(BulkTopicReply {
+        changed: changed_ids.len() as i32,
+        results,
+    })
+}
+
+/// 删除文章以及属于文章的标签关系、系列关系、旧链接、修订和评论
+async fn purge(tx: &mut Transaction<'_, Postgres>, ids: &[i64]) -> Result<(), tonic::Status> {
+    for sql in [
+        "DELETE FROM topic_tags WHERE topic_id = ANY($1)",
+        "DELETE FROM series_topics WHERE topic_id = ANY($1)",
+        "DELETE FROM topic_slugs WHERE topic_id = ANY($1)",
+        "DELETE FROM topic_revisions WHERE topic_id = ANY($1)",
+        "DELETE FROM comments WHERE topic_id = ANY($1)",
+        "DELETE FROM topics WHERE id = ANY($1)",
+    ] {
+        sqlx::query(sql)
+            .bind(ids)
+            .execute(&mut **tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    }
+    Ok(())
+}
+
+/// 根据文章当前的状态得出每个ID的处理结果，重复的ID只处理一次
+fn plan(ids: &[i64], topics: &HashMap<i64, TopicState>, action: Action) -> Vec<BulkTopicResult> {
+    let mut results: Vec<BulkTopicResult> = Vec::with_capacity(ids.len());
+    for &id in ids {
+        if results.iter().any(|r| r.id == id) {
+            continue;
+        }
+        let result = match topics.get(&id) {
+            None => BulkTopicResult {
+                id,
+                ok: false,
+                changed: false,
+                message: "不存在的文章".to_string(),
+            },
+            // 只能彻底删除回收站中的文章
+            Some(&(false, _)) if matches!(action, Action::Purge) => BulkTopicResult {
+                id,
+                ok: false,
+                changed: false,
+                message: "文章不在回收站中".to_string(),
+            },
+            Some(&(is_del, category_id)) => {
+                let changed = match action {
+                    Action::Delete(_) => !is_del,
+                    Action::Restore | Action::Purge => is_del,
+                    Action::Move(to) => category_id != to,
+                };
+                BulkTopicResult {
+                    id,
+                    ok: true,
+                    changed,
+                    message: String::new(),
+                }
+            }
+        };
+        results.push(result);
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan() {
+        let topics = HashMap::from([(1, (false, 1)), (2, (true, 1)), (3, (false, 2))]);
+        let changed = |action| -> Vec<(i64, bool, bool)> {
+            plan(&[1, 2, 3, 4, 1], &topics, action)
+                .iter()
+                .map(|r| (r.id, r.ok, r.changed))
+                .collect()
+        };
+        assert_eq!(
+            changed(Action::Delete(Some(1))),
+            vec![
+                (1, true, true),
+                (2, true, false),
+                (3, true, true),
+                (4, false, false)
+            ]
+        );
+        assert_eq!(
+            changed(Action::Restore),
+            vec![
+                (1, true, false),
+                (2, true, true),
+                (3, true, false),
+                (4, false, false)
+            ]
+        );
+        assert_eq!(
+            changed(Action::Move(2)),
+            vec![
+                (1, true, true),
+                (2, true, true),
+                (3, true, false),
+                (4, false, false)
+            ]
+        );
+        assert_eq!(
+            changed(Action::Purge),
+            vec![
+                (1, false, false),
+                (2, true, true),
+                (3, false, false),
+                (4, false, false)
+            ]
+        );
+    }
+}
@@ -0,0 +1,70 @@
+// 文章列表的游标翻页
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use blog_proto::TopicSort;
+
+/// 翻页方向
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Next,
+    Prev,
+}
+
+/// 游标记录了当前页第一条或最后一条记录的排序字段和ID，对调用方是不透明的字符串
+#[derive(Debug, PartialEq)]
+pub struct Cursor {
+    pub direction: Direction,
+    pub sort: TopicSort,
+    pub id: i64,
+    /// 排序字段的值，由数据库转换成文本
+    pub key: String,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        let direction = match self.direction {
+            Direction::Next => 'n',
+            Direction::Prev => 'p',
+        };
+        let raw = format!(
+            "{}:{}:{}:{}",
+            direction, self.sort as i32, self.id, self.key
+        );
+        URL_SAFE_NO_PAD.encode(raw)
+    }
+
+    pub fn decode(s: &str) -> Option<Self> {
+        let raw = String::from_utf8(URL_SAFE_NO_PAD.decode(s).ok()?).ok()?;
+        let mut parts = raw.splitn(4, ':');
+        let direction = match parts.next()? {
+            "n" => Direction::Next,
+            "p" => Direction::Prev,
+            _ => return None,
+        };
+        let sort = TopicSort::try_from(parts.next()?.parse::<i32>().ok()?).ok()?;
+        let id = parts.next()?.parse().ok()?;
+        let key = parts.next()?.to_string();
+        Some(Self {
+            direction,
+            sort,
+            id,
+            key,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let cursor = Cursor {
+            direction: Direction::Prev,
+            sort: TopicSort::Title,
+            id: 42,
+            key: "a:b 标题".to_string(),
+        };
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor));
+        assert_eq!(Cursor::decode("not a cursor"), None);
+    }
+}
@@ -0,0 +1,40 @@
+// 修订对比：按行计算差异
+use blog_proto::{diff_line::Tag, DiffLine};
+use similar::{ChangeTag, TextDiff};
+
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    // 统一结尾的换行，避免最后一行仅因缺少换行符而被视为修改
+    let old = format!("{}\n", old.trim_end_matches(['\r', '\n']));
+    let new = format!("{}\n", new.trim_end_matches(['\r', '\n']));
+    TextDiff::from_lines(&old, &new)
+        .iter_all_changes()
+        .map(|change| {
+            let tag = match change.tag() {
+                ChangeTag::Equal => Tag::Equal,
+                ChangeTag::Delete => Tag::Delete,
+                ChangeTag::Insert => Tag::Insert,
+            };
+            DiffLine {
+                tag: tag.into(),
+                text: change.value().trim_end_matches(['\r', '\n']).to_string(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        let lines = diff_lines("第一行\n第二行\n第三行", "第一行\n第2行\n第三行");
+        let tags: Vec<Tag> = lines.iter().map(|l| l.tag()).collect();
+        assert_eq!(
+            tags,
+            vec![Tag::Equal, Tag::Delete, Tag::Insert, Tag::Equal]
+        );
+        assert_eq!(lines[1].text, "第二行");
+        assert_eq!(lines[2].text, "第2行");
+    }
+}
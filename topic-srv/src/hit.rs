@@ -0,0 +1,166 @@
+// 点击量：先在内存中累计，再定期批量写入数据库
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use sqlx::PgPool;
+
+/// 爬虫 User-Agent 中常见的关键字（小写）
+const BOT_KEYWORDS: &[&str] = &[
+    "bot",
+    "spider",
+    "crawl",
+    "slurp",
+    "bingpreview",
+    "facebookexternalhit",
+    "headless",
+    "curl",
+    "wget",
+    "python-requests",
+    "go-http-client",
+];
+
+/// 是否为爬虫。空的 User-Agent 也当作爬虫处理
+pub fn is_bot(user_agent: &str) -> bool {
+    let user_agent = user_agent.trim().to_lowercase();
+    user_agent.is_empty() || BOT_KEYWORDS.iter().any(|k| user_agent.contains(k))
+}
+
+pub struct HitCounter {
+    /// 同一访客在这段时间内重复访问同一篇文章只计一次
+    window: Duration,
+    inner: Mutex<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// 尚未写入数据库的点击量
+    pending: HashMap<i64, i32>,
+    /// 访客最近一次被计数的时间
+    seen: HashMap<(i64, String), Instant>,
+}
+
+impl HitCounter {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            inner: Mutex::new(Inner::default()),
+        }
+    }
+
+    /// 记录一次访问，返回是否被计数
+    pub fn hit(&self, topic_id: i64, visitor_id: Option<&str>, user_agent: Option<&str>) -> bool {
+        if user_agent.is_some_and(is_bot) {
+            return false;
+        }
+        let now = Instant::now();
+        let mut inner = self.inner.lock().unwrap();
+        if let Some(visitor_id) = visitor_id.filter(|v| !v.is_empty()) {
+            let key = (topic_id, visitor_id.to_string());
+            match inner.seen.get(&key) {
+                Some(last) if now.duration_since(*last) < self.window => return false,
+                _ => {
+                    inner.seen.insert(key, now);
+                }
+            }
+        }
+        *inner.pending.entry(topic_id).or_default() += 1;
+        true
+    }
+
+    /// 尚未写入数据库的点击量
+    pub fn pending(&self, topic_id: i64) -> i32 {
+        let inner = self.inner.lock().unwrap();
+        inner.pending.get(&topic_id).copied().unwrap_or_default()
+    }
+
+    /// 取出尚未写入的点击量，同时清理过期的访客记录
+    fn take(&self) -> HashMap<i64, i32> {
+        let now = Instant::now();
+        let mut inner = self.inner.lock().unwrap();
+        let window = self.window;
+        inner
+            .seen
+            .retain(|_, last| now.duration_since(*last) < window);
+        std::mem::take(&mut inner.pending)
+    }
+
+    /// 写入失败时把点击量放回去，下次再写
+    fn restore(&self, hits: HashMap<i64, i32>) {
+        let mut inner = self.inner.lock().unwrap();
+        for (topic_id, n) in hits {
+            *inner.pending.entry(topic_id).or_default() += n;
+        }
+    }
+
+    /// 将累计的点击量批量写入数据库
+    pub async fn flush(&self, pool: &PgPool) -> Result<usize, sqlx::Error> {
+        let hits = self.take();
+        if hits.is_empty() {
+            return Ok(0);
+        }
+        let (ids, counts): (Vec<i64>, Vec<i32>) = hits.iter().map(|(k, v)| (*k, *v)).unzip();
+        let result = sqlx::query(
+            r#"
+            UPDATE topics SET hit = topics.hit + v.n
+            FROM (SELECT UNNEST($1::bigint[]) AS id, UNNEST($2::int[]) AS n) AS v
+            WHERE topics.id = v.id"#,
+        )
+        .bind(&ids)
+        .bind(&counts)
+        .execute(pool)
+        .await;
+        match result {
+            Ok(_) => Ok(hits.len()),
+            Err(err) => {
+                self.restore(hits);
+                Err(err)
+            }
+        }
+    }
+}
+
+pub async fn run(hits: Arc<HitCounter>, pool: PgPool, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        if let Err(err) = hits.flush(&pool).await {
+            eprintln!("flush hits failed: {}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_bot() {
+        assert!(is_bot("Mozilla/5.0 (compatible; Googlebot/2.1)"));
+        assert!(is_bot("Baiduspider"));
+        assert!(is_bot(""));
+        assert!(!is_bot(
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 Chrome/120.0 Safari/537.36"
+        ));
+    }
+
+    #[test]
+    fn test_hit_dedup() {
+        let hits = HitCounter::new(Duration::from_secs(60));
+        assert!(hits.hit(1, Some("a"), Some("Mozilla/5.0")));
+        assert!(!hits.hit(1, Some("a"), Some("Mozilla/5.0")));
+        assert!(hits.hit(1, Some("b"), Some("Mozilla/5.0")));
+        assert!(hits.hit(2, Some("a"), Some("Mozilla/5.0")));
+        assert!(!hits.hit(2, Some("c"), Some("Googlebot")));
+        assert!(hits.hit(3, None, None));
+        assert_eq!(hits.pending(1), 2);
+
+        let taken = hits.take();
+        assert_eq!(taken.get(&1), Some(&2));
+        assert_eq!(hits.pending(1), 0);
+        // 窗口内的访客记录不受写入影响
+        assert!(!hits.hit(1, Some("a"), Some("Mozilla/5.0")));
+    }
+}
@@ -0,0 +1,145 @@
+// Markdown 渲染：GFM 表格、脚注、围栏代码、标题锚点，输出经过净化的 HTML
+use std::{collections::HashMap, sync::LazyLock};
+
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag, TagEnd};
+
+static SANITIZER: LazyLock<ammonia::Builder<'static>> = LazyLock::new(|| {
+    let mut builder = ammonia::Builder::default();
+    for tag in ["h1", "h2", "h3", "h4", "h5", "h6"] {
+        builder.add_tag_attributes(tag, ["id"]);
+    }
+    builder
+        .add_tag_attributes("div", ["id"])
+        .add_tag_attributes("code", ["class"])
+        .add_allowed_classes("div", ["footnote-definition"])
+        .add_allowed_classes("sup", ["footnote-reference", "footnote-definition-label"])
+        .attribute_filter(|element, attribute, value| {
+            // 代码块只保留 language-xxx 形式的 class，用于前端语法高亮
+            if element == "code" && attribute == "class" {
+                let lang = value.strip_prefix("language-")?;
+                if lang.is_empty()
+                    || !lang
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "+-_#.".contains(c))
+                {
+                    return None;
+                }
+            }
+            Some(value.into())
+        });
+    builder
+});
+
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_FOOTNOTES | Options::ENABLE_STRIKETHROUGH
+}
+
+/// 将 Markdown 渲染为净化后的 HTML
+pub fn render(source: &str) -> String {
+    let mut events = Vec::new();
+    let mut heading: Option<Vec<Event>> = None;
+    let mut anchors: HashMap<String, usize> = HashMap::new();
+
+    for event in Parser::new_ext(source, options()) {
+        match event {
+            Event::Start(Tag::Heading { .. }) => heading = Some(vec![event]),
+            Event::End(TagEnd::Heading(_)) => {
+                let mut buf = heading.take().unwrap_or_default();
+                let text: String = buf
+                    .iter()
+                    .filter_map(|e| match e {
+                        Event::Text(t) | Event::Code(t) => Some(t.as_ref()),
+                        _ => None,
+                    })
+                    .collect();
+                if let Some(Event::Start(Tag::Heading { id, .. })) = buf.first_mut() {
+                    if id.is_none() {
+                        *id = Some(CowStr::from(unique_anchor(&text, &mut anchors)));
+                    }
+                }
+                events.append(&mut buf);
+                events.push(event);
+            }
+            _ => match heading.as_mut() {
+                Some(buf) => buf.push(event),
+                None => events.push(event),
+            },
+        }
+    }
+
+    let mut out = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut out, events.into_iter());
+    SANITIZER.clean(&out).to_string()
+}
+
+/// 提取 Markdown 的纯文本，去掉标记和内嵌的 HTML，用于摘要和全文检索
+pub fn to_plain_text(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    for event in Parser::new_ext(source, options()) {
+        match event {
+            Event::Text(t) | Event::Code(t) => out.push_str(&t),
+            Event::SoftBreak | Event::HardBreak => out.push(' '),
+            Event::End(
+                TagEnd::Paragraph
+                | TagEnd::Heading(_)
+                | TagEnd::Item
+                | TagEnd::CodeBlock
+                | TagEnd::TableRow
+                | TagEnd::FootnoteDefinition,
+            ) => out.push('\n'),
+            Event::End(TagEnd::TableCell) => out.push(' '),
+            _ => {}
+        }
+    }
+    out.trim().to_string()
+}
+
+/// 根据标题文本生成锚点，重复的锚点追加序号
+fn unique_anchor(text: &str, anchors: &mut HashMap<String, usize>) -> String {
+    let mut anchor = String::with_capacity(text.len());
+    for c in text.trim().chars() {
+        if c.is_alphanumeric() {
+            anchor.extend(c.to_lowercase());
+        } else if (c.is_whitespace() || c == '-' || c == '_') && !anchor.ends_with('-') {
+            anchor.push('-');
+        }
+    }
+    let anchor = anchor.trim_matches('-');
+    let anchor = if anchor.is_empty() { "section" } else { anchor };
+    let count = anchors.entry(anchor.to_string()).or_insert(0);
+    *count += 1;
+    if *count == 1 {
+        anchor.to_string()
+    } else {
+        format!("{}-{}", anchor, *count - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_gfm() {
+        let html = render("# 简介\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n```rust\nfn main() {}\n```\n\n脚注[^1]\n\n[^1]: 说明\n\n## 简介\n");
+        assert!(html.contains(r#"<h1 id="简介">"#));
+        assert!(html.contains(r#"<h2 id="简介-1">"#));
+        assert!(html.contains("<table>"));
+        assert!(html.contains(r#"<code class="language-rust">"#));
+        assert!(html.contains(r#"class="footnote-reference""#));
+    }
+
+    #[test]
+    fn test_render_sanitize() {
+        let html = render("<script>alert(1)</script>\n\n[x](javascript:alert(1)) <img src=x onerror=alert(1)>");
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("javascript:"));
+        assert!(!html.contains("onerror"));
+    }
+
+    #[test]
+    fn test_to_plain_text() {
+        let text = to_plain_text("## 标题\n\n这是**加粗**和`代码`，<b>HTML</b>。\n\n- 列表");
+        assert_eq!(text, "标题\n这是加粗和代码，HTML。\n列表");
+    }
+}
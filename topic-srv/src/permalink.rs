@@ -0,0 +1,122 @@
+// 永久链接：根据标题生成 slug，中文标题转换为拼音
+use sqlx::{Postgres, Row, Transaction};
+
+/// slug 的最大长度
+const MAX_LEN: usize = 200;
+
+/// 将文本转换为 slug，中文转换为不带声调的拼音，其它字符转换为最接近的 ASCII 字符
+pub fn slugify(text: &str) -> String {
+    // 汉字前后加上空格，避免拼音和相邻的英文单词连在一起
+    let text: String = text
+        .chars()
+        .map(|c| {
+            if is_cjk(c) {
+                format!(" {} ", c)
+            } else {
+                c.to_string()
+            }
+        })
+        .collect();
+    // slug::slugify 的结果只包含 ASCII 字符，可以直接按字节截断
+    let mut slug = slug::slugify(text);
+    if slug.len() > MAX_LEN {
+        slug.truncate(MAX_LEN);
+        slug.truncate(slug.trim_end_matches('-').len());
+    }
+    if slug.is_empty() {
+        slug.push_str("topic");
+    }
+    slug
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c, '\u{3400}'..='\u{9FFF}' | '\u{F900}'..='\u{FAFF}' | '\u{20000}'..='\u{2FA1F}')
+}
+
+/// 生成未被其它文章占用（包括其它文章的旧链接）的 slug，重复时依次追加 -2、-3……
+pub async fn unique_slug(
+    tx: &mut Transaction<'_, Postgres>,
+    text: &str,
+    topic_id: Option<i64>,
+) -> Result<String, tonic::Status> {
+    let base = slugify(text);
+    let mut slug = base.clone();
+    for n in 2.. {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                EXISTS(SELECT 1 FROM topics WHERE slug=$1 AND ($2::bigint IS NULL OR id<>$2::bigint))
+                OR
+                EXISTS(SELECT 1 FROM topic_slugs WHERE slug=$1 AND ($2::bigint IS NULL OR topic_id<>$2::bigint))"#,
+        )
+        .bind(&slug)
+        .bind(topic_id)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let taken: bool = row.get(0);
+        if !taken {
+            break;
+        }
+        slug = format!("{}-{}", base, n);
+    }
+    Ok(slug)
+}
+
+/// 修改文章的 slug，旧的 slug 保存下来以便跳转
+pub async fn change_slug(
+    tx: &mut Transaction<'_, Postgres>,
+    topic_id: i64,
+    text: &str,
+) -> Result<(), tonic::Status> {
+    let slug = unique_slug(tx, text, Some(topic_id)).await?;
+    let row = sqlx::query("SELECT slug FROM topics WHERE id=$1 FOR UPDATE")
+        .bind(topic_id)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    let old_slug: String = match row {
+        Some(row) => row.get("slug"),
+        None => return Err(tonic::Status::not_found("不存在的文章")),
+    };
+    if old_slug == slug {
+        return Ok(());
+    }
+    sqlx::query(
+        "INSERT INTO topic_slugs (slug, topic_id) VALUES ($1, $2) ON CONFLICT (slug) DO UPDATE SET topic_id=EXCLUDED.topic_id",
+    )
+    .bind(&old_slug)
+    .bind(topic_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    // 重新使用自己以前的 slug
+    sqlx::query("DELETE FROM topic_slugs WHERE slug=$1")
+        .bind(&slug)
+        .execute(&mut **tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    sqlx::query("UPDATE topics SET slug=$1 WHERE id=$2")
+        .bind(&slug)
+        .bind(topic_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Hello, World!"), "hello-world");
+        assert_eq!(slugify("Rust 入门"), "rust-ru-men");
+        assert_eq!(slugify("使用axum构建"), "shi-yong-axum-gou-jian");
+        assert_eq!(slugify("Café 指南"), "cafe-zhi-nan");
+        assert_eq!(slugify("？？"), "topic");
+        let long = slugify(&"长".repeat(100));
+        assert!(long.len() <= MAX_LEN && !long.ends_with('-'));
+    }
+}
@@ -0,0 +1,114 @@
+// 置顶和推荐文章
+use blog_proto::{PinScope, TopicStatus};
+use sqlx::PgPool;
+
+use crate::server::topic_from_row;
+
+/// 文章在列表中是否置顶：全站置顶，或者按所属分类筛选时的分类置顶。
+/// $3 为筛选的分类，包括下级分类时也包括所有下级分类，因此置顶在下级分类的文章同样置顶
+pub const PINNED_CONDITION: &str =
+    "(pin_scope = 1 OR (pin_scope = 2 AND COALESCE(category_id = ANY($3::int[]), FALSE)))";
+/// 默认的推荐文章条数
+const FEATURED_LIMIT: i32 = 5;
+/// 推荐文章条数的上限
+const MAX_FEATURED_LIMIT: i32 = 20;
+
+/// 置顶或取消置顶，新置顶的文章排在最后，已在相同范围置顶时保持原来的顺序。返回文章是否存在
+pub async fn pin(pool: &PgPool, id: i64, scope: PinScope) -> Result<bool, tonic::Status> {
+    let rows_affected = sqlx::query(
+        r#"
+        UPDATE topics SET
+            pin_order = CASE
+                WHEN $2 = 0 THEN 0
+                WHEN pin_scope = $2 THEN pin_order
+                ELSE (SELECT COALESCE(MAX(pin_order), 0) + 1 FROM topics WHERE pin_scope = $2)
+            END,
+            pin_scope = $2
+        WHERE id = $1"#,
+    )
+    .bind(id)
+    .bind(scope as i16)
+    .execute(pool)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?
+    .rows_affected();
+    Ok(rows_affected > 0)
+}
+
+/// 按照 ids 的顺序重新设置置顶顺序，返回修改的文章数
+pub async fn reorder(pool: &PgPool, ids: &[i64]) -> Result<i32, tonic::Status> {
+    let rows_affected = sqlx::query(
+        r#"
+        UPDATE topics SET pin_order = o.ord
+        FROM unnest($1::bigint[]) WITH ORDINALITY AS o(id, ord)
+        WHERE topics.id = o.id AND topics.pin_scope <> 0"#,
+    )
+    .bind(ids)
+    .execute(pool)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?
+    .rows_affected();
+    Ok(rows_affected as i32)
+}
+
+pub async fn list_pinned(pool: &PgPool) -> Result<Vec<blog_proto::Topic>, tonic::Status> {
+    let rows = sqlx::query(
+        r#"
+        SELECT
+            id,title,'' AS content,'' AS content_html,summary,is_del,category_id,dateline,hit,status,publish_at,slug,deleted_at,deleted_by,author_id,last_edited_by,word_count,reading_time,pin_scope,pin_order,is_featured,version
+        FROM topics
+        WHERE pin_scope <> 0
+        ORDER BY pin_scope, category_id, pin_order, id DESC"#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok(rows.iter().map(topic_from_row).collect())
+}
+
+/// 推荐或取消推荐，返回文章是否存在
+pub async fn feature(pool: &PgPool, id: i64, featured: bool) -> Result<bool, tonic::Status> {
+    let rows_affected = sqlx::query("UPDATE topics SET is_featured=$2 WHERE id=$1")
+        .bind(id)
+        .bind(featured)
+        .execute(pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?
+        .rows_affected();
+    Ok(rows_affected > 0)
+}
+
+pub async fn list_featured(
+    pool: &PgPool,
+    limit: Option<i32>,
+) -> Result<Vec<blog_proto::Topic>, tonic::Status> {
+    let limit = limit.unwrap_or(FEATURED_LIMIT).clamp(1, MAX_FEATURED_LIMIT);
+    let rows = sqlx::query(
+        r#"
+        SELECT
+            id,title,'' AS content,'' AS content_html,summary,is_del,category_id,dateline,hit,status,publish_at,slug,deleted_at,deleted_by,author_id,last_edited_by,word_count,reading_time,pin_scope,pin_order,is_featured,version
+        FROM topics
+        WHERE is_featured AND is_del = false AND status = $1
+            AND category_id IN (SELECT id FROM categories WHERE is_del = false)
+        ORDER BY dateline DESC, id DESC
+        LIMIT $2"#,
+    )
+    .bind(TopicStatus::Published as i16)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok(rows.iter().map(topic_from_row).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pinned_condition_uses_category_set() {
+        // 分类置顶与列表的分类筛选使用同一个分类集合，包括下级分类时下级分类的置顶同样生效
+        assert!(PINNED_CONDITION.contains("category_id = ANY($3::int[])"));
+        assert!(!PINNED_CONDITION.contains("$3::int)"));
+    }
+}
@@ -0,0 +1,26 @@
+// 定时发布：周期性地将到达发布时间的文章改为已发布
+use std::time::Duration;
+
+use sqlx::PgPool;
+
+pub async fn run(pool: PgPool, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        match publish_due(&pool).await {
+            Ok(0) => {}
+            Ok(n) => println!("published {} scheduled topic(s)", n),
+            Err(err) => eprintln!("publish scheduled topics failed: {}", err),
+        }
+    }
+}
+
+async fn publish_due(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let rows_affected = sqlx::query(
+        "UPDATE topics SET status=1, dateline=publish_at, version=version+1 WHERE status=2 AND publish_at<=CURRENT_TIMESTAMP",
+    )
+    .execute(pool)
+    .await?
+    .rows_affected();
+    Ok(rows_affected)
+}
@@ -0,0 +1,84 @@
+// 字数统计和阅读时间估算：中文和日文按字计算，其它文字按词计算
+/// 每分钟阅读的中日文字数
+const CJK_PER_MINUTE: usize = 400;
+/// 每分钟阅读的其它文字的词数
+const WORDS_PER_MINUTE: usize = 200;
+
+#[derive(Debug, PartialEq)]
+pub struct Reading {
+    /// 字数，中日文的字数加上其它文字的词数
+    pub word_count: i32,
+    /// 预计阅读时间（分钟），有内容时至少为1分钟
+    pub reading_time: i32,
+}
+
+/// 统计纯文本的字数，并估算阅读时间
+pub fn measure(plain_text: &str) -> Reading {
+    let (mut cjk, mut words) = (0, 0);
+    let mut in_word = false;
+    for c in plain_text.chars() {
+        if is_cjk(c) {
+            cjk += 1;
+            in_word = false;
+        } else if c.is_alphanumeric() || (in_word && "'’-_".contains(c)) {
+            // 单词中间的撇号和连字符不拆分单词，例如 don't、well-known
+            if !in_word {
+                words += 1;
+                in_word = true;
+            }
+        } else {
+            in_word = false;
+        }
+    }
+    // cjk / CJK_PER_MINUTE + words / WORDS_PER_MINUTE，通分后向上取整
+    let reading_time = (cjk * WORDS_PER_MINUTE + words * CJK_PER_MINUTE)
+        .div_ceil(CJK_PER_MINUTE * WORDS_PER_MINUTE);
+    Reading {
+        word_count: (cjk + words) as i32,
+        reading_time: reading_time as i32,
+    }
+}
+
+/// 汉字以及日文假名，这些文字不使用空格分词
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}' // 平假名、片假名
+        | '\u{3400}'..='\u{4DBF}' // 扩展A
+        | '\u{4E00}'..='\u{9FFF}' // 基本汉字
+        | '\u{F900}'..='\u{FAFF}' // 兼容汉字
+        | '\u{20000}'..='\u{2FA1F}' // 扩展B及之后
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        assert_eq!(
+            measure(""),
+            Reading {
+                word_count: 0,
+                reading_time: 0
+            }
+        );
+        // 标点不计入字数
+        assert_eq!(measure("你好，世界！").word_count, 4);
+        assert_eq!(measure("Don't use well-known words, twice.").word_count, 5);
+        assert_eq!(measure("使用 Rust 和 axum 构建博客").word_count, 9);
+        assert_eq!(measure("ひらがなとカタカナ").word_count, 9);
+        assert_eq!(measure("한국어 문장").word_count, 2);
+    }
+
+    #[test]
+    fn test_reading_time() {
+        assert_eq!(measure("短文").reading_time, 1);
+        assert_eq!(measure(&"字".repeat(400)).reading_time, 1);
+        assert_eq!(measure(&"字".repeat(401)).reading_time, 2);
+        assert_eq!(measure(&"word ".repeat(600)).reading_time, 3);
+        // 中文400字加英文200词，各需1分钟
+        let text = format!("{}{}", "字".repeat(400), " word".repeat(200));
+        assert_eq!(measure(&text).reading_time, 2);
+    }
+}
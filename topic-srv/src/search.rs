@@ -0,0 +1,193 @@
+// 全文检索相关：中文分词、检索词构造、高亮片段
+use std::sync::LazyLock;
+
+use jieba_rs::Jieba;
+use sqlx::{PgPool, Row};
+
+use crate::markdown;
+
+static JIEBA: LazyLock<Jieba> = LazyLock::new(Jieba::new);
+
+/// 内容片段的最大长度（字符数）
+pub const SNIPPET_LEN: usize = 120;
+
+/// 将文本切分成用于建立索引的词条，使用搜索引擎模式以便长词的子词也能命中
+pub fn tokenize(text: &str) -> Vec<String> {
+    normalize(JIEBA.cut_for_search(text, true))
+}
+
+/// 将文本分词后以空格连接，交给 `to_tsvector('simple', ..)` 建立索引
+pub fn to_document(text: &str) -> String {
+    tokenize(text).join(" ")
+}
+
+/// 将用户输入的关键字切分成检索词，和建立索引使用相同的分词方式，长词才能命中
+pub fn query_terms(keyword: &str) -> Vec<String> {
+    tokenize(keyword)
+}
+
+/// 将检索词以空格连接，交给 `plainto_tsquery('simple', ..)` 生成查询
+pub fn to_query(keyword: &str) -> String {
+    query_terms(keyword).join(" ")
+}
+
+/// 将检索词用 OR 连接，交给 `to_tsquery('simple', ..)` 生成匹配任意一个词的查询
+pub fn to_any_query(terms: &[String]) -> String {
+    let mut out: Vec<String> = Vec::with_capacity(terms.len());
+    for term in terms {
+        // 去掉 tsquery 中有特殊含义的引号和反斜杠，再用引号包裹
+        let term: String = term.chars().filter(|c| *c != '\'' && *c != '\\').collect();
+        let term = format!("'{}'", term);
+        if term.len() > 2 && !out.contains(&term) {
+            out.push(term);
+        }
+    }
+    out.join(" | ")
+}
+
+/// 每批补建索引的文章数
+const BACKFILL_BATCH: i64 = 100;
+
+/// 为还没有建立索引的文章（search_vector 为空，例如增加该字段前的文章）补建全文检索索引，
+/// 返回处理的文章数
+pub async fn backfill(pool: &PgPool) -> Result<usize, sqlx::Error> {
+    let mut last_id = 0i64;
+    let mut total = 0;
+    loop {
+        let rows = sqlx::query(
+            "SELECT id,title,summary,content FROM topics WHERE search_vector = ''::tsvector AND id > $1 ORDER BY id LIMIT $2",
+        )
+        .bind(last_id)
+        .bind(BACKFILL_BATCH)
+        .fetch_all(pool)
+        .await?;
+        let Some(last) = rows.last() else {
+            return Ok(total);
+        };
+        last_id = last.get("id");
+        for row in &rows {
+            let content: String = row.get("content");
+            sqlx::query(
+                "UPDATE topics SET search_vector=setweight(to_tsvector('simple', $2), 'A') || setweight(to_tsvector('simple', $3), 'B') || setweight(to_tsvector('simple', $4), 'C') WHERE id=$1",
+            )
+            .bind(row.get::<i64, _>("id"))
+            .bind(to_document(row.get("title")))
+            .bind(to_document(row.get("summary")))
+            .bind(to_document(&markdown::to_plain_text(&content)))
+            .execute(pool)
+            .await?;
+        }
+        total += rows.len();
+    }
+}
+
+fn normalize(words: Vec<&str>) -> Vec<String> {
+    words
+        .into_iter()
+        .filter(|w| w.chars().any(char::is_alphanumeric))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// 对文本做 HTML 转义，并用 `<mark>` 包裹命中的检索词。
+/// 指定 `max_chars` 时，截取第一个命中位置附近的片段。
+pub fn highlight(text: &str, terms: &[String], max_chars: Option<usize>) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+
+    let mut marked = vec![false; chars.len()];
+    for term in terms {
+        let term: Vec<char> = term.chars().collect();
+        if term.is_empty() || term.len() > lower.len() {
+            continue;
+        }
+        for i in 0..=(lower.len() - term.len()) {
+            if lower[i..i + term.len()] == term[..] {
+                marked[i..i + term.len()].iter_mut().for_each(|m| *m = true);
+            }
+        }
+    }
+
+    let (start, end) = match max_chars {
+        Some(max) if chars.len() > max => {
+            let first = marked.iter().position(|m| *m).unwrap_or(0);
+            let start = first.saturating_sub(max / 4).min(chars.len() - max);
+            (start, start + max)
+        }
+        _ => (0, chars.len()),
+    };
+
+    let mut out = String::new();
+    if start > 0 {
+        out.push('…');
+    }
+    let mut in_mark = false;
+    for i in start..end {
+        if marked[i] != in_mark {
+            out.push_str(if marked[i] { "<mark>" } else { "</mark>" });
+            in_mark = marked[i];
+        }
+        match chars[i] {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+    if in_mark {
+        out.push_str("</mark>");
+    }
+    if end < chars.len() {
+        out.push('…');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_cjk() {
+        let tokens = tokenize("使用axum构建分布式博客系统");
+        assert!(tokens.contains(&"axum".to_string()));
+        assert!(tokens.contains(&"博客".to_string()));
+        assert!(tokens.iter().all(|t| !t.trim().is_empty()));
+    }
+
+    #[test]
+    fn test_query_terms_match_index() {
+        // 检索词和索引使用相同的分词方式，长词的子词也在检索词中
+        let terms = query_terms("分布式博客系统");
+        let tokens = tokenize("使用axum构建分布式博客系统");
+        assert!(!terms.is_empty());
+        assert!(terms.iter().all(|t| tokens.contains(t)));
+    }
+
+    #[test]
+    fn test_to_any_query() {
+        let terms = vec!["rust".to_string(), "it's".to_string(), "rust".to_string()];
+        assert_eq!(to_any_query(&terms), "'rust' | 'its'");
+        assert_eq!(to_any_query(&["'".to_string()]), "");
+    }
+
+    #[test]
+    fn test_highlight() {
+        let terms = query_terms("Rust 博客");
+        let out = highlight("用<Rust>写博客", &terms, None);
+        assert_eq!(out, "用&lt;<mark>Rust</mark>&gt;写<mark>博客</mark>");
+    }
+
+    #[test]
+    fn test_highlight_snippet() {
+        let text = format!("{}关键字{}", "前".repeat(100), "后".repeat(100));
+        let out = highlight(&text, &["关键字".to_string()], Some(20));
+        assert!(out.starts_with('…') && out.ends_with('…'));
+        assert!(out.contains("<mark>关键字</mark>"));
+    }
+}
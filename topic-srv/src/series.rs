@@ -0,0 +1,358 @@
+use std::sync::Arc;
+
+use blog_proto::{
+    series_service_server::SeriesService, CreateSeriesReply, CreateSeriesRequest,
+    DeleteSeriesReply, DeleteSeriesRequest, EditSeriesReply, EditSeriesRequest, GetSeriesReply,
+    GetSeriesRequest, GetTopicSeriesReply, GetTopicSeriesRequest, ListSeriesReply,
+    ListSeriesRequest, SetSeriesTopicsReply, SetSeriesTopicsRequest,
+};
+use chrono::{DateTime, Local};
+use sqlx::{postgres::PgRow, PgPool, Row};
+
+use crate::server::{dt_conver, topic_from_row, topic_status};
+
+/// 系列标题的最大长度（字符数）
+const TITLE_MAX_LEN: usize = 255;
+/// 系列简介的最大长度（字符数）
+const DESCRIPTION_MAX_LEN: usize = 1000;
+
+pub struct Series {
+    pool: Arc<PgPool>,
+}
+
+impl Series {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool: Arc::new(pool),
+        }
+    }
+
+    async fn find(&self, id: i32) -> Result<Option<blog_proto::Series>, tonic::Status> {
+        let row = sqlx::query(
+            r#"
+            SELECT
+                s.id, s.title, s.description, s.dateline,
+                (SELECT COUNT(*) FROM series_topics st WHERE st.series_id = s.id) AS topic_count
+            FROM series s
+            WHERE s.id = $1"#,
+        )
+        .bind(id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(row.as_ref().map(series_from_row))
+    }
+
+    /// 系列中满足条件的文章，按顺序排列
+    async fn topics(
+        &self,
+        series_id: i32,
+        is_del: Option<bool>,
+        status: Option<i32>,
+        category_is_del: Option<bool>,
+    ) -> Result<Vec<blog_proto::Topic>, tonic::Status> {
+        let status = match status {
+            Some(status) => {
+                Some(topic_status(status).map_err(tonic::Status::invalid_argument)? as i16)
+            }
+            None => None,
+        };
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                t.id,t.title,'' AS content,'' AS content_html,t.summary,t.is_del,t.category_id,t.dateline,t.hit,t.status,t.publish_at,t.slug,t.deleted_at,t.deleted_by,t.author_id,t.last_edited_by,t.word_count,t.reading_time,t.pin_scope,t.pin_order,t.is_featured,t.version
+            FROM series_topics st
+            INNER JOIN topics t ON t.id = st.topic_id
+            WHERE st.series_id = $1
+                AND ($2::boolean IS NULL OR t.is_del = $2::boolean)
+                AND ($3::smallint IS NULL OR t.status = $3::smallint)
+                AND ($4::boolean IS NULL OR t.category_id IN (SELECT id FROM categories WHERE is_del = $4::boolean))
+            ORDER BY st.position"#,
+        )
+        .bind(series_id)
+        .bind(is_del)
+        .bind(status)
+        .bind(category_is_del)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(rows.iter().map(topic_from_row).collect())
+    }
+}
+
+#[tonic::async_trait]
+impl SeriesService for Series {
+    async fn create_series(
+        &self,
+        request: tonic::Request<CreateSeriesRequest>,
+    ) -> Result<tonic::Response<CreateSeriesReply>, tonic::Status> {
+        let CreateSeriesRequest { title, description } = request.into_inner();
+        let (title, description) =
+            check_series(&title, &description).map_err(tonic::Status::invalid_argument)?;
+        let row =
+            sqlx::query("INSERT INTO series (title, description) VALUES ($1, $2) RETURNING id")
+                .bind(title)
+                .bind(description)
+                .fetch_one(&*self.pool)
+                .await
+                .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(CreateSeriesReply {
+            id: row.get("id"),
+        }))
+    }
+
+    async fn edit_series(
+        &self,
+        request: tonic::Request<EditSeriesRequest>,
+    ) -> Result<tonic::Response<EditSeriesReply>, tonic::Status> {
+        let EditSeriesRequest {
+            id,
+            title,
+            description,
+        } = request.into_inner();
+        let (title, description) =
+            check_series(&title, &description).map_err(tonic::Status::invalid_argument)?;
+        let rows_affected = sqlx::query("UPDATE series SET title=$1, description=$2 WHERE id=$3")
+            .bind(title)
+            .bind(description)
+            .bind(id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?
+            .rows_affected();
+        Ok(tonic::Response::new(EditSeriesReply {
+            id,
+            ok: rows_affected > 0,
+        }))
+    }
+
+    async fn delete_series(
+        &self,
+        request: tonic::Request<DeleteSeriesRequest>,
+    ) -> Result<tonic::Response<DeleteSeriesReply>, tonic::Status> {
+        let DeleteSeriesRequest { id } = request.into_inner();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        sqlx::query("DELETE FROM series_topics WHERE series_id=$1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let rows_affected = sqlx::query("DELETE FROM series WHERE id=$1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?
+            .rows_affected();
+        tx.commit()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(DeleteSeriesReply {
+            id,
+            ok: rows_affected > 0,
+        }))
+    }
+
+    async fn set_series_topics(
+        &self,
+        request: tonic::Request<SetSeriesTopicsRequest>,
+    ) -> Result<tonic::Response<SetSeriesTopicsReply>, tonic::Status> {
+        let SetSeriesTopicsRequest {
+            series_id,
+            topic_ids,
+        } = request.into_inner();
+        // 重复的文章只保留第一次出现的位置
+        let mut ids: Vec<i64> = Vec::with_capacity(topic_ids.len());
+        for id in topic_ids {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let exists = sqlx::query("SELECT id FROM series WHERE id=$1 FOR UPDATE")
+            .bind(series_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        if exists.is_none() {
+            return Err(tonic::Status::not_found("不存在的系列"));
+        }
+        let found: Vec<i64> = sqlx::query("SELECT id FROM topics WHERE id = ANY($1)")
+            .bind(&ids)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?
+            .iter()
+            .map(|row| row.get("id"))
+            .collect();
+        if let Some(id) = ids.iter().find(|id| !found.contains(id)) {
+            return Err(tonic::Status::invalid_argument(format!(
+                "不存在的文章：{}",
+                id
+            )));
+        }
+        sqlx::query("DELETE FROM series_topics WHERE series_id = $1 OR topic_id = ANY($2)")
+            .bind(series_id)
+            .bind(&ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        sqlx::query(
+            r#"
+            INSERT INTO series_topics (topic_id, series_id, position)
+            SELECT o.id, $1, o.ord FROM unnest($2::bigint[]) WITH ORDINALITY AS o(id, ord)"#,
+        )
+        .bind(series_id)
+        .bind(&ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(SetSeriesTopicsReply {
+            series_id,
+            topic_count: ids.len() as i32,
+        }))
+    }
+
+    async fn list_series(
+        &self,
+        _request: tonic::Request<ListSeriesRequest>,
+    ) -> Result<tonic::Response<ListSeriesReply>, tonic::Status> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                s.id, s.title, s.description, s.dateline, COUNT(st.topic_id) AS topic_count
+            FROM series s
+            LEFT JOIN series_topics st ON st.series_id = s.id
+            GROUP BY s.id
+            ORDER BY s.dateline DESC, s.id DESC"#,
+        )
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(ListSeriesReply {
+            series: rows.iter().map(series_from_row).collect(),
+        }))
+    }
+
+    async fn get_series(
+        &self,
+        request: tonic::Request<GetSeriesRequest>,
+    ) -> Result<tonic::Response<GetSeriesReply>, tonic::Status> {
+        let GetSeriesRequest {
+            id,
+            is_del,
+            status,
+            category_is_del,
+        } = request.into_inner();
+        let series = self
+            .find(id)
+            .await?
+            .ok_or(tonic::Status::not_found("不存在的系列"))?;
+        let topics = self.topics(id, is_del, status, category_is_del).await?;
+        Ok(tonic::Response::new(GetSeriesReply {
+            series: Some(series),
+            topics,
+        }))
+    }
+
+    async fn get_topic_series(
+        &self,
+        request: tonic::Request<GetTopicSeriesRequest>,
+    ) -> Result<tonic::Response<GetTopicSeriesReply>, tonic::Status> {
+        let GetTopicSeriesRequest {
+            topic_id,
+            is_del,
+            status,
+            category_is_del,
+        } = request.into_inner();
+        let series_id: Option<i32> =
+            sqlx::query("SELECT series_id FROM series_topics WHERE topic_id=$1")
+                .bind(topic_id)
+                .fetch_optional(&*self.pool)
+                .await
+                .map_err(|err| tonic::Status::internal(err.to_string()))?
+                .map(|row| row.get("series_id"));
+        let series = match series_id {
+            Some(series_id) => self.find(series_id).await?,
+            None => None,
+        };
+        let Some(series) = series else {
+            return Ok(tonic::Response::new(GetTopicSeriesReply::default()));
+        };
+        let mut topics = self
+            .topics(series.id, is_del, status, category_is_del)
+            .await?;
+        let total = topics.len() as i32;
+        let (part, prev, next) = match topics.iter().position(|t| t.id == topic_id) {
+            Some(idx) => {
+                let next = (idx + 1 < topics.len()).then(|| topics.remove(idx + 1));
+                let prev = (idx > 0).then(|| topics.remove(idx - 1));
+                (idx as i32 + 1, prev, next)
+            }
+            None => (0, None, None),
+        };
+        Ok(tonic::Response::new(GetTopicSeriesReply {
+            series: Some(series),
+            part,
+            total,
+            prev,
+            next,
+        }))
+    }
+}
+
+fn series_from_row(row: &PgRow) -> blog_proto::Series {
+    let dt: DateTime<Local> = row.get("dateline");
+    let topic_count: i64 = row.get("topic_count");
+    blog_proto::Series {
+        id: row.get("id"),
+        title: row.get("title"),
+        description: row.get("description"),
+        topic_count: topic_count as i32,
+        dateline: dt_conver(&dt),
+    }
+}
+
+fn check_series<'a>(
+    title: &'a str,
+    description: &'a str,
+) -> Result<(&'a str, &'a str), &'static str> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err("系列标题不能为空");
+    }
+    if title.chars().count() > TITLE_MAX_LEN {
+        return Err("系列标题不能超过255个字符");
+    }
+    let description = description.trim();
+    if description.chars().count() > DESCRIPTION_MAX_LEN {
+        return Err("系列简介不能超过1000个字符");
+    }
+    Ok((title, description))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_series() {
+        assert_eq!(
+            check_series(" Rust 入门 ", " 简介 "),
+            Ok(("Rust 入门", "简介"))
+        );
+        assert!(check_series("  ", "").is_err());
+        assert!(check_series(&"标".repeat(256), "").is_err());
+        assert!(check_series("标题", &"介".repeat(1001)).is_err());
+    }
+}
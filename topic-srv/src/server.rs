@@ -1,21 +1,60 @@
 use std::sync::Arc;
 
 use blog_proto::{
-    topic_service_server::TopicService, CreateTopicReply, CreateTopicRequest, EditTopicReply,
-    EditTopicRequest, GetTopicReply, GetTopicRequest, ListTopicReply, ListTopicRequest,
-    ToggleTopicReply, ToggleTopicRequest,
+    get_topic_request::Condition, topic_service_server::TopicService, Archive, BulkTopicReply,
+    BulkTopicRequest, CreateTopicReply, CreateTopicRequest, DiffTopicRevisionReply,
+    DiffTopicRevisionRequest, EditTopicReply, EditTopicRequest, ExportTopicsRequest,
+    FeatureTopicReply, FeatureTopicRequest, GetRelatedTopicsReply, GetRelatedTopicsRequest,
+    GetTopicReply, GetTopicRequest, GetTopicRevisionReply, GetTopicRevisionRequest, ImportAction,
+    ImportTopicResult, ImportTopicsReply, ListArchiveReply, ListArchiveRequest,
+    ListFeaturedTopicsReply, ListFeaturedTopicsRequest, ListPinnedTopicsReply,
+    ListPinnedTopicsRequest, ListTopicReply, ListTopicRequest, ListTopicRevisionReply,
+    ListTopicRevisionRequest, MoveTopicsRequest, PinScope, PinTopicReply, PinTopicRequest,
+    ReorderPinnedTopicsReply, ReorderPinnedTopicsRequest, RestoreTopicRevisionReply,
+    RestoreTopicRevisionRequest, ToggleTopicReply, ToggleTopicRequest, TopicHighlight,
+    TopicRevision, TopicSort, TopicStatus,
 };
-use chrono::{DateTime, Datelike, Local, TimeZone, Timelike};
-use sqlx::{PgPool, Row};
+use chrono::{DateTime, Local, TimeZone};
+use sqlx::{postgres::PgRow, PgPool, Postgres, Row, Transaction};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{
+    bulk,
+    cursor::{Cursor, Direction},
+    diff,
+    hit::HitCounter,
+    markdown, permalink, pin, reading, search, summary, tag, transfer,
+};
+
+/// 默认每页条数
+const PAGE_SIZE: i32 = 30;
+/// 每页条数的上限
+const MAX_PAGE_SIZE: i32 = 100;
+/// 默认的相关文章条数
+const RELATED_LIMIT: i32 = 5;
+/// 相关文章条数的上限
+const MAX_RELATED_LIMIT: i32 = 20;
+/// 查找相关文章时最多使用的词数
+const RELATED_TERMS: usize = 32;
+/// 置顶文章最多列出的条数
+const MAX_PINNED: i32 = 20;
+/// 导出时缓存的文章数
+const EXPORT_BUFFER: usize = 64;
 
 pub struct Topic {
     pool: Arc<PgPool>,
+    hits: Arc<HitCounter>,
+    /// 自动提取的摘要长度（字符数）
+    summary_len: usize,
 }
 
 impl Topic {
-    pub fn new(pool: PgPool) -> Self {
+    pub fn new(pool: PgPool, hits: Arc<HitCounter>, summary_len: usize) -> Self {
         Self {
             pool: Arc::new(pool),
+            hits,
+            summary_len,
         }
     }
 }
@@ -31,17 +70,71 @@ impl TopicService for Topic {
             category_id,
             content,
             summary,
+            status,
+            publish_at,
+            dateline,
+            tags,
+            slug,
+            author_id,
         } = request.into_inner();
+        let status = match status {
+            Some(status) => topic_status(status).map_err(tonic::Status::invalid_argument)?,
+            None => TopicStatus::Published,
+        };
+        let publish_at =
+            check_publish_at(status, publish_at).map_err(tonic::Status::invalid_argument)?;
+        let dateline = try_tm_cover(dateline).map_err(tonic::Status::invalid_argument)?;
+        // 定时发布的文章以定时发布的时间作为发布时间
+        let dateline = publish_at.or(dateline);
+        let plain_text = markdown::to_plain_text(&content);
+        let reading = reading::measure(&plain_text);
         let summary = match summary {
             Some(summary) => summary,
-            None => get_summary(&content),
+            None => summary::extract(&content, self.summary_len),
         };
-        let row = sqlx::query("insert into topic (title,category_id,content,summary) values($1, $2, $3, $4) returning id")
-            .bind(title)
-            .bind(category_id).bind(content).bind(summary)
-            .fetch_one(&*self.pool)
-            .await.map_err(|err| tonic::Status::internal(err.to_string()))?;
-        let reply = CreateTopicReply { id: row.get("id") };
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let slug = slug
+            .filter(|s| !s.trim().is_empty())
+            .unwrap_or(title.clone());
+        let slug = permalink::unique_slug(&mut tx, &slug, None).await?;
+        let row = sqlx::query(
+            r#"
+            INSERT INTO topics
+                (title,category_id,content,content_html,summary,search_vector,status,publish_at,dateline,slug,author_id,word_count,reading_time)
+            VALUES
+                ($1, $2, $3, $8, $4, setweight(to_tsvector('simple', $5), 'A') || setweight(to_tsvector('simple', $6), 'B') || setweight(to_tsvector('simple', $7), 'C'),
+                $9, $10, COALESCE($11, CURRENT_TIMESTAMP), $12, $13, $14, $15)
+            RETURNING id"#,
+        )
+        .bind(&title)
+        .bind(category_id)
+        .bind(&content)
+        .bind(&summary)
+        .bind(search::to_document(&title))
+        .bind(search::to_document(&summary))
+        .bind(search::to_document(&plain_text))
+        .bind(markdown::render(&content))
+        .bind(status as i16)
+        .bind(publish_at)
+        .bind(dateline)
+        .bind(&slug)
+        .bind(author_id)
+        .bind(reading.word_count)
+        .bind(reading.reading_time)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let id: i64 = row.get("id");
+        tag::save_topic_tags(&mut tx, id, &tags).await?;
+        save_revision(&mut tx, id).await?;
+        tx.commit()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let reply = CreateTopicReply { id };
         Ok(tonic::Response::new(reply))
     }
     async fn edit_topic(
@@ -49,25 +142,105 @@ impl TopicService for Topic {
         request: tonic::Request<EditTopicRequest>,
     ) -> Result<tonic::Response<EditTopicReply>, tonic::Status> {
         let r = request.into_inner();
+        let status = match r.status {
+            Some(status) => Some(topic_status(status).map_err(tonic::Status::invalid_argument)?),
+            None => None,
+        };
+        let publish_at = match status {
+            Some(status) => {
+                check_publish_at(status, r.publish_at).map_err(tonic::Status::invalid_argument)?
+            }
+            None => None,
+        };
+        let dateline = try_tm_cover(r.dateline).map_err(tonic::Status::invalid_argument)?;
+        let plain_text = markdown::to_plain_text(&r.content);
+        let reading = reading::measure(&plain_text);
         let summary = match r.summary {
             Some(s) => s,
-            None => get_summary(&r.content),
+            None => summary::extract(&r.content, self.summary_len),
         };
-        let rows_affected = sqlx::query(
-            "update topic set title=$1,content=$2,summary=$3,category_id=$4 where id=$5",
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let row = sqlx::query(
+            r#"
+            UPDATE topics SET
+                title=$1,content=$2,content_html=$9,summary=$3,category_id=$4,
+                search_vector=setweight(to_tsvector('simple', $6), 'A') || setweight(to_tsvector('simple', $7), 'B') || setweight(to_tsvector('simple', $8), 'C'),
+                status=COALESCE($10::smallint, status),
+                publish_at=CASE WHEN $10::smallint IS NULL THEN publish_at ELSE $11::TIMESTAMPTZ END,
+                dateline=COALESCE(
+                    $12::TIMESTAMPTZ,
+                    CASE
+                        WHEN $10::smallint = 2 THEN $11::TIMESTAMPTZ
+                        WHEN $10::smallint = 1 AND status <> 1 THEN CURRENT_TIMESTAMP
+                        ELSE dateline
+                    END
+                ),
+                author_id=COALESCE(author_id, $13),
+                last_edited_by=COALESCE($13, last_edited_by),
+                word_count=$14,reading_time=$15,
+                version=version+1
+            WHERE id=$5 AND ($16::int IS NULL OR version=$16::int)
+            RETURNING version"#,
         )
-        .bind(r.title)
-        .bind(r.content)
-        .bind(summary)
+        .bind(&r.title)
+        .bind(&r.content)
+        .bind(&summary)
         .bind(r.category_id)
         .bind(r.id)
-        .execute(&*self.pool)
+        .bind(search::to_document(&r.title))
+        .bind(search::to_document(&summary))
+        .bind(search::to_document(&plain_text))
+        .bind(markdown::render(&r.content))
+        .bind(status.map(|s| s as i16))
+        .bind(publish_at)
+        .bind(dateline)
+        .bind(r.author_id)
+        .bind(reading.word_count)
+        .bind(reading.reading_time)
+        .bind(r.version)
+        .fetch_optional(&mut *tx)
         .await
-        .map_err(|err| tonic::Status::internal(err.to_string()))?
-        .rows_affected();
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let version: Option<i32> = row.map(|row| row.get("version"));
+        if version.is_none() && r.version.is_some() {
+            // 没有更新任何记录：文章不存在，或者版本号已经改变
+            let current = sqlx::query("SELECT version FROM topics WHERE id=$1")
+                .bind(r.id)
+                .fetch_optional(&mut *tx)
+                .await
+                .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            if let Some(current) = current {
+                return Err(blog_proto::version_conflict(
+                    "文章已被其他人修改",
+                    current.get("version"),
+                ));
+            }
+        }
+        if version.is_some() {
+            if let Some(tags) = &r.tags {
+                tag::save_topic_tags(&mut tx, r.id, &tags.names).await?;
+            }
+            if let Some(slug) = &r.slug {
+                let slug = if slug.trim().is_empty() {
+                    &r.title
+                } else {
+                    slug
+                };
+                permalink::change_slug(&mut tx, r.id, slug).await?;
+            }
+            save_revision(&mut tx, r.id).await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
         Ok(tonic::Response::new(EditTopicReply {
             id: r.id,
-            ok: rows_affected > 0,
+            ok: version.is_some(),
+            version: version.unwrap_or_default(),
         }))
     }
 
@@ -75,12 +248,23 @@ impl TopicService for Topic {
         &self,
         request: tonic::Request<ToggleTopicRequest>,
     ) -> Result<tonic::Response<ToggleTopicReply>, tonic::Status> {
-        let ToggleTopicRequest { id } = request.into_inner();
-        let row = sqlx::query("update topics set is_del=(not is_del) where id=$1 returning is_del")
-            .bind(id)
-            .fetch_optional(&*self.pool)
-            .await
-            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let ToggleTopicRequest { id, admin_id } = request.into_inner();
+        // 删除时记录删除时间和管理员，恢复时清空
+        let row = sqlx::query(
+            r#"
+            update topics set
+                is_del=(not is_del),
+                deleted_at=(case when is_del then null else current_timestamp end),
+                deleted_by=(case when is_del then null else $2 end),
+                version=version+1
+            where id=$1
+            returning is_del"#,
+        )
+        .bind(id)
+        .bind(admin_id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
         if row.is_none() {
             return Err(tonic::Status::not_found("不存在的文章"));
         }
@@ -95,26 +279,42 @@ impl TopicService for Topic {
         request: tonic::Request<GetTopicRequest>,
     ) -> Result<tonic::Response<GetTopicReply>, tonic::Status> {
         let GetTopicRequest {
-            id,
+            condition,
             is_del,
             inc_hit,
+            status,
+            visitor_id,
+            user_agent,
+            category_is_del,
         } = request.into_inner();
+        let (id, slug) = match condition {
+            Some(Condition::Id(id)) => (Some(id), None),
+            Some(Condition::Slug(slug)) => (None, Some(slug)),
+            None => return Err(tonic::Status::invalid_argument("请指定文章ID或永久链接")),
+        };
 
-        let inc_hit = inc_hit.unwrap_or(false); // 增加点击量
-        if inc_hit {
-            sqlx::query("UPDATE topics SET hit=hit+1 WHERE id=$1")
-                .bind(id)
-                .execute(&*self.pool)
-                .await
-                .map_err(|err| tonic::Status::internal(err.to_string()))?;
-        }
-
-        let query = match is_del {
-            Some(is_del) => sqlx::query("SELECT id,title,content,summary,is_del,category_id,dateline,hit FROM topics WHERE id=$1 AND is_del=$2")
-            .bind(id).bind(is_del),
-            None => sqlx::query("SELECT id,title,content,summary,is_del,category_id,dateline,hit FROM topics WHERE id=$1")
-            .bind(id),
+        let status = match status {
+            Some(status) => {
+                Some(topic_status(status).map_err(tonic::Status::invalid_argument)? as i16)
+            }
+            None => None,
         };
+        let query = sqlx::query(
+            r#"
+            SELECT
+                id,title,content,content_html,summary,is_del,category_id,dateline,hit,status,publish_at,slug,deleted_at,deleted_by,author_id,last_edited_by,word_count,reading_time,pin_scope,pin_order,is_featured,version
+            FROM topics
+            WHERE ($1::bigint IS NULL OR id=$1::bigint)
+                AND ($4::varchar IS NULL OR slug=$4::varchar OR id=(SELECT topic_id FROM topic_slugs WHERE slug=$4::varchar))
+                AND ($2::boolean IS NULL OR is_del=$2::boolean)
+                AND ($3::smallint IS NULL OR status=$3::smallint)
+                AND ($5::boolean IS NULL OR category_id IN (SELECT id FROM categories WHERE is_del=$5::boolean))"#,
+        )
+        .bind(id)
+        .bind(is_del)
+        .bind(status)
+        .bind(slug)
+        .bind(category_is_del);
         let row = query
             .fetch_optional(&*self.pool)
             .await
@@ -122,21 +322,19 @@ impl TopicService for Topic {
         if row.is_none() {
             return Err(tonic::Status::not_found("不存在的文章"));
         }
-        let row = row.unwrap();
-        let dt: DateTime<Local> = row.get("dateline");
-        let dateline = dt_conver(&dt);
+        let mut topic = topic_from_row(&row.unwrap());
+
+        // 点击量先记在内存中，由后台任务定期写入数据库
+        if inc_hit.unwrap_or(false) {
+            self.hits
+                .hit(topic.id, visitor_id.as_deref(), user_agent.as_deref());
+        }
+        topic.hit += self.hits.pending(topic.id);
+        let mut topics = vec![topic];
+        tag::fill_topic_tags(&*self.pool, &mut topics).await?;
 
         Ok(tonic::Response::new(GetTopicReply {
-            topic: Some(blog_proto::Topic {
-                id: row.get("id"),
-                title: row.get("title"),
-                category_id: row.get("category_id"),
-                content: row.get("content"),
-                summary: row.get("summary"),
-                hit: row.get("hit"),
-                is_del: row.get("is_del"),
-                dateline,
-            }),
+            topic: topics.pop(),
         }))
     }
 
@@ -150,90 +348,244 @@ impl TopicService for Topic {
             keyword,
             is_del,
             dateline_range,
+            status,
+            tag,
+            page_size,
+            sort,
+            cursor,
+            skip_total,
+            author_id,
+            pinned_first,
+            category_is_del,
+            include_descendants,
         } = request.into_inner();
+        let category_ids = category_ids(
+            &self.pool,
+            category_id,
+            include_descendants.unwrap_or(false),
+        )
+        .await?;
+        let status = match status {
+            Some(status) => {
+                Some(topic_status(status).map_err(tonic::Status::invalid_argument)? as i16)
+            }
+            None => None,
+        };
 
-        let page = page.unwrap_or(0);
-        let page_size = 30;
-        let offset = page * page_size;
+        let page_size = page_size.unwrap_or(PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
         let mut start = None;
         let mut end = None;
         if let Some(dr) = dateline_range {
-            start = tm_cover(dr.start);
-            end = tm_cover(dr.end);
+            start = try_tm_cover(dr.start).map_err(tonic::Status::invalid_argument)?;
+            end = try_tm_cover(dr.end).map_err(tonic::Status::invalid_argument)?;
+            if let (Some(start), Some(end)) = (start, end) {
+                if start > end {
+                    return Err(tonic::Status::invalid_argument("开始时间不能晚于结束时间"));
+                }
+            }
         }
-        let row = sqlx::query(
-            r#"
-            select count(*)
-            from topics
-            WHERE 1=1
-                AND ($1::int IS NULL OR category_id = $1::int)
-                AND ($2::text IS NULL OR title ILIKE CONCAT('%',$2::text,'%'))
-                AND ($3::boolean IS NULL OR is_del = $3::boolean)
-                AND (
-                    ($4::TIMESTAMPTZ IS NULL OR $5::TIMESTAMPTZ IS NULL)
-                    OR
-                    (dateline BETWEEN $4::TIMESTAMPTZ AND $5::TIMESTAMPTZ)
-                )"#,
-        )
-        .bind(&category_id)
-        .bind(&keyword)
-        .bind(is_del)
-        .bind(start)
-        .bind(end)
-        .fetch_one(&*self.pool)
-        .await
-        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        // 关键字分词后使用全文检索
+        let keyword = keyword.filter(|k| !k.trim().is_empty());
+        let ts_query = keyword.as_deref().map(search::to_query);
+        let sort = match sort {
+            Some(sort) => TopicSort::try_from(sort)
+                .map_err(|_| tonic::Status::invalid_argument("错误的排序方式"))?,
+            None if keyword.is_some() => TopicSort::Relevance,
+            None => TopicSort::Dateline,
+        };
+        let cursor = match cursor.filter(|c| !c.is_empty()) {
+            Some(cursor) => match Cursor::decode(&cursor) {
+                Some(cursor) if cursor.sort == sort => Some(cursor),
+                _ => return Err(tonic::Status::invalid_argument("错误的翻页游标")),
+            },
+            None => None,
+        };
+        // 使用游标时不再使用页码
+        let page = match cursor {
+            Some(_) => 0,
+            None => page.unwrap_or(0),
+        };
+        let offset = page * page_size;
 
-        let record_total: i64 = row.get(0);
-        let page_totoal = f64::ceil(record_total as f64 / page_size as f64) as i64;
+        let (record_total, page_totoal) = if skip_total.unwrap_or(false) {
+            (0, 0)
+        } else {
+            let row = sqlx::query(
+                r#"
+                select count(*)
+                from topics
+                WHERE 1=1
+                    AND ($1::int[] IS NULL OR category_id = ANY($1::int[]))
+                    AND ($2::text IS NULL OR search_vector @@ plainto_tsquery('simple', $2::text))
+                    AND ($3::boolean IS NULL OR is_del = $3::boolean)
+                    AND ($4::TIMESTAMPTZ IS NULL OR dateline >= $4::TIMESTAMPTZ)
+                    AND ($5::TIMESTAMPTZ IS NULL OR dateline < $5::TIMESTAMPTZ)
+                    AND ($6::smallint IS NULL OR status = $6::smallint)
+                    AND ($7::varchar IS NULL OR id IN (
+                        SELECT tt.topic_id FROM topic_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id WHERE tg.name = $7::varchar
+                    ))
+                    AND ($8::int IS NULL OR author_id = $8::int)
+                    AND ($9::boolean IS NULL OR category_id IN (SELECT id FROM categories WHERE is_del = $9::boolean))"#,
+            )
+            .bind(&category_ids)
+            .bind(&ts_query)
+            .bind(is_del)
+            .bind(start)
+            .bind(end)
+            .bind(status)
+            .bind(&tag)
+            .bind(author_id)
+            .bind(category_is_del)
+            .fetch_one(&*self.pool)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            let record_total: i64 = row.get(0);
+            let page_totoal = f64::ceil(record_total as f64 / page_size as f64) as i64;
+            (record_total, page_totoal)
+        };
 
-        let rows = sqlx::query(
-            r#"
-            SELECT 
-                id,title,content,summary,is_del,category_id,dateline,hit FROM topics
-            WHERE 1=1
-                AND ($3::int IS NULL OR category_id = $3::int)
-                AND ($4::text IS NULL OR title ILIKE CONCAT('%',$4::text,'%'))
-                AND ($5::boolean IS NULL OR is_del = $5::boolean)
-                AND (
-                    ($6::TIMESTAMPTZ IS NULL OR $7::TIMESTAMPTZ IS NULL)
-                    OR
-                    (dateline BETWEEN $6::TIMESTAMPTZ AND $7::TIMESTAMPTZ)
+        // 向前翻页时反向查询，再把结果倒过来
+        let (column, column_type, desc) = sort_column(sort);
+        let direction = cursor
+            .as_ref()
+            .map(|c| c.direction)
+            .unwrap_or(Direction::Next);
+        let (order, op) = if desc == (direction == Direction::Next) {
+            ("DESC", "<")
+        } else {
+            ("ASC", ">")
+        };
+        // 置顶的文章单独查询，不再出现在列表中
+        let pinned_first = pinned_first.unwrap_or(false);
+        let list_sql = |order_by: &str| {
+            format!(
+                r#"
+                SELECT 
+                    id,title,content,content_html,summary,is_del,category_id,dateline,hit,status,publish_at,slug,deleted_at,deleted_by,author_id,last_edited_by,word_count,reading_time,pin_scope,pin_order,is_featured,version,
+                    ({column})::text AS sort_key
+                FROM topics
+                WHERE 1=1
+                    AND ($3::int[] IS NULL OR category_id = ANY($3::int[]))
+                    AND ($4::text IS NULL OR search_vector @@ plainto_tsquery('simple', $4::text))
+                    AND ($5::boolean IS NULL OR is_del = $5::boolean)
+                    AND ($6::TIMESTAMPTZ IS NULL OR dateline >= $6::TIMESTAMPTZ)
+                    AND ($7::TIMESTAMPTZ IS NULL OR dateline < $7::TIMESTAMPTZ)
+                    AND ($8::smallint IS NULL OR status = $8::smallint)
+                    AND ($9::varchar IS NULL OR id IN (
+                        SELECT tt.topic_id FROM topic_tags tt INNER JOIN tags tg ON tg.id = tt.tag_id WHERE tg.name = $9::varchar
+                    ))
+                    AND ($10::text IS NULL OR ({column}, id) {op} ($10::{column_type}, $11::bigint))
+                    AND ($12::int IS NULL OR author_id = $12::int)
+                    AND ($13::boolean IS NULL OR {pinned} = $13::boolean)
+                    AND ($14::boolean IS NULL OR category_id IN (SELECT id FROM categories WHERE is_del = $14::boolean))
+                ORDER BY 
+                    {order_by}
+                LIMIT 
+                    $1
+                OFFSET
+                    $2
+                "#,
+                pinned = pin::PINNED_CONDITION,
             )
-            ORDER BY 
-                id DESC
-            LIMIT 
-                $1
-            OFFSET
-                $2
-            "#,
-        )
-        .bind(page_size)
-        .bind(offset)
-        .bind(&category_id)
-        .bind(&keyword)
-        .bind(&is_del)
-        .bind(&start)
-        .bind(&end)
-        .fetch_all(&*self.pool)
-        .await
-        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        };
+        let sql = list_sql(&format!("{column} {order}, id {order}"));
+        let mut rows = sqlx::query(&sql)
+            .bind(page_size + 1) // 多查一条，用来判断是否还有下一页
+            .bind(offset)
+            .bind(&category_ids)
+            .bind(&ts_query)
+            .bind(is_del)
+            .bind(start)
+            .bind(end)
+            .bind(status)
+            .bind(&tag)
+            .bind(cursor.as_ref().map(|c| &c.key))
+            .bind(cursor.as_ref().map(|c| c.id))
+            .bind(author_id)
+            .bind(pinned_first.then_some(false))
+            .bind(category_is_del)
+            .fetch_all(&*self.pool)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let has_more = rows.len() > page_size as usize;
+        rows.truncate(page_size as usize);
+        if direction == Direction::Prev {
+            rows.reverse();
+        }
+        let cursor_at = |row: Option<&PgRow>, direction: Direction| {
+            row.map(|row| {
+                Cursor {
+                    direction,
+                    sort,
+                    id: row.get("id"),
+                    key: row.get("sort_key"),
+                }
+                .encode()
+            })
+        };
+        let (next_cursor, prev_cursor) = match direction {
+            Direction::Next => (
+                cursor_at(rows.last().filter(|_| has_more), Direction::Next),
+                cursor_at(
+                    rows.first().filter(|_| cursor.is_some() || page > 0),
+                    Direction::Prev,
+                ),
+            ),
+            Direction::Prev => (
+                cursor_at(rows.last(), Direction::Next),
+                cursor_at(rows.first().filter(|_| has_more), Direction::Prev),
+            ),
+        };
+
+        // 列表的第一页，包括向前翻页回到第一页
+        let is_first_page = match direction {
+            Direction::Next => cursor.is_none() && page == 0,
+            Direction::Prev => !has_more,
+        };
+        if pinned_first && is_first_page {
+            let pinned = sqlx::query(&list_sql("pin_scope, pin_order, id DESC"))
+                .bind(MAX_PINNED)
+                .bind(0)
+                .bind(&category_ids)
+                .bind(&ts_query)
+                .bind(is_del)
+                .bind(start)
+                .bind(end)
+                .bind(status)
+                .bind(&tag)
+                .bind(None::<String>)
+                .bind(None::<i64>)
+                .bind(author_id)
+                .bind(Some(true))
+                .bind(category_is_del)
+                .fetch_all(&*self.pool)
+                .await
+                .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            rows.splice(0..0, pinned);
+        }
 
+        let terms = keyword
+            .as_deref()
+            .map(search::query_terms)
+            .unwrap_or_default();
         let mut topics = Vec::with_capacity(rows.len());
+        let mut highlights = Vec::new();
         for row in rows {
-            let dt: DateTime<Local> = row.get("dateline");
-            let dateline = dt_conver(&dt);
-            topics.push(blog_proto::Topic {
-                id: row.get("id"),
-                title: row.get("title"),
-                category_id: row.get("category_id"),
-                content: row.get("content"),
-                summary: row.get("summary"),
-                hit: row.get("hit"),
-                is_del: row.get("is_del"),
-                dateline,
-            });
+            let topic = topic_from_row(&row);
+            if !terms.is_empty() {
+                highlights.push(TopicHighlight {
+                    id: topic.id,
+                    title: search::highlight(&topic.title, &terms, None),
+                    snippet: search::highlight(
+                        &markdown::to_plain_text(&topic.content),
+                        &terms,
+                        Some(search::SNIPPET_LEN),
+                    ),
+                });
+            }
+            topics.push(topic);
         }
+        tag::fill_topic_tags(&*self.pool, &mut topics).await?;
 
         Ok(tonic::Response::new(ListTopicReply {
             page,
@@ -241,35 +593,552 @@ impl TopicService for Topic {
             page_totoal,
             record_total,
             topics,
+            highlights,
+            next_cursor,
+            prev_cursor,
         }))
     }
+
+    async fn list_topic_revision(
+        &self,
+        request: tonic::Request<ListTopicRevisionRequest>,
+    ) -> Result<tonic::Response<ListTopicRevisionReply>, tonic::Status> {
+        let ListTopicRevisionRequest { topic_id } = request.into_inner();
+        let rows = sqlx::query(
+            "SELECT id,topic_id,title,category_id,summary,'' AS content,dateline FROM topic_revisions WHERE topic_id=$1 ORDER BY id DESC",
+        )
+        .bind(topic_id)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let revisions = rows.iter().map(revision_from_row).collect();
+        Ok(tonic::Response::new(ListTopicRevisionReply { revisions }))
+    }
+
+    async fn get_topic_revision(
+        &self,
+        request: tonic::Request<GetTopicRevisionRequest>,
+    ) -> Result<tonic::Response<GetTopicRevisionReply>, tonic::Status> {
+        let GetTopicRevisionRequest { id } = request.into_inner();
+        let row = sqlx::query(
+            "SELECT id,topic_id,title,category_id,summary,content,dateline FROM topic_revisions WHERE id=$1",
+        )
+        .bind(id)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?
+        .ok_or(tonic::Status::not_found("不存在的修订"))?;
+        Ok(tonic::Response::new(GetTopicRevisionReply {
+            revision: Some(revision_from_row(&row)),
+        }))
+    }
+
+    async fn diff_topic_revision(
+        &self,
+        request: tonic::Request<DiffTopicRevisionRequest>,
+    ) -> Result<tonic::Response<DiffTopicRevisionReply>, tonic::Status> {
+        let DiffTopicRevisionRequest { from_id, to_id } = request.into_inner();
+        let rows = sqlx::query(
+            "SELECT id,topic_id,title,category_id,summary,content,dateline FROM topic_revisions WHERE id=$1 OR id=$2",
+        )
+        .bind(from_id)
+        .bind(to_id)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let mut revisions = rows.iter().map(revision_from_row);
+        let (from, to) = match (revisions.next(), revisions.next()) {
+            (Some(a), Some(b)) if a.id == from_id => (a, b),
+            (Some(a), Some(b)) => (b, a),
+            _ => return Err(tonic::Status::not_found("不存在的修订")),
+        };
+        if from.topic_id != to.topic_id {
+            return Err(tonic::Status::invalid_argument("只能对比同一篇文章的修订"));
+        }
+        Ok(tonic::Response::new(DiffTopicRevisionReply {
+            title: diff::diff_lines(&from.title, &to.title),
+            summary: diff::diff_lines(&from.summary, &to.summary),
+            content: diff::diff_lines(&from.content, &to.content),
+            from: Some(from),
+            to: Some(to),
+        }))
+    }
+
+    async fn restore_topic_revision(
+        &self,
+        request: tonic::Request<RestoreTopicRevisionRequest>,
+    ) -> Result<tonic::Response<RestoreTopicRevisionReply>, tonic::Status> {
+        let RestoreTopicRevisionRequest {
+            id,
+            version,
+            admin_id,
+        } = request.into_inner();
+        let revision = self
+            .get_topic_revision(tonic::Request::new(GetTopicRevisionRequest { id }))
+            .await?
+            .into_inner()
+            .revision
+            .unwrap_or_default();
+        let reply = self
+            .edit_topic(tonic::Request::new(restore_request(
+                revision, version, admin_id,
+            )))
+            .await?
+            .into_inner();
+        Ok(tonic::Response::new(RestoreTopicRevisionReply {
+            topic_id: reply.id,
+            ok: reply.ok,
+            version: reply.version,
+        }))
+    }
+
+    async fn get_related_topics(
+        &self,
+        request: tonic::Request<GetRelatedTopicsRequest>,
+    ) -> Result<tonic::Response<GetRelatedTopicsReply>, tonic::Status> {
+        let GetRelatedTopicsRequest { id, limit } = request.into_inner();
+        let limit = limit.unwrap_or(RELATED_LIMIT).clamp(1, MAX_RELATED_LIMIT);
+        let row = sqlx::query("SELECT title,summary FROM topics WHERE id=$1")
+            .bind(id)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?
+            .ok_or(tonic::Status::not_found("不存在的文章"))?;
+        let title: String = row.get("title");
+        let summary: String = row.get("summary");
+        let mut terms = search::query_terms(&format!("{} {}", title, summary));
+        terms.truncate(RELATED_TERMS);
+        let ts_query = search::to_any_query(&terms);
+
+        // 相关程度 = 同分类 + 共同的词 + 标题相似度 + 共同的标签
+        let rows = sqlx::query(
+            r#"
+            SELECT * FROM (
+                SELECT
+                    t.id,t.title,'' AS content,'' AS content_html,t.summary,t.is_del,t.category_id,t.dateline,t.hit,t.status,t.publish_at,t.slug,t.deleted_at,t.deleted_by,t.author_id,t.last_edited_by,t.word_count,t.reading_time,t.pin_scope,t.pin_order,t.is_featured,t.version,
+                    (CASE WHEN t.category_id = src.category_id THEN 1 ELSE 0 END)
+                    + (CASE WHEN $2::text = '' THEN 0 ELSE ts_rank(t.search_vector, to_tsquery('simple', $2::text)) * 4 END)
+                    + similarity(t.title, src.title) * 2
+                    + (
+                        SELECT COUNT(*) FROM topic_tags a INNER JOIN topic_tags b ON b.tag_id = a.tag_id
+                        WHERE a.topic_id = src.id AND b.topic_id = t.id
+                    ) * 0.5 AS score
+                FROM topics t, topics src
+                WHERE src.id = $1 AND t.id <> src.id AND t.is_del = false AND t.status = 1
+                    AND t.category_id IN (SELECT id FROM categories WHERE is_del = false)
+            ) AS related
+            WHERE score > 0
+            ORDER BY score DESC, dateline DESC
+            LIMIT $3"#,
+        )
+        .bind(id)
+        .bind(&ts_query)
+        .bind(limit)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let topics = rows.iter().map(topic_from_row).collect();
+        Ok(tonic::Response::new(GetRelatedTopicsReply { topics }))
+    }
+
+    async fn list_archive(
+        &self,
+        request: tonic::Request<ListArchiveRequest>,
+    ) -> Result<tonic::Response<ListArchiveReply>, tonic::Status> {
+        let ListArchiveRequest {
+            is_del,
+            status,
+            category_is_del,
+        } = request.into_inner();
+        let status = match status {
+            Some(status) => {
+                Some(topic_status(status).map_err(tonic::Status::invalid_argument)? as i16)
+            }
+            None => None,
+        };
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                EXTRACT(YEAR FROM dateline)::int AS year,
+                EXTRACT(MONTH FROM dateline)::int AS month,
+                COUNT(*) AS topic_count
+            FROM topics
+            WHERE ($1::boolean IS NULL OR is_del = $1::boolean)
+                AND ($2::smallint IS NULL OR status = $2::smallint)
+                AND ($3::boolean IS NULL OR category_id IN (SELECT id FROM categories WHERE is_del = $3::boolean))
+            GROUP BY year, month
+            ORDER BY year DESC, month DESC"#,
+        )
+        .bind(is_del)
+        .bind(status)
+        .bind(category_is_del)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let archives = rows
+            .iter()
+            .map(|row| Archive {
+                year: row.get("year"),
+                month: row.get("month"),
+                topic_count: row.get("topic_count"),
+            })
+            .collect();
+        Ok(tonic::Response::new(ListArchiveReply { archives }))
+    }
+
+    type ExportTopicsStream = ReceiverStream<Result<blog_proto::Topic, tonic::Status>>;
+
+    async fn export_topics(
+        &self,
+        _request: tonic::Request<ExportTopicsRequest>,
+    ) -> Result<tonic::Response<Self::ExportTopicsStream>, tonic::Status> {
+        let (sender, receiver) = mpsc::channel(EXPORT_BUFFER);
+        let pool = self.pool.clone();
+        tokio::spawn(async move {
+            if let Err(err) = transfer::export(&pool, &sender).await {
+                sender.send(Err(err)).await.ok();
+            }
+        });
+        Ok(tonic::Response::new(ReceiverStream::new(receiver)))
+    }
+
+    async fn import_topics(
+        &self,
+        request: tonic::Request<tonic::Streaming<blog_proto::Topic>>,
+    ) -> Result<tonic::Response<ImportTopicsReply>, tonic::Status> {
+        let mut stream = request.into_inner();
+        let mut reply = ImportTopicsReply::default();
+        let mut index = 0;
+        // 没有ID的文章最后导入，避免自动生成的ID占用之后的文章指定的ID
+        let mut without_id = vec![];
+        while let Some(topic) = stream.message().await? {
+            if topic.id > 0 {
+                let result = transfer::import(&self.pool, topic, self.summary_len).await;
+                push_import_result(&mut reply, index, result);
+            } else {
+                without_id.push((index, topic));
+            }
+            index += 1;
+        }
+        if !without_id.is_empty() {
+            transfer::sync_id_sequence(&self.pool).await?;
+        }
+        for (index, topic) in without_id {
+            let result = transfer::import(&self.pool, topic, self.summary_len).await;
+            push_import_result(&mut reply, index, result);
+        }
+        reply.results.sort_by_key(|r| r.index);
+        if reply.created > 0 {
+            transfer::sync_id_sequence(&self.pool).await?;
+        }
+        Ok(tonic::Response::new(reply))
+    }
+
+    async fn delete_topics(
+        &self,
+        request: tonic::Request<BulkTopicRequest>,
+    ) -> Result<tonic::Response<BulkTopicReply>, tonic::Status> {
+        let BulkTopicRequest { ids, admin_id } = request.into_inner();
+        let reply = bulk::apply(&self.pool, &ids, bulk::Action::Delete(admin_id)).await?;
+        Ok(tonic::Response::new(reply))
+    }
+
+    async fn restore_topics(
+        &self,
+        request: tonic::Request<BulkTopicRequest>,
+    ) -> Result<tonic::Response<BulkTopicReply>, tonic::Status> {
+        let BulkTopicRequest { ids, .. } = request.into_inner();
+        let reply = bulk::apply(&self.pool, &ids, bulk::Action::Restore).await?;
+        Ok(tonic::Response::new(reply))
+    }
+
+    async fn move_topics(
+        &self,
+        request: tonic::Request<MoveTopicsRequest>,
+    ) -> Result<tonic::Response<BulkTopicReply>, tonic::Status> {
+        let MoveTopicsRequest { ids, category_id } = request.into_inner();
+        let reply = bulk::apply(&self.pool, &ids, bulk::Action::Move(category_id)).await?;
+        Ok(tonic::Response::new(reply))
+    }
+
+    async fn purge_topics(
+        &self,
+        request: tonic::Request<BulkTopicRequest>,
+    ) -> Result<tonic::Response<BulkTopicReply>, tonic::Status> {
+        let BulkTopicRequest { ids, .. } = request.into_inner();
+        let reply = bulk::apply(&self.pool, &ids, bulk::Action::Purge).await?;
+        Ok(tonic::Response::new(reply))
+    }
+
+    async fn pin_topic(
+        &self,
+        request: tonic::Request<PinTopicRequest>,
+    ) -> Result<tonic::Response<PinTopicReply>, tonic::Status> {
+        let PinTopicRequest { id, scope } = request.into_inner();
+        let scope = PinScope::try_from(scope)
+            .map_err(|_| tonic::Status::invalid_argument("错误的置顶范围"))?;
+        let ok = pin::pin(&self.pool, id, scope).await?;
+        Ok(tonic::Response::new(PinTopicReply { id, ok }))
+    }
+
+    async fn reorder_pinned_topics(
+        &self,
+        request: tonic::Request<ReorderPinnedTopicsRequest>,
+    ) -> Result<tonic::Response<ReorderPinnedTopicsReply>, tonic::Status> {
+        let ReorderPinnedTopicsRequest { ids } = request.into_inner();
+        let updated = pin::reorder(&self.pool, &ids).await?;
+        Ok(tonic::Response::new(ReorderPinnedTopicsReply { updated }))
+    }
+
+    async fn list_pinned_topics(
+        &self,
+        _request: tonic::Request<ListPinnedTopicsRequest>,
+    ) -> Result<tonic::Response<ListPinnedTopicsReply>, tonic::Status> {
+        let topics = pin::list_pinned(&self.pool).await?;
+        Ok(tonic::Response::new(ListPinnedTopicsReply { topics }))
+    }
+
+    async fn feature_topic(
+        &self,
+        request: tonic::Request<FeatureTopicRequest>,
+    ) -> Result<tonic::Response<FeatureTopicReply>, tonic::Status> {
+        let FeatureTopicRequest { id, featured } = request.into_inner();
+        let ok = pin::feature(&self.pool, id, featured).await?;
+        Ok(tonic::Response::new(FeatureTopicReply { id, ok }))
+    }
+
+    async fn list_featured_topics(
+        &self,
+        request: tonic::Request<ListFeaturedTopicsRequest>,
+    ) -> Result<tonic::Response<ListFeaturedTopicsReply>, tonic::Status> {
+        let ListFeaturedTopicsRequest { limit } = request.into_inner();
+        let mut topics = pin::list_featured(&self.pool, limit).await?;
+        tag::fill_topic_tags(&*self.pool, &mut topics).await?;
+        Ok(tonic::Response::new(ListFeaturedTopicsReply { topics }))
+    }
+}
+
+/// 按分类筛选文章时的分类ID，包括下级分类时查询出所有下级分类
+async fn category_ids(
+    pool: &PgPool,
+    category_id: Option<i32>,
+    include_descendants: bool,
+) -> Result<Option<Vec<i32>>, tonic::Status> {
+    let Some(category_id) = category_id else {
+        return Ok(None);
+    };
+    if !include_descendants {
+        return Ok(Some(vec![category_id]));
+    }
+    let rows = sqlx::query(
+        r#"
+        WITH RECURSIVE descendants AS (
+            SELECT $1::int AS id
+            UNION
+            SELECT c.id FROM categories c INNER JOIN descendants d ON c.parent_id = d.id
+        )
+        SELECT id FROM descendants"#,
+    )
+    .bind(category_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok(Some(rows.iter().map(|row| row.get("id")).collect()))
 }
 
-fn get_summary(content: &str) -> String {
-    if content.len() <= 255 {
-        return String::from(content);
+/// 文章列表排序字段的 SQL 表达式、类型，以及是否倒序
+fn sort_column(sort: TopicSort) -> (&'static str, &'static str, bool) {
+    match sort {
+        TopicSort::Dateline => ("dateline", "TIMESTAMPTZ", true),
+        TopicSort::Hit => ("hit", "INT", true),
+        TopicSort::Title => ("title", "VARCHAR", false),
+        TopicSort::WordCount => ("word_count", "INT", true),
+        // $4 为全文检索的查询条件
+        TopicSort::Relevance => (
+            "CASE WHEN $4::text IS NULL THEN 0 ELSE ts_rank_cd(search_vector, plainto_tsquery('simple', $4::text)) END",
+            "REAL",
+            true,
+        ),
     }
-    content.chars().into_iter().take(255).collect()
 }
 
-fn dt_conver(dt: &DateTime<Local>) -> Option<prost_types::Timestamp> {
-    if let Ok(dt) = prost_types::Timestamp::date_time(
-        dt.year().into(),
-        dt.month() as u8,
-        dt.day() as u8,
-        dt.hour() as u8,
-        dt.minute() as u8,
-        dt.second() as u8,
-    ) {
-        Some(dt)
-    } else {
-        None
+pub fn topic_from_row(row: &PgRow) -> blog_proto::Topic {
+    let dt: DateTime<Local> = row.get("dateline");
+    let publish_at: Option<DateTime<Local>> = row.get("publish_at");
+    let deleted_at: Option<DateTime<Local>> = row.get("deleted_at");
+    let status: i16 = row.get("status");
+    blog_proto::Topic {
+        id: row.get("id"),
+        title: row.get("title"),
+        category_id: row.get("category_id"),
+        content: row.get("content"),
+        content_html: row.get("content_html"),
+        summary: row.get("summary"),
+        hit: row.get("hit"),
+        is_del: row.get("is_del"),
+        dateline: dt_conver(&dt),
+        status: status.into(),
+        publish_at: publish_at.and_then(|dt| dt_conver(&dt)),
+        tags: vec![],
+        slug: row.get("slug"),
+        deleted_at: deleted_at.and_then(|dt| dt_conver(&dt)),
+        deleted_by: row.get("deleted_by"),
+        author_id: row.get("author_id"),
+        last_edited_by: row.get("last_edited_by"),
+        word_count: row.get("word_count"),
+        reading_time: row.get("reading_time"),
+        pin_scope: row.get::<i16, _>("pin_scope").into(),
+        pin_order: row.get("pin_order"),
+        is_featured: row.get("is_featured"),
+        version: row.get("version"),
+    }
+}
+
+/// 记录一篇文章的导入结果
+fn push_import_result(
+    reply: &mut ImportTopicsReply,
+    index: i32,
+    result: Result<(i64, String, ImportAction), tonic::Status>,
+) {
+    let result = match result {
+        Ok((id, slug, action)) => {
+            match action {
+                ImportAction::Created => reply.created += 1,
+                _ => reply.updated += 1,
+            }
+            ImportTopicResult {
+                index,
+                id,
+                slug,
+                action: action.into(),
+                message: String::new(),
+            }
+        }
+        Err(err) => {
+            reply.failed += 1;
+            ImportTopicResult {
+                index,
+                action: ImportAction::Failed.into(),
+                message: err.message().to_string(),
+                ..Default::default()
+            }
+        }
+    };
+    reply.results.push(result);
+}
+
+/// 恢复修订即用修订的内容修改文章，执行恢复的管理员记录为最后修改者
+fn restore_request(
+    revision: TopicRevision,
+    version: Option<i32>,
+    admin_id: Option<i32>,
+) -> EditTopicRequest {
+    EditTopicRequest {
+        id: revision.topic_id,
+        title: revision.title,
+        category_id: revision.category_id,
+        summary: Some(revision.summary),
+        content: revision.content,
+        author_id: admin_id,
+        version,
+        ..Default::default()
     }
 }
 
-fn tm_cover(tm: Option<prost_types::Timestamp>) -> Option<DateTime<Local>> {
+pub fn topic_status(status: i32) -> Result<TopicStatus, &'static str> {
+    TopicStatus::try_from(status).map_err(|_| "错误的文章状态")
+}
+
+/// 定时发布的文章必须指定晚于当前的发布时间，其它状态忽略定时发布时间
+fn check_publish_at(
+    status: TopicStatus,
+    publish_at: Option<prost_types::Timestamp>,
+) -> Result<Option<DateTime<Local>>, &'static str> {
+    if status != TopicStatus::Scheduled {
+        return Ok(None);
+    }
+    match try_tm_cover(publish_at)? {
+        Some(publish_at) if publish_at > Local::now() => Ok(Some(publish_at)),
+        Some(_) => Err("定时发布的时间必须晚于当前时间"),
+        None => Err("请指定定时发布的时间"),
+    }
+}
+
+fn revision_from_row(row: &PgRow) -> TopicRevision {
+    let dt: DateTime<Local> = row.get("dateline");
+    TopicRevision {
+        id: row.get("id"),
+        topic_id: row.get("topic_id"),
+        title: row.get("title"),
+        category_id: row.get("category_id"),
+        summary: row.get("summary"),
+        content: row.get("content"),
+        dateline: dt_conver(&dt),
+    }
+}
+
+/// 将文章的当前内容保存为一条修订
+pub async fn save_revision(
+    tx: &mut Transaction<'_, Postgres>,
+    topic_id: i64,
+) -> Result<(), tonic::Status> {
+    sqlx::query(
+        r#"
+        INSERT INTO topic_revisions
+            (topic_id,title,category_id,summary,content)
+        SELECT id,title,category_id,summary,content FROM topics WHERE id=$1"#,
+    )
+    .bind(topic_id)
+    .execute(&mut **tx)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok(())
+}
+
+pub fn dt_conver(dt: &DateTime<Local>) -> Option<prost_types::Timestamp> {
+    Some(prost_types::Timestamp {
+        seconds: dt.timestamp(),
+        nanos: 0,
+    })
+}
+
+/// 转换客户端传入的时间，超出范围时返回错误
+pub fn try_tm_cover(
+    tm: Option<prost_types::Timestamp>,
+) -> Result<Option<DateTime<Local>>, &'static str> {
     match tm {
-        Some(tm) => Some(Local.timestamp_opt(tm.seconds, 0).unwrap()),
-        None => None,
+        Some(tm) => match Local.timestamp_opt(tm.seconds, 0).single() {
+            Some(dt) => Ok(Some(dt)),
+            None => Err("错误的时间"),
+        },
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restore_request_records_editor() {
+        let revision = TopicRevision {
+            id: 3,
+            topic_id: 7,
+            title: "标题".to_string(),
+            category_id: 2,
+            summary: "摘要".to_string(),
+            content: "内容".to_string(),
+            ..Default::default()
+        };
+        let req = restore_request(revision, Some(5), Some(1));
+        assert_eq!(req.id, 7);
+        assert_eq!(req.title, "标题");
+        assert_eq!(req.summary.as_deref(), Some("摘要"));
+        assert_eq!(req.version, Some(5));
+        // 执行恢复的管理员作为最后修改者
+        assert_eq!(req.author_id, Some(1));
+        // 恢复修订不改变状态、发布时间、标签和永久链接
+        assert!(req.status.is_none() && req.dateline.is_none());
+        assert!(req.tags.is_none() && req.slug.is_none());
     }
 }
@@ -0,0 +1,157 @@
+// 文章摘要：优先使用 <!--more--> 之前的内容，否则从纯文本中按句子截取
+use crate::markdown;
+
+/// 摘要长度的上限（字符数），与数据库中摘要字段的长度一致
+pub const MAX_LEN: usize = 255;
+/// 按句子截取时，摘要至少保留的比例，句子过长时改为按词截取
+const MIN_RATIO: usize = 2;
+/// 截断时追加的省略号
+const ELLIPSIS: char = '…';
+
+/// 从 Markdown 内容中提取摘要，max_len 为字符数
+pub fn extract(content: &str, max_len: usize) -> String {
+    let max_len = max_len.clamp(1, MAX_LEN);
+    let source = match find_more(content) {
+        Some(pos) => &content[..pos],
+        None => content,
+    };
+    let text = collapse_whitespace(&markdown::to_plain_text(source));
+    truncate(&text, max_len)
+}
+
+/// 查找 <!--more--> 标记的位置，允许空白和大写，例如 <!-- More -->
+fn find_more(content: &str) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(start) = content[offset..].find("<!--") {
+        let start = offset + start;
+        let rest = &content[start + 4..];
+        let end = rest.find("-->")?;
+        if rest[..end].trim().eq_ignore_ascii_case("more") {
+            return Some(start);
+        }
+        offset = start + 4 + end + 3;
+    }
+    None
+}
+
+/// 把换行和连续的空白合并为一个空格
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 截取到 max_len 个字符以内：优先在句子结束处截断，其次在词的边界截断并追加省略号
+fn truncate(text: &str, max_len: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    if chars.len() <= max_len {
+        return text.to_string();
+    }
+    let min_len = max_len / MIN_RATIO;
+
+    // 句子结束处，包括紧随其后的引号和括号
+    let mut sentence_end = None;
+    for i in 0..max_len {
+        if is_sentence_end(&chars, i) {
+            let mut end = i + 1;
+            while end < max_len && is_closing(chars[end]) {
+                end += 1;
+            }
+            sentence_end = Some(end);
+        }
+    }
+    if let Some(end) = sentence_end.filter(|&end| end >= min_len) {
+        return chars[..end].iter().collect();
+    }
+
+    // 给省略号留出位置，拉丁文字在空白处截断，避免截断单词
+    let limit = max_len - 1;
+    let end = match chars[..=limit].iter().rposition(|c| c.is_whitespace()) {
+        Some(pos) if pos >= min_len && chars[limit].is_ascii_alphanumeric() => pos,
+        _ => limit,
+    };
+    let mut summary: String = chars[..end].iter().collect();
+    let trimmed = summary
+        .trim_end_matches(|c: char| c.is_whitespace() || ",，、;；:：".contains(c))
+        .len();
+    summary.truncate(trimmed);
+    summary.push(ELLIPSIS);
+    summary
+}
+
+/// 中文的句号等直接结束句子；英文的句号等后面必须是空白或文本结束，避免截断小数和缩写
+fn is_sentence_end(chars: &[char], i: usize) -> bool {
+    match chars[i] {
+        '。' | '！' | '？' | '…' => true,
+        '.' | '!' | '?' => {
+            let mut next = i + 1;
+            while next < chars.len() && is_closing(chars[next]) {
+                next += 1;
+            }
+            chars.get(next).is_none_or(|c| c.is_whitespace())
+        }
+        _ => false,
+    }
+}
+
+fn is_closing(c: char) -> bool {
+    "”’」』）)\"'".contains(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_more_marker() {
+        let content = "## 简介\n\n这是**摘要**部分。\n\n<!-- more -->\n\n这是正文。";
+        assert_eq!(extract(content, MAX_LEN), "简介 这是摘要部分。");
+        assert_eq!(extract("前言<!--MORE-->正文", MAX_LEN), "前言");
+        // 标记之前的内容过长时仍然截取
+        let content = format!("{}<!--more-->", "很长的句子。".repeat(10));
+        assert_eq!(extract(&content, 20), "很长的句子。".repeat(3));
+        // 其它注释不是标记
+        assert_eq!(extract("a<!-- note -->b", MAX_LEN), "ab");
+    }
+
+    #[test]
+    fn test_extract_strip_markup() {
+        let content =
+            "# Title\n\nSome *emphasis* and <span>html</span> with [a link](https://example.com).";
+        assert_eq!(
+            extract(content, MAX_LEN),
+            "Title Some emphasis and html with a link."
+        );
+    }
+
+    #[test]
+    fn test_extract_cjk_sentence() {
+        // 按字符而不是字节计算长度
+        let content = "中".repeat(200);
+        assert_eq!(extract(&content, MAX_LEN), content);
+
+        let content = "第一句话比较短。第二句话稍微长一点！第三句话会超出长度限制。";
+        assert_eq!(extract(content, 20), "第一句话比较短。第二句话稍微长一点！");
+        let content = "他说：“今天天气很好。”然后就出门了，一直走到很远的地方。";
+        assert_eq!(extract(content, 20), "他说：“今天天气很好。”");
+    }
+
+    #[test]
+    fn test_extract_latin_sentence() {
+        let content =
+            "Rust is fast. It costs 3.14 dollars! Memory safety without garbage collection.";
+        assert_eq!(extract(content, 40), "Rust is fast. It costs 3.14 dollars!");
+        // 句子过长时在词的边界截断
+        let content = "Rust empowers everyone to build reliable and efficient software.";
+        assert_eq!(extract(content, 30), "Rust empowers everyone to…");
+    }
+
+    #[test]
+    fn test_extract_mixed() {
+        let content = "使用 Rust 和 axum 构建分布式博客。本文介绍 gRPC 服务的拆分方式。";
+        assert_eq!(extract(content, 30), "使用 Rust 和 axum 构建分布式博客。");
+        // 没有句子和词的边界时直接截断
+        let content = "没有标点的中文内容会在长度限制处直接截断并加上省略号";
+        let summary = extract(content, 10);
+        assert_eq!(summary, "没有标点的中文内容…");
+        assert_eq!(summary.chars().count(), 10);
+    }
+}
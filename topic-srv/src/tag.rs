@@ -0,0 +1,280 @@
+use std::{collections::HashMap, sync::Arc};
+
+use blog_proto::{
+    tag_service_server::TagService, CreateTagReply, CreateTagRequest, ListTagReply, ListTagRequest,
+    MergeTagReply, MergeTagRequest, RenameTagReply, RenameTagRequest,
+};
+use sqlx::{PgExecutor, PgPool, Postgres, Row, Transaction};
+
+use crate::server::topic_status;
+
+/// 标签名称的最大长度（字符数）
+const NAME_MAX_LEN: usize = 50;
+
+pub struct Tag {
+    pool: Arc<PgPool>,
+}
+
+impl Tag {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool: Arc::new(pool),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl TagService for Tag {
+    async fn create_tag(
+        &self,
+        request: tonic::Request<CreateTagRequest>,
+    ) -> Result<tonic::Response<CreateTagReply>, tonic::Status> {
+        let CreateTagRequest { name } = request.into_inner();
+        let name = check_name(&name).map_err(tonic::Status::invalid_argument)?;
+        let row = sqlx::query(
+            "INSERT INTO tags (name) VALUES ($1) ON CONFLICT (name) DO NOTHING RETURNING id",
+        )
+        .bind(name)
+        .fetch_optional(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        match row {
+            Some(row) => Ok(tonic::Response::new(CreateTagReply { id: row.get("id") })),
+            None => Err(tonic::Status::already_exists("标签已存在")),
+        }
+    }
+
+    async fn rename_tag(
+        &self,
+        request: tonic::Request<RenameTagRequest>,
+    ) -> Result<tonic::Response<RenameTagReply>, tonic::Status> {
+        let RenameTagRequest { id, name } = request.into_inner();
+        let name = check_name(&name).map_err(tonic::Status::invalid_argument)?;
+        let exists: Option<i32> = sqlx::query("SELECT id FROM tags WHERE name=$1")
+            .bind(name)
+            .fetch_optional(&*self.pool)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?
+            .map(|row| row.get("id"));
+        if exists.is_some_and(|exists| exists != id) {
+            return Err(tonic::Status::already_exists("标签已存在，请使用合并"));
+        }
+        let rows_affected = sqlx::query("UPDATE tags SET name=$1 WHERE id=$2")
+            .bind(name)
+            .bind(id)
+            .execute(&*self.pool)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?
+            .rows_affected();
+        Ok(tonic::Response::new(RenameTagReply {
+            id,
+            ok: rows_affected > 0,
+        }))
+    }
+
+    async fn merge_tag(
+        &self,
+        request: tonic::Request<MergeTagRequest>,
+    ) -> Result<tonic::Response<MergeTagReply>, tonic::Status> {
+        let MergeTagRequest { from_ids, to_id } = request.into_inner();
+        let from_ids: Vec<i32> = from_ids.into_iter().filter(|&id| id != to_id).collect();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let exists = sqlx::query("SELECT id FROM tags WHERE id=$1 FOR UPDATE")
+            .bind(to_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        if exists.is_none() {
+            return Err(tonic::Status::not_found("不存在的标签"));
+        }
+        sqlx::query(
+            r#"
+            INSERT INTO topic_tags (topic_id, tag_id)
+            SELECT topic_id, $1 FROM topic_tags WHERE tag_id = ANY($2)
+            ON CONFLICT DO NOTHING"#,
+        )
+        .bind(to_id)
+        .bind(&from_ids)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        sqlx::query("DELETE FROM topic_tags WHERE tag_id = ANY($1)")
+            .bind(&from_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        sqlx::query("DELETE FROM tags WHERE id = ANY($1)")
+            .bind(&from_ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let row = sqlx::query("SELECT COUNT(*) FROM topic_tags WHERE tag_id=$1")
+            .bind(to_id)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        tx.commit()
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        Ok(tonic::Response::new(MergeTagReply {
+            to_id,
+            topic_count: row.get(0),
+        }))
+    }
+
+    async fn list_tag(
+        &self,
+        request: tonic::Request<ListTagRequest>,
+    ) -> Result<tonic::Response<ListTagReply>, tonic::Status> {
+        let ListTagRequest {
+            name,
+            is_del,
+            status,
+            category_is_del,
+        } = request.into_inner();
+        let status = match status {
+            Some(status) => {
+                Some(topic_status(status).map_err(tonic::Status::invalid_argument)? as i16)
+            }
+            None => None,
+        };
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                tg.id, tg.name, COUNT(t.id) AS topic_count
+            FROM tags tg
+            LEFT JOIN topic_tags tt ON tt.tag_id = tg.id
+            LEFT JOIN topics t ON t.id = tt.topic_id
+                AND ($2::boolean IS NULL OR t.is_del = $2::boolean)
+                AND ($3::smallint IS NULL OR t.status = $3::smallint)
+                AND ($4::boolean IS NULL OR t.category_id IN (SELECT id FROM categories WHERE is_del = $4::boolean))
+            WHERE ($1::varchar IS NULL OR tg.name = $1::varchar)
+            GROUP BY tg.id, tg.name
+            ORDER BY topic_count DESC, tg.name"#,
+        )
+        .bind(name)
+        .bind(is_del)
+        .bind(status)
+        .bind(category_is_del)
+        .fetch_all(&*self.pool)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let tags = rows
+            .iter()
+            .map(|row| blog_proto::Tag {
+                id: row.get("id"),
+                name: row.get("name"),
+                topic_count: row.get("topic_count"),
+            })
+            .collect();
+        Ok(tonic::Response::new(ListTagReply { tags }))
+    }
+}
+
+fn check_name(name: &str) -> Result<&str, &'static str> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("标签名称不能为空");
+    }
+    if name.chars().count() > NAME_MAX_LEN {
+        return Err("标签名称过长");
+    }
+    Ok(name)
+}
+
+/// 整理文章的标签：去掉首尾空白、空标签、过长的标签和重复的标签，保持原有顺序
+pub fn normalize_names(names: &[String]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::with_capacity(names.len());
+    for name in names {
+        if let Ok(name) = check_name(name) {
+            if !out.iter().any(|n| n == name) {
+                out.push(name.to_string());
+            }
+        }
+    }
+    out
+}
+
+/// 设置文章的标签，不存在的标签会自动创建
+pub async fn save_topic_tags(
+    tx: &mut Transaction<'_, Postgres>,
+    topic_id: i64,
+    names: &[String],
+) -> Result<(), tonic::Status> {
+    let names = normalize_names(names);
+    sqlx::query(
+        "INSERT INTO tags (name) SELECT UNNEST($1::varchar[]) ON CONFLICT (name) DO NOTHING",
+    )
+    .bind(&names)
+    .execute(&mut **tx)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    sqlx::query("DELETE FROM topic_tags WHERE topic_id=$1")
+        .bind(topic_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    sqlx::query(
+        "INSERT INTO topic_tags (topic_id, tag_id) SELECT $1, id FROM tags WHERE name = ANY($2)",
+    )
+    .bind(topic_id)
+    .bind(&names)
+    .execute(&mut **tx)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok(())
+}
+
+/// 为文章列表填充标签
+pub async fn fill_topic_tags<'e>(
+    executor: impl PgExecutor<'e>,
+    topics: &mut [blog_proto::Topic],
+) -> Result<(), tonic::Status> {
+    if topics.is_empty() {
+        return Ok(());
+    }
+    let ids: Vec<i64> = topics.iter().map(|t| t.id).collect();
+    let rows = sqlx::query(
+        r#"
+        SELECT tt.topic_id, tg.name
+        FROM topic_tags tt
+        INNER JOIN tags tg ON tg.id = tt.tag_id
+        WHERE tt.topic_id = ANY($1)
+        ORDER BY tg.name"#,
+    )
+    .bind(&ids)
+    .fetch_all(executor)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    let mut tags: HashMap<i64, Vec<String>> = HashMap::new();
+    for row in rows {
+        tags.entry(row.get("topic_id"))
+            .or_default()
+            .push(row.get("name"));
+    }
+    for topic in topics {
+        topic.tags = tags.remove(&topic.id).unwrap_or_default();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_names() {
+        let names = vec![
+            " rust ".to_string(),
+            "".to_string(),
+            "axum".to_string(),
+            "rust".to_string(),
+            "长".repeat(NAME_MAX_LEN + 1),
+        ];
+        assert_eq!(normalize_names(&names), vec!["rust", "axum"]);
+    }
+}
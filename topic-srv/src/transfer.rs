@@ -0,0 +1,233 @@
+// 导出和导入文章，用于备份和在不同环境之间迁移
+use blog_proto::{ImportAction, PinScope, Topic, TopicStatus};
+use chrono::Local;
+use sqlx::{PgPool, Row};
+use tokio::sync::mpsc;
+
+use crate::{markdown, permalink, reading, search, server, summary, tag};
+
+/// 导出时每次从数据库读取的条数
+const EXPORT_BATCH_SIZE: i64 = 100;
+
+/// 在同一个只读事务中分批读取，保证导出的是同一时刻的数据
+pub async fn export(
+    pool: &PgPool,
+    sender: &mpsc::Sender<Result<Topic, tonic::Status>>,
+) -> Result<(), tonic::Status> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ ONLY")
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    let mut last_id = 0;
+    loop {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id,title,content,content_html,summary,is_del,category_id,dateline,hit,status,publish_at,slug,deleted_at,deleted_by,author_id,last_edited_by,word_count,reading_time,pin_scope,pin_order,is_featured,version
+            FROM topics
+            WHERE id > $1
+            ORDER BY id
+            LIMIT $2"#,
+        )
+        .bind(last_id)
+        .bind(EXPORT_BATCH_SIZE)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+        let mut topics: Vec<Topic> = rows.iter().map(server::topic_from_row).collect();
+        match topics.last() {
+            Some(topic) => last_id = topic.id,
+            None => break,
+        }
+        tag::fill_topic_tags(&mut *tx, &mut topics).await?;
+        for topic in topics {
+            // 客户端已断开连接
+            if sender.send(Ok(topic)).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+    tx.commit()
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok(())
+}
+
+/// 导入一篇文章，返回文章ID、永久链接以及是新建还是更新
+pub async fn import(
+    pool: &PgPool,
+    topic: Topic,
+    summary_len: usize,
+) -> Result<(i64, String, ImportAction), tonic::Status> {
+    if topic.title.trim().is_empty() {
+        return Err(tonic::Status::invalid_argument("标题不能为空"));
+    }
+    let status = server::topic_status(topic.status).map_err(tonic::Status::invalid_argument)?;
+    let pin_scope = PinScope::try_from(topic.pin_scope)
+        .map_err(|_| tonic::Status::invalid_argument("错误的置顶范围"))?;
+    let publish_at =
+        server::try_tm_cover(topic.publish_at).map_err(tonic::Status::invalid_argument)?;
+    let publish_at = match status {
+        TopicStatus::Scheduled => match publish_at {
+            Some(publish_at) => Some(publish_at),
+            None => return Err(tonic::Status::invalid_argument("请指定定时发布的时间")),
+        },
+        _ => None,
+    };
+    let dateline = server::try_tm_cover(topic.dateline)
+        .map_err(tonic::Status::invalid_argument)?
+        .unwrap_or_else(Local::now);
+    // 没有删除时间的已删除文章，从导入时开始计算回收站的保留期
+    let deleted_at =
+        server::try_tm_cover(topic.deleted_at).map_err(tonic::Status::invalid_argument)?;
+    let deleted_at = topic.is_del.then(|| deleted_at.unwrap_or_else(Local::now));
+    let deleted_by = topic.deleted_by.filter(|_| topic.is_del);
+    let plain_text = markdown::to_plain_text(&topic.content);
+    // 字数和阅读时间总是按导入的内容重新计算
+    let reading = reading::measure(&plain_text);
+    let summary = if topic.summary.is_empty() {
+        summary::extract(&topic.content, summary_len)
+    } else {
+        topic.summary.clone()
+    };
+    let slug = if topic.slug.trim().is_empty() {
+        &topic.title
+    } else {
+        &topic.slug
+    };
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    // 依次按ID、永久链接、旧的永久链接查找已有的文章，修改过永久链接的文章再次导入时仍能找到
+    let existing: Option<i64> = sqlx::query(
+        r#"
+        SELECT id FROM (
+            SELECT id, 1 AS priority FROM topics WHERE id = $1
+            UNION ALL
+            SELECT id, 2 FROM topics WHERE $2 <> '' AND slug = $2
+            UNION ALL
+            SELECT topic_id, 3 FROM topic_slugs WHERE $2 <> '' AND slug = $2
+        ) AS matched
+        ORDER BY priority
+        LIMIT 1"#,
+    )
+    .bind(topic.id)
+    .bind(topic.slug.trim())
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?
+    .map(|row| row.get("id"));
+
+    let (id, action) = match existing {
+        Some(id) => {
+            sqlx::query(
+                r#"
+                UPDATE topics SET
+                    title=$1,category_id=$2,content=$3,content_html=$4,summary=$5,
+                    search_vector=setweight(to_tsvector('simple', $6), 'A') || setweight(to_tsvector('simple', $7), 'B') || setweight(to_tsvector('simple', $8), 'C'),
+                    hit=$9,is_del=$10,status=$11,publish_at=$12,dateline=$13,deleted_at=$15,deleted_by=$16,author_id=$17,last_edited_by=$23,
+                    word_count=$18,reading_time=$19,pin_scope=$20,pin_order=$21,is_featured=$22,
+                    version=version+1
+                WHERE id=$14"#,
+            )
+            .bind(&topic.title)
+            .bind(topic.category_id)
+            .bind(&topic.content)
+            .bind(markdown::render(&topic.content))
+            .bind(&summary)
+            .bind(search::to_document(&topic.title))
+            .bind(search::to_document(&summary))
+            .bind(search::to_document(&plain_text))
+            .bind(topic.hit)
+            .bind(topic.is_del)
+            .bind(status as i16)
+            .bind(publish_at)
+            .bind(dateline)
+            .bind(id)
+            .bind(deleted_at)
+            .bind(deleted_by)
+            .bind(topic.author_id)
+            .bind(reading.word_count)
+            .bind(reading.reading_time)
+            .bind(pin_scope as i16)
+            .bind(topic.pin_order)
+            .bind(topic.is_featured)
+            .bind(topic.last_edited_by)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            permalink::change_slug(&mut tx, id, slug).await?;
+            (id, ImportAction::Updated)
+        }
+        None => {
+            let slug = permalink::unique_slug(&mut tx, slug, None).await?;
+            // 保留原来的ID，以免旧的文章地址失效。没有找到相同ID的文章，因此该ID未被占用
+            let row = sqlx::query(
+                r#"
+                INSERT INTO topics
+                    (id,title,category_id,content,content_html,summary,search_vector,hit,is_del,status,publish_at,dateline,slug,deleted_at,deleted_by,author_id,last_edited_by,word_count,reading_time,pin_scope,pin_order,is_featured)
+                VALUES
+                    (COALESCE($1::bigint, nextval(pg_get_serial_sequence('topics', 'id'))), $2, $3, $4, $5, $6,
+                    setweight(to_tsvector('simple', $7), 'A') || setweight(to_tsvector('simple', $8), 'B') || setweight(to_tsvector('simple', $9), 'C'),
+                    $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24)
+                RETURNING id"#,
+            )
+            .bind(Some(topic.id).filter(|&id| id > 0))
+            .bind(&topic.title)
+            .bind(topic.category_id)
+            .bind(&topic.content)
+            .bind(markdown::render(&topic.content))
+            .bind(&summary)
+            .bind(search::to_document(&topic.title))
+            .bind(search::to_document(&summary))
+            .bind(search::to_document(&plain_text))
+            .bind(topic.hit)
+            .bind(topic.is_del)
+            .bind(status as i16)
+            .bind(publish_at)
+            .bind(dateline)
+            .bind(&slug)
+            .bind(deleted_at)
+            .bind(deleted_by)
+            .bind(topic.author_id)
+            .bind(topic.last_edited_by)
+            .bind(reading.word_count)
+            .bind(reading.reading_time)
+            .bind(pin_scope as i16)
+            .bind(topic.pin_order)
+            .bind(topic.is_featured)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|err| tonic::Status::internal(err.to_string()))?;
+            (row.get("id"), ImportAction::Created)
+        }
+    };
+    tag::save_topic_tags(&mut tx, id, &topic.tags).await?;
+    server::save_revision(&mut tx, id).await?;
+    let row = sqlx::query("SELECT slug FROM topics WHERE id=$1")
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    tx.commit()
+        .await
+        .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok((id, row.get("slug"), action))
+}
+
+/// 使用指定的ID创建文章后，让自增序列从最大的ID之后开始
+pub async fn sync_id_sequence(pool: &PgPool) -> Result<(), tonic::Status> {
+    sqlx::query(
+        "SELECT setval(pg_get_serial_sequence('topics', 'id'), GREATEST((SELECT MAX(id) FROM topics), 1))",
+    )
+    .execute(pool)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?;
+    Ok(())
+}
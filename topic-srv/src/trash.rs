@@ -0,0 +1,36 @@
+// 回收站清理：周期性地彻底删除超过保留期的文章
+use std::time::Duration;
+
+use sqlx::{PgPool, Row};
+
+use crate::bulk;
+
+pub async fn run(pool: PgPool, interval: Duration, retention_days: i32) {
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        match purge_expired(&pool, retention_days).await {
+            Ok(0) => {}
+            Ok(n) => println!("purged {} topic(s) from trash", n),
+            Err(err) => eprintln!("purge trash failed: {}", err),
+        }
+    }
+}
+
+async fn purge_expired(pool: &PgPool, retention_days: i32) -> Result<i32, tonic::Status> {
+    let ids: Vec<i64> = sqlx::query(
+        "SELECT id FROM topics WHERE is_del AND deleted_at < CURRENT_TIMESTAMP - make_interval(days => $1)",
+    )
+    .bind(retention_days)
+    .fetch_all(pool)
+    .await
+    .map_err(|err| tonic::Status::internal(err.to_string()))?
+    .iter()
+    .map(|row| row.get("id"))
+    .collect();
+    if ids.is_empty() {
+        return Ok(0);
+    }
+    let reply = bulk::apply(pool, &ids, bulk::Action::Purge).await?;
+    Ok(reply.changed)
+}